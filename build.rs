@@ -9,8 +9,21 @@ use bindgen::Bindings;
 use cmake::Config;
 
 const SOURCE_DIR: &str = "vendor/KTX-Software";
-fn get_vk_inc_dir() -> String {
-    env::var("VULKAN_SDK").expect("VULKAN_SDK not found")
+// Range of libktx releases the checked-in bindings were generated against
+const KTX_MIN_VERSION: &str = "4.2.0";
+const KTX_MAX_VERSION: &str = "4.3.0";
+
+/// A libktx installation found on the host instead of the vendored tree.
+struct SystemKtx {
+    include_paths: Vec<PathBuf>,
+}
+
+// Prefer an installed Vulkan SDK, falling back to the headers KTX-Software ships
+fn get_vk_inc_dir() -> PathBuf {
+    match env::var_os("VULKAN_SDK") {
+        Some(sdk) => Path::new(&sdk).join("include"),
+        None => Path::new(SOURCE_DIR).join("other_include"),
+    }
 }
 
 fn main() {
@@ -22,21 +35,179 @@ fn main() {
     
     configure_cargo();
 
-    // Build dependencies
-    let out = build_ktx(SOURCE_DIR, build_type);
-    link_ktx(out);
+    // Without `native` only the pure Rust reader is compiled, with nothing to link
+    if cfg!(not(feature = "native")) {
+        return;
+    }
+
+    // Without bindgen we rely on pregenerated bindings for this target,
+    // so make sure they exist before spending minutes building libktx
+    let bindings = bindings_dir();
+    #[cfg(not(feature = "bindgen"))]
+    check_bindings(&bindings);
 
-    // Generate bindings
+    // Build dependencies, preferring a suitable system libktx
+    let system = probe_system_ktx();
+    if system.is_none() {
+        let out = build_ktx(SOURCE_DIR, build_type);
+        link_ktx(out);
+    }
+
+    // Generate bindings against the headers of whichever libktx we link,
+    // leaving the checked-in copies alone unless explicitly asked to refresh them
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
     #[cfg(feature = "bindgen")]
-    gen_bindings()
-        .write_to_file("src/ffi.rs")
-        .expect("Couldn't write bindings!");
+    {
+        let include_paths = ktx_include_paths(system.as_ref());
+        gen_bindings(&include_paths, &out_dir, &enabled_surfaces());
+        if env::var_os("LIBKTX_SYS_UPDATE_BINDINGS").is_some() {
+            gen_bindings(&include_paths, &bindings, &SURFACES);
+        }
+    }
+    #[cfg(not(feature = "bindgen"))]
+    copy_bindings(&bindings, &out_dir);
+
+    #[cfg(feature = "postprocess")]
+    postprocess::run(&out_dir);
+}
+
+// Pregenerated bindings are keyed by `<arch>-<os>`, e.g. `x86_64-linux`
+fn bindings_dir() -> PathBuf {
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src/bindings")
+        .join(format!("{}-{}", arch, os))
+}
+
+fn copy_bindings(src: &Path, dest: &Path) {
+    for surface in enabled_surfaces() {
+        let file = format!("{}.rs", surface);
+        std::fs::copy(src.join(&file), dest.join(&file)).expect("Couldn't copy bindings!");
+    }
+}
+
+fn check_bindings(dir: &Path) {
+    for surface in enabled_surfaces() {
+        if !dir.join(format!("{}.rs", surface)).exists() {
+            panic!(
+                "No pregenerated {} bindings for target `{}` (looked in {}). \
+                 Bindings are only shipped for: {}. \
+                 Enable the `bindgen` feature to generate them at build time.",
+                surface,
+                env::var("TARGET").unwrap(),
+                dir.display(),
+                pregenerated_targets().join(", ")
+            );
+        }
+    }
+}
+
+// Targets with a directory of checked-in bindings
+fn pregenerated_targets() -> Vec<String> {
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bindings");
+    let mut targets: Vec<String> = std::fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    targets.sort();
+    targets
+}
+
+fn ktx_include_paths(system: Option<&SystemKtx>) -> Vec<PathBuf> {
+    match system {
+        Some(ktx) => ktx.include_paths.clone(),
+        None => vec![
+            Path::new(SOURCE_DIR).join("include"),
+            Path::new(SOURCE_DIR).join("lib"),
+        ],
+    }
 }
 
 fn configure_cargo() {
     // Tell cargo to invalidate the built crate whenever the wrapper changes
     println!("cargo:rerun-if-changed=wrapper.h");
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=KTX_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=KTX_INCLUDE_DIR");
+    println!("cargo:rerun-if-env-changed=KTX_STATIC");
+    println!("cargo:rerun-if-env-changed=VULKAN_SDK");
+    println!("cargo:rerun-if-env-changed=LIBKTX_SYS_UPDATE_BINDINGS");
+}
+
+/// Looks for an installed libktx, either at `KTX_LIB_DIR` or through pkg-config.
+///
+/// `KTX_LIB_DIR` bypasses pkg-config, so the release is checked against the
+/// headers instead, taken from `KTX_INCLUDE_DIR` or `$KTX_LIB_DIR/../include`.
+/// Set `KTX_STATIC` to link statically.
+#[cfg(feature = "system")]
+fn probe_system_ktx() -> Option<SystemKtx> {
+    let statik = env::var_os("KTX_STATIC").is_some();
+
+    if let Some(lib_dir) = env::var_os("KTX_LIB_DIR") {
+        let lib_dir = PathBuf::from(lib_dir);
+        let include_dir = env::var_os("KTX_INCLUDE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| lib_dir.join("..").join("include"));
+        check_header_version(&include_dir);
+
+        let kind = if statik { "static" } else { "dylib" };
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        println!("cargo:rustc-link-lib={}=ktx", kind);
+        return Some(SystemKtx {
+            include_paths: vec![include_dir],
+        });
+    }
+
+    match pkg_config::Config::new()
+        .range_version(KTX_MIN_VERSION..KTX_MAX_VERSION)
+        .statik(statik)
+        .probe("libktx")
+    {
+        Ok(lib) => Some(SystemKtx {
+            include_paths: lib.include_paths,
+        }),
+        Err(err) => {
+            println!(
+                "cargo:warning=No suitable system libktx ({}), building the vendored copy",
+                err.to_string().trim().lines().next().unwrap_or_default()
+            );
+            None
+        }
+    }
+}
+
+// ktx.h carries no version number, so the release is told apart by entry points:
+// the first must be declared, the second only exists in releases after the range
+const KTX_HEADER_REQUIRED: &str = "ktxTexture2_GetOETF_e";
+const KTX_HEADER_TOO_NEW: &str = "ktxTexture2_DeflateZLIB";
+
+#[cfg(feature = "system")]
+fn check_header_version(include_dir: &Path) {
+    let header = include_dir.join("ktx.h");
+    let Ok(source) = std::fs::read_to_string(&header) else {
+        panic!(
+            "KTX_LIB_DIR is set but {} doesn't exist. \
+             Set KTX_INCLUDE_DIR to the headers of that libktx.",
+            header.display()
+        );
+    };
+    if !source.contains(KTX_HEADER_REQUIRED) || source.contains(KTX_HEADER_TOO_NEW) {
+        panic!(
+            "{} is not from a libktx release in {}..{}, which the bindings require.",
+            header.display(),
+            KTX_MIN_VERSION,
+            KTX_MAX_VERSION
+        );
+    }
+}
+
+#[cfg(not(feature = "system"))]
+fn probe_system_ktx() -> Option<SystemKtx> {
+    None
 }
 
 fn build_ktx(srcs: impl AsRef<Path>, build_type: &'_ str) -> PathBuf {
@@ -49,9 +220,19 @@ fn build_ktx(srcs: impl AsRef<Path>, build_type: &'_ str) -> PathBuf {
         .define("CMAKE_CXX_STANDARD", "17")
         .define("CMAKE_CXX_FLAGS", get_flags())
         .define("SUPPORT_SOFTWARE_ETC_UNPACK", "OFF")
+        .define("KTX_FEATURE_VK_UPLOAD", on_off(cfg!(feature = "vulkan")))
+        .define("KTX_FEATURE_GL_UPLOAD", on_off(cfg!(feature = "opengl")))
         .build()
 }
 
+fn on_off(enabled: bool) -> &'static str {
+    if enabled {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
+
 fn link_ktx(dest: impl AsRef<Path>) {
     // Tell cargo to tell rustc to link the system library
     println!(
@@ -61,25 +242,107 @@ fn link_ktx(dest: impl AsRef<Path>) {
     println!("cargo:rustc-link-lib=static=ktx");
 }
 
-fn gen_bindings() -> Bindings {
+// API surfaces, each kept in its own bindings file. `vk_format` is just the
+// `VkFormat` enum, which the format tables need when `vulkan` is disabled.
+const SURFACES: [&str; 4] = ["core", "vulkan", "opengl", "vk_format"];
+
+// Surfaces enabled by cargo features
+fn enabled_surfaces() -> Vec<&'static str> {
+    let mut surfaces = vec!["core"];
+    if cfg!(feature = "vulkan") {
+        surfaces.push("vulkan");
+    } else {
+        surfaces.push("vk_format");
+    }
+    if cfg!(feature = "opengl") {
+        surfaces.push("opengl");
+    }
+    surfaces
+}
+
+/// Writes one file per API surface so `src/ffi.rs` can gate them on features.
+fn gen_bindings(include_paths: &[PathBuf], dest: impl AsRef<Path>, surfaces: &[&str]) {
+    let dest = dest.as_ref();
+    std::fs::create_dir_all(dest).expect("Couldn't create bindings directory!");
+    for &surface in surfaces {
+        let bindings = match surface {
+            "vulkan" => vulkan_bindings(include_paths),
+            "opengl" => opengl_bindings(include_paths),
+            "vk_format" => vk_format_bindings(include_paths),
+            _ => core_bindings(include_paths),
+        };
+        bindings
+            .write_to_file(dest.join(format!("{}.rs", surface)))
+            .expect("Couldn't write bindings!");
+    }
+}
+
+fn bindings_builder(include_paths: &[PathBuf]) -> bindgen::Builder {
     bindgen::Builder::default()
         // The input header we would like to generate
         // bindings for.
         .header("wrapper.h")
-        .clang_args(get_clang_args())
+        .clang_args(get_clang_args(include_paths))
+}
+
+// Texture, hashlist and compression API, without any GPU upload entry points.
+// Only libktx and Khronos data format symbols are allowlisted, so libc and stdio
+// internals don't leak into the crate root. `FILE` is only ever passed by
+// pointer and is kept opaque to avoid baking in the host's libc layout.
+fn core_bindings(include_paths: &[PathBuf]) -> Bindings {
+    bindings_builder(include_paths)
+        .allowlist_function("ktx.*")
+        .allowlist_type("ktx.*|KTX.*|_?khr_.*")
+        .allowlist_var("KTX_.*|KHR_DF_.*")
+        .blocklist_function("ktxTexture_GLUpload")
+        .blocklist_type("GL.*|FILE|_IO_FILE")
+        .raw_line(OPAQUE_FILE)
         .generate()
         // Unwrap the Result and panic on failure.
-        .expect("Unable to generate bindings")
+        .expect("Unable to generate core bindings")
+}
+
+const OPAQUE_FILE: &str = r#"#[doc = " Opaque C stdio stream, only ever handled by pointer."]
+#[repr(C)]
+pub struct FILE {
+    _unused: [u8; 0],
+}"#;
+
+// ktxvulkan.h and exactly the Vulkan types it refers to. The core libktx
+// headers are blocklisted since those types come from the core bindings.
+fn vulkan_bindings(include_paths: &[PathBuf]) -> Bindings {
+    bindings_builder(include_paths)
+        .clang_arg(format!("-I{}", get_vk_inc_dir().display()))
+        .clang_arg("-DLIBKTX_SYS_VULKAN")
+        .allowlist_file(".*ktxvulkan\\.h")
+        .blocklist_file(".*ktx\\.h|.*khr_df\\.h")
+        .generate()
+        .expect("Unable to generate Vulkan bindings")
+}
+
+fn vk_format_bindings(include_paths: &[PathBuf]) -> Bindings {
+    bindings_builder(include_paths)
+        .clang_arg(format!("-I{}", get_vk_inc_dir().display()))
+        .clang_arg("-DLIBKTX_SYS_VULKAN")
+        .allowlist_type("VkFormat")
+        .generate()
+        .expect("Unable to generate VkFormat bindings")
+}
+
+fn opengl_bindings(include_paths: &[PathBuf]) -> Bindings {
+    bindings_builder(include_paths)
+        .allowlist_function("ktxTexture_GLUpload")
+        .allowlist_type("GL.*")
+        .allowlist_recursively(false)
+        .generate()
+        .expect("Unable to generate OpenGL bindings")
 }
 
-fn get_clang_args() -> Vec<String> {
-    let vulkan_include_path = Path::new(&get_vk_inc_dir()).join("include");
-    [
-        format!("-I{}/include", SOURCE_DIR),
-        format!("-I{}/lib", SOURCE_DIR),
-        format!("-I{}", vulkan_include_path.display()),
-    ]
-    .to_vec()
+fn get_clang_args(include_paths: &[PathBuf]) -> Vec<String> {
+    include_paths
+        .iter()
+        .map(|path| format!("-I{}", path.display()))
+        .collect()
 }
 
 // TODO support non-msvc compilers on windows
@@ -96,3 +359,228 @@ fn get_flags() -> &'static str {
         "-Wno-error"
     }
 }
+
+/// Turns the constified C enums of the core bindings into Rust enums and
+/// bitflags-style newtypes. The raw constants are left untouched, the
+/// generated types are defined in terms of them.
+#[cfg(feature = "postprocess")]
+mod postprocess {
+    use std::{fmt::Write, fs, path::Path};
+
+    use regex::Regex;
+
+    // C enums that get a Rust enum
+    const ENUMS: &str = r"^(ktx_error_code_e|ktxSupercmpScheme|ktx_transcode_fmt_e|ktx_pack_astc_\w+_e|_khr_df_\w+_e)$";
+    // Flag bit enums and the typedef used to pass their combinations around
+    const FLAGS: [(&str, &str); 3] = [
+        ("ktxTextureCreateFlagBits", "ktxTextureCreateFlags"),
+        ("ktx_transcode_flag_bits_e", "ktx_transcode_flags"),
+        ("ktx_pack_uastc_flag_bits_e", "ktx_pack_uastc_flags"),
+    ];
+
+    // (constant or alias, variant)
+    type Pair<'a> = (&'a str, &'a str);
+
+    struct CEnum {
+        name: String,
+        repr: &'static str,
+        // (constant, variant, value) in declaration order
+        values: Vec<(String, String, i64)>,
+    }
+
+    pub fn run(out_dir: &Path) {
+        let core = fs::read_to_string(out_dir.join("core.rs")).expect("Couldn't read bindings!");
+        let enums = parse_enums(&core);
+        let wanted = Regex::new(ENUMS).unwrap();
+
+        let mut out = String::from("// Generated by the `postprocess` feature of build.rs\n");
+        for e in enums.iter().filter(|e| wanted.is_match(&e.name)) {
+            write_enum(&mut out, e);
+        }
+        for (bits, flags) in FLAGS {
+            if let Some(e) = enums.iter().find(|e| e.name == bits) {
+                write_flags(&mut out, e, flags);
+            }
+        }
+
+        fs::write(out_dir.join("postprocess.rs"), out).expect("Couldn't write bindings!");
+    }
+
+    fn parse_enums(bindings: &str) -> Vec<CEnum> {
+        let ty = Regex::new(r"pub type (\w+) = ::std::os::raw::(c_uint|c_int);").unwrap();
+        let constant = Regex::new(r"pub const (\w+):\s*(\w+)\s*=\s*(-?\d+);").unwrap();
+
+        let mut enums: Vec<CEnum> = ty
+            .captures_iter(bindings)
+            .map(|c| CEnum {
+                name: c[1].to_string(),
+                repr: if &c[2] == "c_int" { "i32" } else { "u32" },
+                values: Vec::new(),
+            })
+            .collect();
+        for c in constant.captures_iter(bindings) {
+            let Some(e) = enums.iter_mut().find(|e| e.name == c[2]) else {
+                continue;
+            };
+            let Some(variant) = c[1].strip_prefix(&format!("{}_", e.name)) else {
+                continue;
+            };
+            e.values
+                .push((c[1].to_string(), variant.to_string(), c[3].parse().unwrap()));
+        }
+        enums.retain(|e| !e.values.is_empty());
+        enums
+    }
+
+    // `_khr_df_model_e` -> `KhrDfModel`, `ktxSupercmpScheme` -> `KtxSupercmpScheme`
+    fn rust_name(c_name: &str) -> String {
+        let name = c_name.trim_start_matches('_');
+        let name = name.strip_suffix("_e").unwrap_or(name);
+        name.split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                let first = chars.next().unwrap().to_ascii_uppercase();
+                std::iter::once(first).chain(chars).collect::<String>()
+            })
+            .collect()
+    }
+
+    fn write_enum(out: &mut String, e: &CEnum) {
+        let (name, c_name, repr) = (rust_name(&e.name), &e.name, e.repr);
+        let (variants, aliases) = split_aliases(e);
+        let variants_list: String = variants
+            .iter()
+            .map(|(constant, variant)| format!("    {variant} = {constant},\n"))
+            .collect();
+        let alias_consts: String = aliases
+            .iter()
+            .map(|(alias, variant)| format!("    pub const {alias}: Self = Self::{variant};\n"))
+            .collect();
+        let match_arms: String = variants
+            .iter()
+            .map(|(constant, variant)| format!("            {constant} => Ok(Self::{variant}),\n"))
+            .collect();
+
+        write!(
+            out,
+            r#"
+#[doc = "Rust enum over the `{c_name}` constants."]
+#[repr({repr})]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum {name} {{
+{variants_list}}}
+impl {name} {{
+{alias_consts}}}
+impl TryFrom<{repr}> for {name} {{
+    type Error = {repr};
+    fn try_from(value: {repr}) -> Result<Self, Self::Error> {{
+        match value {{
+{match_arms}            _ => Err(value),
+        }}
+    }}
+}}
+impl From<{name}> for {repr} {{
+    fn from(value: {name}) -> Self {{
+        value as {repr}
+    }}
+}}
+"#
+        )
+        .unwrap();
+    }
+
+    // Rust enums can't repeat discriminants, so later constants with an already
+    // used value become associated constants aliasing the first one
+    fn split_aliases(e: &CEnum) -> (Vec<Pair<'_>>, Vec<Pair<'_>>) {
+        let mut variants: Vec<(&str, &str, i64)> = Vec::new();
+        let mut aliases = Vec::new();
+        for (constant, variant, value) in &e.values {
+            match variants.iter().find(|(_, _, v)| v == value) {
+                Some((_, first, _)) => aliases.push((variant.as_str(), *first)),
+                None => variants.push((constant, variant, *value)),
+            }
+        }
+        let variants = variants.into_iter().map(|(c, v, _)| (c, v)).collect();
+        (variants, aliases)
+    }
+
+    fn write_flags(out: &mut String, e: &CEnum, flags: &str) {
+        let (name, c_name) = (rust_name(flags), &e.name);
+        let bit_consts: String = e
+            .values
+            .iter()
+            .map(|(constant, variant, _)| {
+                format!("    pub const {variant}: Self = Self({constant});\n")
+            })
+            .collect();
+
+        write!(
+            out,
+            r#"
+#[doc = "Bitflags over the `{c_name}` constants."]
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct {name}(pub {flags});
+impl {name} {{
+{bit_consts}
+    pub const fn empty() -> Self {{
+        Self(0)
+    }}
+    pub const fn bits(self) -> {flags} {{
+        self.0
+    }}
+    pub const fn is_empty(self) -> bool {{
+        self.0 == 0
+    }}
+    pub const fn contains(self, other: Self) -> bool {{
+        self.0 & other.0 == other.0
+    }}
+    pub const fn intersects(self, other: Self) -> bool {{
+        self.0 & other.0 != 0
+    }}
+    pub fn insert(&mut self, other: Self) {{
+        self.0 |= other.0;
+    }}
+    pub fn remove(&mut self, other: Self) {{
+        self.0 &= !other.0;
+    }}
+}}
+impl From<{name}> for {flags} {{
+    fn from(value: {name}) -> Self {{
+        value.0
+    }}
+}}
+impl From<{flags}> for {name} {{
+    fn from(value: {flags}) -> Self {{
+        Self(value)
+    }}
+}}
+"#
+        )
+        .unwrap();
+
+        for (op, method, symbol) in [
+            ("BitOr", "bitor", "|"),
+            ("BitAnd", "bitand", "&"),
+            ("BitXor", "bitxor", "^"),
+        ] {
+            write!(
+                out,
+                r#"impl ::std::ops::{op} for {name} {{
+    type Output = Self;
+    fn {method}(self, rhs: Self) -> Self {{
+        Self(self.0 {symbol} rhs.0)
+    }}
+}}
+impl ::std::ops::{op}Assign for {name} {{
+    fn {method}_assign(&mut self, rhs: Self) {{
+        self.0 {symbol}= rhs.0;
+    }}
+}}
+"#
+            )
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,3135 @@
+/* automatically generated by rust-bindgen 0.69.1 */
+
+#[doc = " Opaque C stdio stream, only ever handled by pointer."]
+#[repr(C)]
+pub struct FILE {
+    _unused: [u8; 0],
+}
+
+pub const KTX_ANIMDATA_KEY: &[u8; 12] = b"KTXanimData\0";
+pub const KTX_ORIENTATION_KEY: &[u8; 15] = b"KTXorientation\0";
+pub const KTX_SWIZZLE_KEY: &[u8; 11] = b"KTXswizzle\0";
+pub const KTX_WRITER_KEY: &[u8; 10] = b"KTXwriter\0";
+pub const KTX_WRITER_SCPARAMS_KEY: &[u8; 18] = b"KTXwriterScParams\0";
+pub const KTX_ORIENTATION1_FMT: &[u8; 5] = b"S=%c\0";
+pub const KTX_ORIENTATION2_FMT: &[u8; 10] = b"S=%c,T=%c\0";
+pub const KTX_ORIENTATION3_FMT: &[u8; 15] = b"S=%c,T=%c,R=%c\0";
+pub const KTX_GL_UNPACK_ALIGNMENT: u32 = 4;
+pub const KTX_TRUE: u32 = 1;
+pub const KTX_FALSE: u32 = 0;
+pub const KTX_ENDIAN_REF: u32 = 67305985;
+pub const KTX_ENDIAN_REF_REV: u32 = 16909060;
+pub const KTX_HEADER_SIZE: u32 = 64;
+pub type __off_t = ::std::os::raw::c_long;
+pub type off_t = __off_t;
+pub const _khr_word_e_KHR_DF_WORD_VENDORID: _khr_word_e = 0;
+pub const _khr_word_e_KHR_DF_WORD_DESCRIPTORTYPE: _khr_word_e = 0;
+pub const _khr_word_e_KHR_DF_WORD_VERSIONNUMBER: _khr_word_e = 1;
+pub const _khr_word_e_KHR_DF_WORD_DESCRIPTORBLOCKSIZE: _khr_word_e = 1;
+pub const _khr_word_e_KHR_DF_WORD_MODEL: _khr_word_e = 2;
+pub const _khr_word_e_KHR_DF_WORD_PRIMARIES: _khr_word_e = 2;
+pub const _khr_word_e_KHR_DF_WORD_TRANSFER: _khr_word_e = 2;
+pub const _khr_word_e_KHR_DF_WORD_FLAGS: _khr_word_e = 2;
+pub const _khr_word_e_KHR_DF_WORD_TEXELBLOCKDIMENSION0: _khr_word_e = 3;
+pub const _khr_word_e_KHR_DF_WORD_TEXELBLOCKDIMENSION1: _khr_word_e = 3;
+pub const _khr_word_e_KHR_DF_WORD_TEXELBLOCKDIMENSION2: _khr_word_e = 3;
+pub const _khr_word_e_KHR_DF_WORD_TEXELBLOCKDIMENSION3: _khr_word_e = 3;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE0: _khr_word_e = 4;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE1: _khr_word_e = 4;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE2: _khr_word_e = 4;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE3: _khr_word_e = 4;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE4: _khr_word_e = 5;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE5: _khr_word_e = 5;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE6: _khr_word_e = 5;
+pub const _khr_word_e_KHR_DF_WORD_BYTESPLANE7: _khr_word_e = 5;
+pub const _khr_word_e_KHR_DF_WORD_SAMPLESTART: _khr_word_e = 6;
+pub const _khr_word_e_KHR_DF_WORD_SAMPLEWORDS: _khr_word_e = 4;
+pub type _khr_word_e = ::std::os::raw::c_uint;
+pub use self::_khr_word_e as khr_df_word_e;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_VENDORID: _khr_df_shift_e = 0;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_DESCRIPTORTYPE: _khr_df_shift_e = 17;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_VERSIONNUMBER: _khr_df_shift_e = 0;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_DESCRIPTORBLOCKSIZE: _khr_df_shift_e = 16;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_MODEL: _khr_df_shift_e = 0;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_PRIMARIES: _khr_df_shift_e = 8;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_TRANSFER: _khr_df_shift_e = 16;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_FLAGS: _khr_df_shift_e = 24;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_TEXELBLOCKDIMENSION0: _khr_df_shift_e = 0;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_TEXELBLOCKDIMENSION1: _khr_df_shift_e = 8;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_TEXELBLOCKDIMENSION2: _khr_df_shift_e = 16;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_TEXELBLOCKDIMENSION3: _khr_df_shift_e = 24;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE0: _khr_df_shift_e = 0;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE1: _khr_df_shift_e = 8;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE2: _khr_df_shift_e = 16;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE3: _khr_df_shift_e = 24;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE4: _khr_df_shift_e = 0;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE5: _khr_df_shift_e = 8;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE6: _khr_df_shift_e = 16;
+pub const _khr_df_shift_e_KHR_DF_SHIFT_BYTESPLANE7: _khr_df_shift_e = 24;
+pub type _khr_df_shift_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_shift_e as khr_df_shift_e;
+pub const _khr_df_mask_e_KHR_DF_MASK_VENDORID: _khr_df_mask_e = 131071;
+pub const _khr_df_mask_e_KHR_DF_MASK_DESCRIPTORTYPE: _khr_df_mask_e = 32767;
+pub const _khr_df_mask_e_KHR_DF_MASK_VERSIONNUMBER: _khr_df_mask_e = 65535;
+pub const _khr_df_mask_e_KHR_DF_MASK_DESCRIPTORBLOCKSIZE: _khr_df_mask_e = 65535;
+pub const _khr_df_mask_e_KHR_DF_MASK_MODEL: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_PRIMARIES: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_TRANSFER: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_FLAGS: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_TEXELBLOCKDIMENSION0: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_TEXELBLOCKDIMENSION1: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_TEXELBLOCKDIMENSION2: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_TEXELBLOCKDIMENSION3: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE0: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE1: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE2: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE3: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE4: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE5: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE6: _khr_df_mask_e = 255;
+pub const _khr_df_mask_e_KHR_DF_MASK_BYTESPLANE7: _khr_df_mask_e = 255;
+pub type _khr_df_mask_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_mask_e as khr_df_mask_e;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_BITOFFSET: _khr_df_sampleword_e = 0;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_BITLENGTH: _khr_df_sampleword_e = 0;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_CHANNELID: _khr_df_sampleword_e = 0;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_QUALIFIERS: _khr_df_sampleword_e = 0;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_SAMPLEPOSITION0: _khr_df_sampleword_e = 1;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_SAMPLEPOSITION1: _khr_df_sampleword_e = 1;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_SAMPLEPOSITION2: _khr_df_sampleword_e = 1;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_SAMPLEPOSITION3: _khr_df_sampleword_e = 1;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_SAMPLEPOSITION_ALL: _khr_df_sampleword_e = 1;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_SAMPLELOWER: _khr_df_sampleword_e = 2;
+pub const _khr_df_sampleword_e_KHR_DF_SAMPLEWORD_SAMPLEUPPER: _khr_df_sampleword_e = 3;
+pub type _khr_df_sampleword_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_sampleword_e as khr_df_sampleword_e;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_BITOFFSET: _khr_df_sampleshift_e = 0;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_BITLENGTH: _khr_df_sampleshift_e = 16;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_CHANNELID: _khr_df_sampleshift_e = 24;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_QUALIFIERS: _khr_df_sampleshift_e = 24;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_SAMPLEPOSITION0: _khr_df_sampleshift_e = 0;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_SAMPLEPOSITION1: _khr_df_sampleshift_e = 8;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_SAMPLEPOSITION2: _khr_df_sampleshift_e = 16;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_SAMPLEPOSITION3: _khr_df_sampleshift_e = 24;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_SAMPLEPOSITION_ALL: _khr_df_sampleshift_e = 0;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_SAMPLELOWER: _khr_df_sampleshift_e = 0;
+pub const _khr_df_sampleshift_e_KHR_DF_SAMPLESHIFT_SAMPLEUPPER: _khr_df_sampleshift_e = 0;
+pub type _khr_df_sampleshift_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_sampleshift_e as khr_df_sampleshift_e;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_BITOFFSET: _khr_df_samplemask_e = 65535;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_BITLENGTH: _khr_df_samplemask_e = 255;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_CHANNELID: _khr_df_samplemask_e = 15;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_QUALIFIERS: _khr_df_samplemask_e = 240;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_SAMPLEPOSITION0: _khr_df_samplemask_e = 255;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_SAMPLEPOSITION1: _khr_df_samplemask_e = 255;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_SAMPLEPOSITION2: _khr_df_samplemask_e = 255;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_SAMPLEPOSITION3: _khr_df_samplemask_e = 255;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_SAMPLEPOSITION_ALL: _khr_df_samplemask_e = -1;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_SAMPLELOWER: _khr_df_samplemask_e = -1;
+pub const _khr_df_samplemask_e_KHR_DF_SAMPLEMASK_SAMPLEUPPER: _khr_df_samplemask_e = -1;
+pub type _khr_df_samplemask_e = ::std::os::raw::c_int;
+pub use self::_khr_df_samplemask_e as khr_df_samplemask_e;
+pub const _khr_df_vendorid_e_KHR_DF_VENDORID_KHRONOS: _khr_df_vendorid_e = 0;
+pub const _khr_df_vendorid_e_KHR_DF_VENDORID_MAX: _khr_df_vendorid_e = 131071;
+pub type _khr_df_vendorid_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_vendorid_e as khr_df_vendorid_e;
+pub const _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_BASICFORMAT:
+    _khr_df_khr_descriptortype_e = 0;
+pub const _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_ADDITIONAL_PLANES:
+    _khr_df_khr_descriptortype_e = 24577;
+pub const _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_ADDITIONAL_DIMENSIONS:
+    _khr_df_khr_descriptortype_e = 24578;
+pub const _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_NEEDED_FOR_WRITE_BIT:
+    _khr_df_khr_descriptortype_e = 8192;
+pub const _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_NEEDED_FOR_DECODE_BIT:
+    _khr_df_khr_descriptortype_e = 16384;
+pub const _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_MAX: _khr_df_khr_descriptortype_e =
+    32767;
+pub type _khr_df_khr_descriptortype_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_khr_descriptortype_e as khr_df_khr_descriptortype_e;
+pub const _khr_df_versionnumber_e_KHR_DF_VERSIONNUMBER_1_0: _khr_df_versionnumber_e = 0;
+pub const _khr_df_versionnumber_e_KHR_DF_VERSIONNUMBER_1_1: _khr_df_versionnumber_e = 0;
+pub const _khr_df_versionnumber_e_KHR_DF_VERSIONNUMBER_1_2: _khr_df_versionnumber_e = 1;
+pub const _khr_df_versionnumber_e_KHR_DF_VERSIONNUMBER_1_3: _khr_df_versionnumber_e = 2;
+pub const _khr_df_versionnumber_e_KHR_DF_VERSIONNUMBER_LATEST: _khr_df_versionnumber_e = 2;
+pub const _khr_df_versionnumber_e_KHR_DF_VERSIONNUMBER_MAX: _khr_df_versionnumber_e = 65535;
+pub type _khr_df_versionnumber_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_versionnumber_e as khr_df_versionnumber_e;
+pub const _khr_df_model_e_KHR_DF_MODEL_UNSPECIFIED: _khr_df_model_e = 0;
+pub const _khr_df_model_e_KHR_DF_MODEL_RGBSDA: _khr_df_model_e = 1;
+pub const _khr_df_model_e_KHR_DF_MODEL_YUVSDA: _khr_df_model_e = 2;
+pub const _khr_df_model_e_KHR_DF_MODEL_YIQSDA: _khr_df_model_e = 3;
+pub const _khr_df_model_e_KHR_DF_MODEL_LABSDA: _khr_df_model_e = 4;
+pub const _khr_df_model_e_KHR_DF_MODEL_CMYKA: _khr_df_model_e = 5;
+pub const _khr_df_model_e_KHR_DF_MODEL_XYZW: _khr_df_model_e = 6;
+pub const _khr_df_model_e_KHR_DF_MODEL_HSVA_ANG: _khr_df_model_e = 7;
+pub const _khr_df_model_e_KHR_DF_MODEL_HSLA_ANG: _khr_df_model_e = 8;
+pub const _khr_df_model_e_KHR_DF_MODEL_HSVA_HEX: _khr_df_model_e = 9;
+pub const _khr_df_model_e_KHR_DF_MODEL_HSLA_HEX: _khr_df_model_e = 10;
+pub const _khr_df_model_e_KHR_DF_MODEL_YCGCOA: _khr_df_model_e = 11;
+pub const _khr_df_model_e_KHR_DF_MODEL_YCCBCCRC: _khr_df_model_e = 12;
+pub const _khr_df_model_e_KHR_DF_MODEL_ICTCP: _khr_df_model_e = 13;
+pub const _khr_df_model_e_KHR_DF_MODEL_CIEXYZ: _khr_df_model_e = 14;
+pub const _khr_df_model_e_KHR_DF_MODEL_CIEXYY: _khr_df_model_e = 15;
+pub const _khr_df_model_e_KHR_DF_MODEL_DXT1A: _khr_df_model_e = 128;
+pub const _khr_df_model_e_KHR_DF_MODEL_BC1A: _khr_df_model_e = 128;
+pub const _khr_df_model_e_KHR_DF_MODEL_DXT2: _khr_df_model_e = 129;
+pub const _khr_df_model_e_KHR_DF_MODEL_DXT3: _khr_df_model_e = 129;
+pub const _khr_df_model_e_KHR_DF_MODEL_BC2: _khr_df_model_e = 129;
+pub const _khr_df_model_e_KHR_DF_MODEL_DXT4: _khr_df_model_e = 130;
+pub const _khr_df_model_e_KHR_DF_MODEL_DXT5: _khr_df_model_e = 130;
+pub const _khr_df_model_e_KHR_DF_MODEL_BC3: _khr_df_model_e = 130;
+pub const _khr_df_model_e_KHR_DF_MODEL_BC4: _khr_df_model_e = 131;
+pub const _khr_df_model_e_KHR_DF_MODEL_BC5: _khr_df_model_e = 132;
+pub const _khr_df_model_e_KHR_DF_MODEL_BC6H: _khr_df_model_e = 133;
+pub const _khr_df_model_e_KHR_DF_MODEL_BC7: _khr_df_model_e = 134;
+pub const _khr_df_model_e_KHR_DF_MODEL_ETC1: _khr_df_model_e = 160;
+pub const _khr_df_model_e_KHR_DF_MODEL_ETC2: _khr_df_model_e = 161;
+pub const _khr_df_model_e_KHR_DF_MODEL_ASTC: _khr_df_model_e = 162;
+pub const _khr_df_model_e_KHR_DF_MODEL_ETC1S: _khr_df_model_e = 163;
+pub const _khr_df_model_e_KHR_DF_MODEL_PVRTC: _khr_df_model_e = 164;
+pub const _khr_df_model_e_KHR_DF_MODEL_PVRTC2: _khr_df_model_e = 165;
+pub const _khr_df_model_e_KHR_DF_MODEL_UASTC: _khr_df_model_e = 166;
+pub const _khr_df_model_e_KHR_DF_MODEL_MAX: _khr_df_model_e = 255;
+pub type _khr_df_model_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_model_e as khr_df_model_e;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_0: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_1: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_2: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_3: _khr_df_model_channels_e = 3;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_4: _khr_df_model_channels_e = 4;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_5: _khr_df_model_channels_e = 5;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_6: _khr_df_model_channels_e = 6;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_7: _khr_df_model_channels_e = 7;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_8: _khr_df_model_channels_e = 8;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_9: _khr_df_model_channels_e = 9;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_10: _khr_df_model_channels_e = 10;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_11: _khr_df_model_channels_e = 11;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_12: _khr_df_model_channels_e = 12;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_13: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_14: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UNSPECIFIED_15: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_RED: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_R: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_GREEN: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_G: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_BLUE: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_B: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_STENCIL: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_S: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_DEPTH: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_D: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_Y: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_CB: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_U: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_CR: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_V: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_STENCIL: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_S: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_DEPTH: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_D: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_Y: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_I: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_Q: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_STENCIL: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_S: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_DEPTH: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_D: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YIQSDA_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_L: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_A: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_B: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_STENCIL: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_S: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_DEPTH: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_D: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_LABSDA_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_CYAN: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_C: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_MAGENTA: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_M: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_YELLOW: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_Y: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_KEY: _khr_df_model_channels_e = 3;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_BLACK: _khr_df_model_channels_e = 3;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_K: _khr_df_model_channels_e = 3;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CMYKSDA_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_XYZW_X: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_XYZW_Y: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_XYZW_Z: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_XYZW_W: _khr_df_model_channels_e = 3;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_VALUE: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_V: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_SATURATION: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_S: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_HUE: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_H: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_ANG_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_LIGHTNESS: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_L: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_SATURATION: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_S: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_HUE: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_H: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_ANG_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_VALUE: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_V: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_SATURATION: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_S: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_HUE: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_H: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSVA_HEX_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_LIGHTNESS: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_L: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_SATURATION: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_S: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_HUE: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_H: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_HSLA_HEX_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YCGCOA_Y: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YCGCOA_CG: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YCGCOA_CO: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YCGCOA_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_YCGCOA_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CIEXYZ_X: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CIEXYZ_Y: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CIEXYZ_Z: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CIEXYY_X: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CIEXYY_YCHROMA: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_CIEXYY_YLUMA: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT1A_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC1A_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT1A_ALPHAPRESENT: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT1A_ALPHA: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC1A_ALPHAPRESENT: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC1A_ALPHA: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT2_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT3_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC2_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT2_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT3_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC2_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT4_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT5_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC3_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT4_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_DXT5_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC3_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC4_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC5_RED: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC5_R: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC5_GREEN: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC5_G: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC6H_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC6H_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC7_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_BC7_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_RED: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_R: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_GREEN: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_G: _khr_df_model_channels_e = 1;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_COLOR: _khr_df_model_channels_e = 2;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_A: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ASTC_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_RGB: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_RRR: _khr_df_model_channels_e = 3;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_GGG: _khr_df_model_channels_e = 4;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_AAA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_PVRTC_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_PVRTC_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_PVRTC2_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_PVRTC2_COLOR: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_DATA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RGB: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RGBA: _khr_df_model_channels_e = 3;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RRR: _khr_df_model_channels_e = 4;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RRRG: _khr_df_model_channels_e = 5;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RG: _khr_df_model_channels_e = 6;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_LUMA: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_L: _khr_df_model_channels_e = 0;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_STENCIL: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_S: _khr_df_model_channels_e = 13;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_DEPTH: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_D: _khr_df_model_channels_e = 14;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_ALPHA: _khr_df_model_channels_e = 15;
+pub const _khr_df_model_channels_e_KHR_DF_CHANNEL_COMMON_A: _khr_df_model_channels_e = 15;
+pub type _khr_df_model_channels_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_model_channels_e as khr_df_model_channels_e;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_UNSPECIFIED: _khr_df_primaries_e = 0;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_BT709: _khr_df_primaries_e = 1;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_SRGB: _khr_df_primaries_e = 1;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_BT601_EBU: _khr_df_primaries_e = 2;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_BT601_SMPTE: _khr_df_primaries_e = 3;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_BT2020: _khr_df_primaries_e = 4;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_CIEXYZ: _khr_df_primaries_e = 5;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_ACES: _khr_df_primaries_e = 6;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_ACESCC: _khr_df_primaries_e = 7;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_NTSC1953: _khr_df_primaries_e = 8;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_PAL525: _khr_df_primaries_e = 9;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_DISPLAYP3: _khr_df_primaries_e = 10;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_ADOBERGB: _khr_df_primaries_e = 11;
+pub const _khr_df_primaries_e_KHR_DF_PRIMARIES_MAX: _khr_df_primaries_e = 255;
+pub type _khr_df_primaries_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_primaries_e as khr_df_primaries_e;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_UNSPECIFIED: _khr_df_transfer_e = 0;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_LINEAR: _khr_df_transfer_e = 1;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_SRGB: _khr_df_transfer_e = 2;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_ITU: _khr_df_transfer_e = 3;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_SMTPE170M: _khr_df_transfer_e = 3;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_NTSC: _khr_df_transfer_e = 4;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_SLOG: _khr_df_transfer_e = 5;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_SLOG2: _khr_df_transfer_e = 6;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_BT1886: _khr_df_transfer_e = 7;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_HLG_OETF: _khr_df_transfer_e = 8;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_HLG_EOTF: _khr_df_transfer_e = 9;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_PQ_EOTF: _khr_df_transfer_e = 10;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_PQ_OETF: _khr_df_transfer_e = 11;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_DCIP3: _khr_df_transfer_e = 12;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_PAL_OETF: _khr_df_transfer_e = 13;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_PAL625_EOTF: _khr_df_transfer_e = 14;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_ST240: _khr_df_transfer_e = 15;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_ACESCC: _khr_df_transfer_e = 16;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_ACESCCT: _khr_df_transfer_e = 17;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_ADOBERGB: _khr_df_transfer_e = 18;
+pub const _khr_df_transfer_e_KHR_DF_TRANSFER_MAX: _khr_df_transfer_e = 255;
+pub type _khr_df_transfer_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_transfer_e as khr_df_transfer_e;
+pub const _khr_df_flags_e_KHR_DF_FLAG_ALPHA_STRAIGHT: _khr_df_flags_e = 0;
+pub const _khr_df_flags_e_KHR_DF_FLAG_ALPHA_PREMULTIPLIED: _khr_df_flags_e = 1;
+pub type _khr_df_flags_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_flags_e as khr_df_flags_e;
+pub const _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_LINEAR:
+    _khr_df_sample_datatype_qualifiers_e = 16;
+pub const _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_EXPONENT:
+    _khr_df_sample_datatype_qualifiers_e = 32;
+pub const _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_SIGNED:
+    _khr_df_sample_datatype_qualifiers_e = 64;
+pub const _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_FLOAT:
+    _khr_df_sample_datatype_qualifiers_e = 128;
+pub type _khr_df_sample_datatype_qualifiers_e = ::std::os::raw::c_uint;
+pub use self::_khr_df_sample_datatype_qualifiers_e as khr_df_sample_datatype_qualifiers_e;
+pub type ktx_uint8_t = ::std::os::raw::c_uchar;
+pub type ktx_bool_t = bool;
+pub type ktx_uint16_t = u16;
+pub type ktx_int16_t = i16;
+pub type ktx_uint32_t = u32;
+pub type ktx_int32_t = i32;
+pub type ktx_size_t = usize;
+pub type ktx_uint64_t = u64;
+pub type ktx_int64_t = i64;
+pub type ktx_uint32_t_SIZE_ASSERT = [::std::os::raw::c_uchar; 1usize];
+#[doc = "< Operation was successful."]
+pub const ktx_error_code_e_KTX_SUCCESS: ktx_error_code_e = 0;
+#[doc = "< The data in the file is inconsistent with the spec."]
+pub const ktx_error_code_e_KTX_FILE_DATA_ERROR: ktx_error_code_e = 1;
+#[doc = "< The file is a pipe or named pipe."]
+pub const ktx_error_code_e_KTX_FILE_ISPIPE: ktx_error_code_e = 2;
+#[doc = "< The target file could not be opened."]
+pub const ktx_error_code_e_KTX_FILE_OPEN_FAILED: ktx_error_code_e = 3;
+#[doc = "< The operation would exceed the max file size."]
+pub const ktx_error_code_e_KTX_FILE_OVERFLOW: ktx_error_code_e = 4;
+#[doc = "< An error occurred while reading from the file."]
+pub const ktx_error_code_e_KTX_FILE_READ_ERROR: ktx_error_code_e = 5;
+#[doc = "< An error occurred while seeking in the file."]
+pub const ktx_error_code_e_KTX_FILE_SEEK_ERROR: ktx_error_code_e = 6;
+#[doc = "< File does not have enough data to satisfy request."]
+pub const ktx_error_code_e_KTX_FILE_UNEXPECTED_EOF: ktx_error_code_e = 7;
+#[doc = "< An error occurred while writing to the file."]
+pub const ktx_error_code_e_KTX_FILE_WRITE_ERROR: ktx_error_code_e = 8;
+#[doc = "< GL operations resulted in an error."]
+pub const ktx_error_code_e_KTX_GL_ERROR: ktx_error_code_e = 9;
+#[doc = "< The operation is not allowed in the current state."]
+pub const ktx_error_code_e_KTX_INVALID_OPERATION: ktx_error_code_e = 10;
+#[doc = "< A parameter value was not valid"]
+pub const ktx_error_code_e_KTX_INVALID_VALUE: ktx_error_code_e = 11;
+#[doc = "< Requested key was not found"]
+pub const ktx_error_code_e_KTX_NOT_FOUND: ktx_error_code_e = 12;
+#[doc = "< Not enough memory to complete the operation."]
+pub const ktx_error_code_e_KTX_OUT_OF_MEMORY: ktx_error_code_e = 13;
+#[doc = "< Transcoding of block compressed texture failed."]
+pub const ktx_error_code_e_KTX_TRANSCODE_FAILED: ktx_error_code_e = 14;
+#[doc = "< The file not a KTX file"]
+pub const ktx_error_code_e_KTX_UNKNOWN_FILE_FORMAT: ktx_error_code_e = 15;
+#[doc = "< The KTX file specifies an unsupported texture type."]
+pub const ktx_error_code_e_KTX_UNSUPPORTED_TEXTURE_TYPE: ktx_error_code_e = 16;
+#[doc = "< Feature not included in in-use library or not yet implemented."]
+pub const ktx_error_code_e_KTX_UNSUPPORTED_FEATURE: ktx_error_code_e = 17;
+#[doc = "< Library dependency (OpenGL or Vulkan) not linked into application."]
+pub const ktx_error_code_e_KTX_LIBRARY_NOT_LINKED: ktx_error_code_e = 18;
+#[doc = "< For safety checks."]
+pub const ktx_error_code_e_KTX_ERROR_MAX_ENUM: ktx_error_code_e = 18;
+#[doc = " @~English\n @brief Error codes returned by library functions."]
+pub type ktx_error_code_e = ::std::os::raw::c_uint;
+#[doc = " @~English\n @brief Result codes returned by library functions."]
+pub use self::ktx_error_code_e as ktxResult;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxKVListEntry {
+    _unused: [u8; 0],
+}
+#[doc = " @class ktxHashList\n @~English\n @brief Opaque handle to a ktxHashList."]
+pub type ktxHashList = *mut ktxKVListEntry;
+#[doc = " @class ktxHashListEntry\n @~English\n @brief Opaque handle to an entry in a @ref ktxHashList."]
+pub type ktxHashListEntry = ktxKVListEntry;
+pub const ktxOrientationX_KTX_ORIENT_X_LEFT: ktxOrientationX = 108;
+pub const ktxOrientationX_KTX_ORIENT_X_RIGHT: ktxOrientationX = 114;
+pub type ktxOrientationX = ::std::os::raw::c_uint;
+pub const ktxOrientationY_KTX_ORIENT_Y_UP: ktxOrientationY = 117;
+pub const ktxOrientationY_KTX_ORIENT_Y_DOWN: ktxOrientationY = 100;
+pub type ktxOrientationY = ::std::os::raw::c_uint;
+pub const ktxOrientationZ_KTX_ORIENT_Z_IN: ktxOrientationZ = 105;
+pub const ktxOrientationZ_KTX_ORIENT_Z_OUT: ktxOrientationZ = 111;
+pub type ktxOrientationZ = ::std::os::raw::c_uint;
+pub const class_id_ktxTexture1_c: class_id = 1;
+pub const class_id_ktxTexture2_c: class_id = 2;
+pub type class_id = ::std::os::raw::c_uint;
+#[doc = " @~English\n @brief Struct describing the logical orientation of an image."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxOrientation {
+    #[doc = "< Orientation in X"]
+    pub x: ktxOrientationX,
+    #[doc = "< Orientation in Y"]
+    pub y: ktxOrientationY,
+    #[doc = "< Orientation in Z"]
+    pub z: ktxOrientationZ,
+}
+#[test]
+fn bindgen_test_layout_ktxOrientation() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxOrientation> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxOrientation>(),
+        12usize,
+        concat!("Size of: ", stringify!(ktxOrientation))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxOrientation>(),
+        4usize,
+        concat!("Alignment of ", stringify!(ktxOrientation))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxOrientation),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxOrientation),
+            "::",
+            stringify!(y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxOrientation),
+            "::",
+            stringify!(z)
+        )
+    );
+}
+#[doc = " @class ktxTexture\n @~English\n @brief Base class representing a texture.\n\n ktxTextures should be created only by one of the provided\n functions and these fields should be considered read-only."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture {
+    pub classId: class_id,
+    pub vtbl: *mut ktxTexture_vtbl,
+    pub vvtbl: *mut ktxTexture_vvtbl,
+    pub _protected: *mut ktxTexture_protected,
+    pub isArray: ktx_bool_t,
+    pub isCubemap: ktx_bool_t,
+    pub isCompressed: ktx_bool_t,
+    pub generateMipmaps: ktx_bool_t,
+    pub baseWidth: ktx_uint32_t,
+    pub baseHeight: ktx_uint32_t,
+    pub baseDepth: ktx_uint32_t,
+    pub numDimensions: ktx_uint32_t,
+    pub numLevels: ktx_uint32_t,
+    pub numLayers: ktx_uint32_t,
+    pub numFaces: ktx_uint32_t,
+    pub orientation: ktxOrientation,
+    pub kvDataHead: ktxHashList,
+    pub kvDataLen: ktx_uint32_t,
+    pub kvData: *mut ktx_uint8_t,
+    pub dataSize: ktx_size_t,
+    pub pData: *mut ktx_uint8_t,
+}
+#[test]
+fn bindgen_test_layout_ktxTexture() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxTexture> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxTexture>(),
+        120usize,
+        concat!("Size of: ", stringify!(ktxTexture))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxTexture>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ktxTexture))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classId) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(classId)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vtbl) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(vtbl)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vvtbl) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(vvtbl)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._protected) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(_protected)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isArray) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(isArray)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isCubemap) as usize - ptr as usize },
+        33usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(isCubemap)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isCompressed) as usize - ptr as usize },
+        34usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(isCompressed)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).generateMipmaps) as usize - ptr as usize },
+        35usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(generateMipmaps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseWidth) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(baseWidth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseHeight) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(baseHeight)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseDepth) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(baseDepth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numDimensions) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(numDimensions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLevels) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(numLevels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLayers) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(numLayers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numFaces) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(numFaces)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).orientation) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(orientation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvDataHead) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(kvDataHead)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvDataLen) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(kvDataLen)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvData) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(kvData)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dataSize) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(dataSize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pData) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture),
+            "::",
+            stringify!(pData)
+        )
+    );
+}
+#[doc = " @memberof ktxTexture\n @~English\n @brief Signature of function called by the <tt>ktxTexture_Iterate*</tt>\n        functions to receive image data.\n\n The function parameters are used to pass values which change for each image.\n Obtain values which are uniform across all images from the @c ktxTexture\n object.\n\n @param [in] miplevel        MIP level from 0 to the max level which is\n                             dependent on the texture size.\n @param [in] face            usually 0; for cube maps, one of the 6 cube\n                             faces in the order +X, -X, +Y, -Y, +Z, -Z,\n                             0 to 5.\n @param [in] width           width of the image.\n @param [in] height          height of the image or, for 1D textures\n                             textures, 1.\n @param [in] depth           depth of the image or, for 1D & 2D\n                             textures, 1.\n @param [in] faceLodSize     number of bytes of data pointed at by\n                             @p pixels.\n @param [in] pixels          pointer to the image data.\n @param [in,out] userdata    pointer for the application to pass data to and\n                             from the callback function."]
+pub type PFNKTXITERCB = ::std::option::Option<
+    unsafe extern "C" fn(
+        miplevel: ::std::os::raw::c_int,
+        face: ::std::os::raw::c_int,
+        width: ::std::os::raw::c_int,
+        height: ::std::os::raw::c_int,
+        depth: ::std::os::raw::c_int,
+        faceLodSize: ktx_uint64_t,
+        pixels: *mut ::std::os::raw::c_void,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXDESTROY = ::std::option::Option<unsafe extern "C" fn(This: *mut ktxTexture)>;
+pub type PFNKTEXGETIMAGEOFFSET = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        level: ktx_uint32_t,
+        layer: ktx_uint32_t,
+        faceSlice: ktx_uint32_t,
+        pOffset: *mut ktx_size_t,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXGETDATASIZEUNCOMPRESSED =
+    ::std::option::Option<unsafe extern "C" fn(This: *mut ktxTexture) -> ktx_size_t>;
+pub type PFNKTEXGETIMAGESIZE = ::std::option::Option<
+    unsafe extern "C" fn(This: *mut ktxTexture, level: ktx_uint32_t) -> ktx_size_t,
+>;
+pub type PFNKTEXITERATELEVELS = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        iterCb: PFNKTXITERCB,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXITERATELOADLEVELFACES = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        iterCb: PFNKTXITERCB,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXLOADIMAGEDATA = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        pBuffer: *mut ktx_uint8_t,
+        bufSize: ktx_size_t,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXNEEDSTRANSCODING =
+    ::std::option::Option<unsafe extern "C" fn(This: *mut ktxTexture) -> ktx_bool_t>;
+pub type PFNKTEXSETIMAGEFROMMEMORY = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        level: ktx_uint32_t,
+        layer: ktx_uint32_t,
+        faceSlice: ktx_uint32_t,
+        src: *const ktx_uint8_t,
+        srcSize: ktx_size_t,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXSETIMAGEFROMSTDIOSTREAM = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        level: ktx_uint32_t,
+        layer: ktx_uint32_t,
+        faceSlice: ktx_uint32_t,
+        src: *mut FILE,
+        srcSize: ktx_size_t,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXWRITETOSTDIOSTREAM = ::std::option::Option<
+    unsafe extern "C" fn(This: *mut ktxTexture, dstsstr: *mut FILE) -> ktx_error_code_e,
+>;
+pub type PFNKTEXWRITETONAMEDFILE = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        dstname: *const ::std::os::raw::c_char,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXWRITETOMEMORY = ::std::option::Option<
+    unsafe extern "C" fn(
+        This: *mut ktxTexture,
+        bytes: *mut *mut ktx_uint8_t,
+        size: *mut ktx_size_t,
+    ) -> ktx_error_code_e,
+>;
+pub type PFNKTEXWRITETOSTREAM = ::std::option::Option<
+    unsafe extern "C" fn(This: *mut ktxTexture, dststr: *mut ktxStream) -> ktx_error_code_e,
+>;
+#[doc = " @memberof ktxTexture\n @~English\n @brief Table of virtual ktxTexture methods."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture_vtbl {
+    pub Destroy: PFNKTEXDESTROY,
+    pub GetImageOffset: PFNKTEXGETIMAGEOFFSET,
+    pub GetDataSizeUncompressed: PFNKTEXGETDATASIZEUNCOMPRESSED,
+    pub GetImageSize: PFNKTEXGETIMAGESIZE,
+    pub IterateLevels: PFNKTEXITERATELEVELS,
+    pub IterateLoadLevelFaces: PFNKTEXITERATELOADLEVELFACES,
+    pub NeedsTranscoding: PFNKTEXNEEDSTRANSCODING,
+    pub LoadImageData: PFNKTEXLOADIMAGEDATA,
+    pub SetImageFromMemory: PFNKTEXSETIMAGEFROMMEMORY,
+    pub SetImageFromStdioStream: PFNKTEXSETIMAGEFROMSTDIOSTREAM,
+    pub WriteToStdioStream: PFNKTEXWRITETOSTDIOSTREAM,
+    pub WriteToNamedFile: PFNKTEXWRITETONAMEDFILE,
+    pub WriteToMemory: PFNKTEXWRITETOMEMORY,
+    pub WriteToStream: PFNKTEXWRITETOSTREAM,
+}
+#[test]
+fn bindgen_test_layout_ktxTexture_vtbl() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxTexture_vtbl> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxTexture_vtbl>(),
+        112usize,
+        concat!("Size of: ", stringify!(ktxTexture_vtbl))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxTexture_vtbl>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ktxTexture_vtbl))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).Destroy) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(Destroy)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).GetImageOffset) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(GetImageOffset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).GetDataSizeUncompressed) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(GetDataSizeUncompressed)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).GetImageSize) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(GetImageSize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).IterateLevels) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(IterateLevels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).IterateLoadLevelFaces) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(IterateLoadLevelFaces)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).NeedsTranscoding) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(NeedsTranscoding)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).LoadImageData) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(LoadImageData)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).SetImageFromMemory) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(SetImageFromMemory)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).SetImageFromStdioStream) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(SetImageFromStdioStream)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).WriteToStdioStream) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(WriteToStdioStream)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).WriteToNamedFile) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(WriteToNamedFile)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).WriteToMemory) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(WriteToMemory)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).WriteToStream) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture_vtbl),
+            "::",
+            stringify!(WriteToStream)
+        )
+    );
+}
+#[doc = " @class ktxTexture1\n @~English\n @brief Class representing a KTX version 1 format texture.\n\n ktxTextures should be created only by one of the ktxTexture_Create*\n functions and these fields should be considered read-only."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture1 {
+    pub classId: class_id,
+    pub vtbl: *mut ktxTexture_vtbl,
+    pub vvtbl: *mut ktxTexture_vvtbl,
+    pub _protected: *mut ktxTexture_protected,
+    pub isArray: ktx_bool_t,
+    pub isCubemap: ktx_bool_t,
+    pub isCompressed: ktx_bool_t,
+    pub generateMipmaps: ktx_bool_t,
+    pub baseWidth: ktx_uint32_t,
+    pub baseHeight: ktx_uint32_t,
+    pub baseDepth: ktx_uint32_t,
+    pub numDimensions: ktx_uint32_t,
+    pub numLevels: ktx_uint32_t,
+    pub numLayers: ktx_uint32_t,
+    pub numFaces: ktx_uint32_t,
+    pub orientation: ktxOrientation,
+    pub kvDataHead: ktxHashList,
+    pub kvDataLen: ktx_uint32_t,
+    pub kvData: *mut ktx_uint8_t,
+    pub dataSize: ktx_size_t,
+    pub pData: *mut ktx_uint8_t,
+    #[doc = "< Format of the texture data, e.g., GL_RGB."]
+    pub glFormat: ktx_uint32_t,
+    #[doc = "< Internal format of the texture data,\ne.g., GL_RGB8."]
+    pub glInternalformat: ktx_uint32_t,
+    #[doc = "< Base format of the texture data,\ne.g., GL_RGB."]
+    pub glBaseInternalformat: ktx_uint32_t,
+    #[doc = "< Type of the texture data, e.g, GL_UNSIGNED_BYTE."]
+    pub glType: ktx_uint32_t,
+    #[doc = "< Private data."]
+    pub _private: *mut ktxTexture1_private,
+}
+#[test]
+fn bindgen_test_layout_ktxTexture1() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxTexture1> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxTexture1>(),
+        144usize,
+        concat!("Size of: ", stringify!(ktxTexture1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxTexture1>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ktxTexture1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classId) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(classId)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vtbl) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(vtbl)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vvtbl) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(vvtbl)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._protected) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(_protected)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isArray) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(isArray)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isCubemap) as usize - ptr as usize },
+        33usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(isCubemap)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isCompressed) as usize - ptr as usize },
+        34usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(isCompressed)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).generateMipmaps) as usize - ptr as usize },
+        35usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(generateMipmaps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseWidth) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(baseWidth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseHeight) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(baseHeight)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseDepth) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(baseDepth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numDimensions) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(numDimensions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLevels) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(numLevels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLayers) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(numLayers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numFaces) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(numFaces)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).orientation) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(orientation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvDataHead) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(kvDataHead)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvDataLen) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(kvDataLen)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvData) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(kvData)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dataSize) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(dataSize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pData) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(pData)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).glFormat) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(glFormat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).glInternalformat) as usize - ptr as usize },
+        124usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(glInternalformat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).glBaseInternalformat) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(glBaseInternalformat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).glType) as usize - ptr as usize },
+        132usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(glType)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._private) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture1),
+            "::",
+            stringify!(_private)
+        )
+    );
+}
+#[doc = "< No supercompression."]
+pub const ktxSupercmpScheme_KTX_SS_NONE: ktxSupercmpScheme = 0;
+#[doc = "< Basis LZ supercompression."]
+pub const ktxSupercmpScheme_KTX_SS_BASIS_LZ: ktxSupercmpScheme = 1;
+#[doc = "< ZStd supercompression."]
+pub const ktxSupercmpScheme_KTX_SS_ZSTD: ktxSupercmpScheme = 2;
+pub const ktxSupercmpScheme_KTX_SS_BEGIN_RANGE: ktxSupercmpScheme = 0;
+pub const ktxSupercmpScheme_KTX_SS_END_RANGE: ktxSupercmpScheme = 2;
+pub const ktxSupercmpScheme_KTX_SS_BEGIN_VENDOR_RANGE: ktxSupercmpScheme = 65536;
+pub const ktxSupercmpScheme_KTX_SS_END_VENDOR_RANGE: ktxSupercmpScheme = 131071;
+pub const ktxSupercmpScheme_KTX_SS_BEGIN_RESERVED: ktxSupercmpScheme = 131072;
+pub const ktxSupercmpScheme_KTX_SUPERCOMPRESSION_BASIS: ktxSupercmpScheme = 1;
+pub const ktxSupercmpScheme_KTX_SUPERCOMPRESSION_ZSTD: ktxSupercmpScheme = 2;
+#[doc = " @~English\n @brief Enumerators identifying the supercompression scheme."]
+pub type ktxSupercmpScheme = ::std::os::raw::c_uint;
+#[doc = " @class ktxTexture2\n @~English\n @brief Class representing a KTX version 2 format texture.\n\n ktxTextures should be created only by one of the ktxTexture_Create*\n functions and these fields should be considered read-only."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture2 {
+    pub classId: class_id,
+    pub vtbl: *mut ktxTexture_vtbl,
+    pub vvtbl: *mut ktxTexture_vvtbl,
+    pub _protected: *mut ktxTexture_protected,
+    pub isArray: ktx_bool_t,
+    pub isCubemap: ktx_bool_t,
+    pub isCompressed: ktx_bool_t,
+    pub generateMipmaps: ktx_bool_t,
+    pub baseWidth: ktx_uint32_t,
+    pub baseHeight: ktx_uint32_t,
+    pub baseDepth: ktx_uint32_t,
+    pub numDimensions: ktx_uint32_t,
+    pub numLevels: ktx_uint32_t,
+    pub numLayers: ktx_uint32_t,
+    pub numFaces: ktx_uint32_t,
+    pub orientation: ktxOrientation,
+    pub kvDataHead: ktxHashList,
+    pub kvDataLen: ktx_uint32_t,
+    pub kvData: *mut ktx_uint8_t,
+    pub dataSize: ktx_size_t,
+    pub pData: *mut ktx_uint8_t,
+    pub vkFormat: ktx_uint32_t,
+    pub pDfd: *mut ktx_uint32_t,
+    pub supercompressionScheme: ktxSupercmpScheme,
+    pub isVideo: ktx_bool_t,
+    pub duration: ktx_uint32_t,
+    pub timescale: ktx_uint32_t,
+    pub loopcount: ktx_uint32_t,
+    #[doc = "< Private data."]
+    pub _private: *mut ktxTexture2_private,
+}
+#[test]
+fn bindgen_test_layout_ktxTexture2() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxTexture2> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxTexture2>(),
+        168usize,
+        concat!("Size of: ", stringify!(ktxTexture2))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxTexture2>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ktxTexture2))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classId) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(classId)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vtbl) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(vtbl)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vvtbl) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(vvtbl)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._protected) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(_protected)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isArray) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(isArray)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isCubemap) as usize - ptr as usize },
+        33usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(isCubemap)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isCompressed) as usize - ptr as usize },
+        34usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(isCompressed)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).generateMipmaps) as usize - ptr as usize },
+        35usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(generateMipmaps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseWidth) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(baseWidth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseHeight) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(baseHeight)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseDepth) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(baseDepth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numDimensions) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(numDimensions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLevels) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(numLevels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLayers) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(numLayers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numFaces) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(numFaces)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).orientation) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(orientation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvDataHead) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(kvDataHead)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvDataLen) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(kvDataLen)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kvData) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(kvData)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dataSize) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(dataSize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pData) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(pData)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vkFormat) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(vkFormat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pDfd) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(pDfd)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).supercompressionScheme) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(supercompressionScheme)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isVideo) as usize - ptr as usize },
+        140usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(isVideo)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).duration) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(duration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timescale) as usize - ptr as usize },
+        148usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(timescale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).loopcount) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(loopcount)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr)._private) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTexture2),
+            "::",
+            stringify!(_private)
+        )
+    );
+}
+#[doc = " @memberof ktxTexture\n @~English\n @brief Structure for passing texture information to ktxTexture1_Create() and\n        ktxTexture2_Create().\n\n @sa ktxTexture1_Create() and ktxTexture2_Create()."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTextureCreateInfo {
+    #[doc = "< Internal format for the texture, e.g.,\nGL_RGB8. Ignored when creating a\nktxTexture2."]
+    pub glInternalformat: ktx_uint32_t,
+    #[doc = "< VkFormat for texture. Ignored when creating a\nktxTexture1."]
+    pub vkFormat: ktx_uint32_t,
+    #[doc = "< Pointer to DFD. Used only when creating a\nktxTexture2 and only if vkFormat is\nVK_FORMAT_UNDEFINED."]
+    pub pDfd: *mut ktx_uint32_t,
+    #[doc = "< Width of the base level of the texture."]
+    pub baseWidth: ktx_uint32_t,
+    #[doc = "< Height of the base level of the texture."]
+    pub baseHeight: ktx_uint32_t,
+    #[doc = "< Depth of the base level of the texture."]
+    pub baseDepth: ktx_uint32_t,
+    #[doc = "< Number of dimensions in the texture, 1, 2\nor 3."]
+    pub numDimensions: ktx_uint32_t,
+    #[doc = "< Number of mip levels in the texture. Should be\n1 if @c generateMipmaps is KTX_TRUE;"]
+    pub numLevels: ktx_uint32_t,
+    #[doc = "< Number of array layers in the texture."]
+    pub numLayers: ktx_uint32_t,
+    #[doc = "< Number of faces: 6 for cube maps, 1 otherwise."]
+    pub numFaces: ktx_uint32_t,
+    #[doc = "< Set to KTX_TRUE if the texture is to be an\narray texture. Means OpenGL will use a\nGL_TEXTURE_*_ARRAY target."]
+    pub isArray: ktx_bool_t,
+    #[doc = "< Set to KTX_TRUE if mipmaps should be\ngenerated for the texture when loading\ninto a 3D API."]
+    pub generateMipmaps: ktx_bool_t,
+}
+#[test]
+fn bindgen_test_layout_ktxTextureCreateInfo() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxTextureCreateInfo> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxTextureCreateInfo>(),
+        48usize,
+        concat!("Size of: ", stringify!(ktxTextureCreateInfo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxTextureCreateInfo>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ktxTextureCreateInfo))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).glInternalformat) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(glInternalformat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vkFormat) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(vkFormat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pDfd) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(pDfd)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseWidth) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(baseWidth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseHeight) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(baseHeight)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseDepth) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(baseDepth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numDimensions) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(numDimensions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLevels) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(numLevels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numLayers) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(numLayers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numFaces) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(numFaces)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).isArray) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(isArray)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).generateMipmaps) as usize - ptr as usize },
+        45usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxTextureCreateInfo),
+            "::",
+            stringify!(generateMipmaps)
+        )
+    );
+}
+#[doc = "< Don't allocate any image storage."]
+pub const ktxTextureCreateStorageEnum_KTX_TEXTURE_CREATE_NO_STORAGE: ktxTextureCreateStorageEnum =
+    0;
+#[doc = "< Allocate image storage."]
+pub const ktxTextureCreateStorageEnum_KTX_TEXTURE_CREATE_ALLOC_STORAGE:
+    ktxTextureCreateStorageEnum = 1;
+#[doc = " @memberof ktxTexture\n @~English\n @brief Enum for requesting, or not, allocation of storage for images.\n\n @sa ktxTexture1_Create() and ktxTexture2_Create()."]
+pub type ktxTextureCreateStorageEnum = ::std::os::raw::c_uint;
+pub const ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_NO_FLAGS: ktxTextureCreateFlagBits = 0;
+pub const ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_LOAD_IMAGE_DATA_BIT:
+    ktxTextureCreateFlagBits = 1;
+pub const ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_RAW_KVDATA_BIT: ktxTextureCreateFlagBits = 2;
+pub const ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_SKIP_KVDATA_BIT: ktxTextureCreateFlagBits = 4;
+#[doc = " @memberof ktxTexture\n @~English\n @brief Flags for requesting services during creation.\n\n @sa ktxTexture_CreateFrom*"]
+pub type ktxTextureCreateFlagBits = ::std::os::raw::c_uint;
+#[doc = " @memberof ktxTexture\n @~English\n @brief Type for TextureCreateFlags parameters.\n\n @sa ktxTexture_CreateFrom*()"]
+pub type ktxTextureCreateFlags = ktx_uint32_t;
+pub type ktx_off_t = off_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxMem {
+    _unused: [u8; 0],
+}
+pub const streamType_eStreamTypeFile: streamType = 1;
+pub const streamType_eStreamTypeMemory: streamType = 2;
+pub const streamType_eStreamTypeCustom: streamType = 3;
+pub type streamType = ::std::os::raw::c_uint;
+#[doc = " @~English\n @brief type for a pointer to a stream reading function"]
+pub type ktxStream_read = ::std::option::Option<
+    unsafe extern "C" fn(
+        str_: *mut ktxStream,
+        dst: *mut ::std::os::raw::c_void,
+        count: ktx_size_t,
+    ) -> ktx_error_code_e,
+>;
+#[doc = " @~English\n @brief type for a pointer to a stream skipping function"]
+pub type ktxStream_skip = ::std::option::Option<
+    unsafe extern "C" fn(str_: *mut ktxStream, count: ktx_size_t) -> ktx_error_code_e,
+>;
+#[doc = " @~English\n @brief type for a pointer to a stream writing function"]
+pub type ktxStream_write = ::std::option::Option<
+    unsafe extern "C" fn(
+        str_: *mut ktxStream,
+        src: *const ::std::os::raw::c_void,
+        size: ktx_size_t,
+        count: ktx_size_t,
+    ) -> ktx_error_code_e,
+>;
+#[doc = " @~English\n @brief type for a pointer to a stream position query function"]
+pub type ktxStream_getpos = ::std::option::Option<
+    unsafe extern "C" fn(str_: *mut ktxStream, offset: *mut ktx_off_t) -> ktx_error_code_e,
+>;
+#[doc = " @~English\n @brief type for a pointer to a stream position query function"]
+pub type ktxStream_setpos = ::std::option::Option<
+    unsafe extern "C" fn(str_: *mut ktxStream, offset: ktx_off_t) -> ktx_error_code_e,
+>;
+#[doc = " @~English\n @brief type for a pointer to a stream size query function"]
+pub type ktxStream_getsize = ::std::option::Option<
+    unsafe extern "C" fn(str_: *mut ktxStream, size: *mut ktx_size_t) -> ktx_error_code_e,
+>;
+#[doc = " @~English\n @brief Destruct a stream"]
+pub type ktxStream_destruct = ::std::option::Option<unsafe extern "C" fn(str_: *mut ktxStream)>;
+#[doc = " @~English\n\n @brief Interface of ktxStream.\n\n @author Maksim Kolesin\n @author Georg Kolling, Imagination Technology\n @author Mark Callow, HI Corporation"]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ktxStream {
+    #[doc = "< pointer to function for reading bytes."]
+    pub read: ktxStream_read,
+    #[doc = "< pointer to function for skipping bytes."]
+    pub skip: ktxStream_skip,
+    #[doc = "< pointer to function for writing bytes."]
+    pub write: ktxStream_write,
+    #[doc = "< pointer to function for getting current position in stream."]
+    pub getpos: ktxStream_getpos,
+    #[doc = "< pointer to function for setting current position in stream."]
+    pub setpos: ktxStream_setpos,
+    #[doc = "< pointer to function for querying size."]
+    pub getsize: ktxStream_getsize,
+    #[doc = "< destruct the stream."]
+    pub destruct: ktxStream_destruct,
+    pub type_: streamType,
+    #[doc = "< pointer to the stream data."]
+    pub data: ktxStream__bindgen_ty_1,
+    #[doc = "< used by FileStream for stdin."]
+    pub readpos: ktx_off_t,
+    #[doc = "< Close FILE* or dispose of memory on destruct."]
+    pub closeOnDestruct: ktx_bool_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ktxStream__bindgen_ty_1 {
+    #[doc = "< a stdio FILE pointer for a ktxFileStream."]
+    pub file: *mut FILE,
+    #[doc = "< a pointer to a ktxMem struct for a ktxMemStream."]
+    pub mem: *mut ktxMem,
+    #[doc = "< pointer to a struct for custom streams."]
+    pub custom_ptr: ktxStream__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxStream__bindgen_ty_1__bindgen_ty_1 {
+    #[doc = "< pointer to the data."]
+    pub address: *mut ::std::os::raw::c_void,
+    #[doc = "< pointer to a memory allocator."]
+    pub allocatorAddress: *mut ::std::os::raw::c_void,
+    #[doc = "< size of the data."]
+    pub size: ktx_size_t,
+}
+#[test]
+fn bindgen_test_layout_ktxStream__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxStream__bindgen_ty_1__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxStream__bindgen_ty_1__bindgen_ty_1>(),
+        24usize,
+        concat!(
+            "Size of: ",
+            stringify!(ktxStream__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxStream__bindgen_ty_1__bindgen_ty_1>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(ktxStream__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).address) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(address)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).allocatorAddress) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(allocatorAddress)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(size)
+        )
+    );
+}
+#[test]
+fn bindgen_test_layout_ktxStream__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxStream__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxStream__bindgen_ty_1>(),
+        24usize,
+        concat!("Size of: ", stringify!(ktxStream__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxStream__bindgen_ty_1>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ktxStream__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).file) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream__bindgen_ty_1),
+            "::",
+            stringify!(file)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mem) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream__bindgen_ty_1),
+            "::",
+            stringify!(mem)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).custom_ptr) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream__bindgen_ty_1),
+            "::",
+            stringify!(custom_ptr)
+        )
+    );
+}
+#[test]
+fn bindgen_test_layout_ktxStream() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxStream> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxStream>(),
+        104usize,
+        concat!("Size of: ", stringify!(ktxStream))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxStream>(),
+        8usize,
+        concat!("Alignment of ", stringify!(ktxStream))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).read) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(read)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).skip) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(skip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).write) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(write)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).getpos) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(getpos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).setpos) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(setpos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).getsize) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(getsize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).destruct) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(destruct)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).readpos) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(readpos)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).closeOnDestruct) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxStream),
+            "::",
+            stringify!(closeOnDestruct)
+        )
+    );
+}
+extern "C" {
+    pub fn ktxTexture_CreateFromStdioStream(
+        stdioStream: *mut FILE,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture_CreateFromNamedFile(
+        filename: *const ::std::os::raw::c_char,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture_CreateFromMemory(
+        bytes: *const ktx_uint8_t,
+        size: ktx_size_t,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture_CreateFromStream(
+        stream: *mut ktxStream,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture_GetData(This: *mut ktxTexture) -> *mut ktx_uint8_t;
+}
+extern "C" {
+    pub fn ktxTexture_GetRowPitch(This: *mut ktxTexture, level: ktx_uint32_t) -> ktx_uint32_t;
+}
+extern "C" {
+    pub fn ktxTexture_GetElementSize(This: *mut ktxTexture) -> ktx_uint32_t;
+}
+extern "C" {
+    pub fn ktxTexture_GetDataSize(This: *mut ktxTexture) -> ktx_size_t;
+}
+extern "C" {
+    pub fn ktxTexture_IterateLevelFaces(
+        This: *mut ktxTexture,
+        iterCb: PFNKTXITERCB,
+        userdata: *mut ::std::os::raw::c_void,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_Create(
+        createInfo: *mut ktxTextureCreateInfo,
+        storageAllocation: ktxTextureCreateStorageEnum,
+        newTex: *mut *mut ktxTexture1,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_CreateFromStdioStream(
+        stdioStream: *mut FILE,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture1,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_CreateFromNamedFile(
+        filename: *const ::std::os::raw::c_char,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture1,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_CreateFromMemory(
+        bytes: *const ktx_uint8_t,
+        size: ktx_size_t,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture1,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_CreateFromStream(
+        stream: *mut ktxStream,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture1,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_NeedsTranscoding(This: *mut ktxTexture1) -> ktx_bool_t;
+}
+extern "C" {
+    pub fn ktxTexture1_WriteKTX2ToStdioStream(
+        This: *mut ktxTexture1,
+        dstsstr: *mut FILE,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_WriteKTX2ToNamedFile(
+        This: *mut ktxTexture1,
+        dstname: *const ::std::os::raw::c_char,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_WriteKTX2ToMemory(
+        This: *mut ktxTexture1,
+        bytes: *mut *mut ktx_uint8_t,
+        size: *mut ktx_size_t,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture1_WriteKTX2ToStream(
+        This: *mut ktxTexture1,
+        dststr: *mut ktxStream,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_Create(
+        createInfo: *mut ktxTextureCreateInfo,
+        storageAllocation: ktxTextureCreateStorageEnum,
+        newTex: *mut *mut ktxTexture2,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_CreateCopy(
+        orig: *mut ktxTexture2,
+        newTex: *mut *mut ktxTexture2,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_CreateFromStdioStream(
+        stdioStream: *mut FILE,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture2,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_CreateFromNamedFile(
+        filename: *const ::std::os::raw::c_char,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture2,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_CreateFromMemory(
+        bytes: *const ktx_uint8_t,
+        size: ktx_size_t,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture2,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_CreateFromStream(
+        stream: *mut ktxStream,
+        createFlags: ktxTextureCreateFlags,
+        newTex: *mut *mut ktxTexture2,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_CompressBasis(
+        This: *mut ktxTexture2,
+        quality: ktx_uint32_t,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_DeflateZstd(This: *mut ktxTexture2, level: ktx_uint32_t)
+        -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_GetComponentInfo(
+        This: *mut ktxTexture2,
+        numComponents: *mut ktx_uint32_t,
+        componentByteLength: *mut ktx_uint32_t,
+    );
+}
+extern "C" {
+    pub fn ktxTexture2_GetNumComponents(This: *mut ktxTexture2) -> ktx_uint32_t;
+}
+extern "C" {
+    pub fn ktxTexture2_GetOETF_e(This: *mut ktxTexture2) -> khr_df_transfer_e;
+}
+extern "C" {
+    pub fn ktxTexture2_GetOETF(This: *mut ktxTexture2) -> ktx_uint32_t;
+}
+extern "C" {
+    pub fn ktxTexture2_GetColorModel_e(This: *mut ktxTexture2) -> khr_df_model_e;
+}
+extern "C" {
+    pub fn ktxTexture2_GetPremultipliedAlpha(This: *mut ktxTexture2) -> ktx_bool_t;
+}
+extern "C" {
+    pub fn ktxTexture2_NeedsTranscoding(This: *mut ktxTexture2) -> ktx_bool_t;
+}
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_FASTEST: ktx_pack_uastc_flag_bits_e = 0;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_FASTER: ktx_pack_uastc_flag_bits_e = 1;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_DEFAULT: ktx_pack_uastc_flag_bits_e = 2;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_SLOWER: ktx_pack_uastc_flag_bits_e = 3;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_VERYSLOW: ktx_pack_uastc_flag_bits_e = 4;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_MAX_LEVEL: ktx_pack_uastc_flag_bits_e = 4;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_MASK: ktx_pack_uastc_flag_bits_e = 15;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_FAVOR_UASTC_ERROR: ktx_pack_uastc_flag_bits_e =
+    8;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_FAVOR_BC7_ERROR: ktx_pack_uastc_flag_bits_e =
+    16;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_ETC1_FASTER_HINTS: ktx_pack_uastc_flag_bits_e =
+    64;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_ETC1_FASTEST_HINTS: ktx_pack_uastc_flag_bits_e =
+    128;
+pub const ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC__ETC1_DISABLE_FLIP_AND_INDIVIDUAL:
+    ktx_pack_uastc_flag_bits_e = 256;
+#[doc = " @~English\n @brief Flags specifiying UASTC encoding options."]
+pub type ktx_pack_uastc_flag_bits_e = ::std::os::raw::c_uint;
+pub type ktx_pack_uastc_flags = ktx_uint32_t;
+pub const ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_FASTEST:
+    ktx_pack_astc_quality_levels_e = 0;
+pub const ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_FAST:
+    ktx_pack_astc_quality_levels_e = 10;
+pub const ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_MEDIUM:
+    ktx_pack_astc_quality_levels_e = 60;
+pub const ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_THOROUGH:
+    ktx_pack_astc_quality_levels_e = 98;
+pub const ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_EXHAUSTIVE:
+    ktx_pack_astc_quality_levels_e = 100;
+pub const ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_MAX:
+    ktx_pack_astc_quality_levels_e = 100;
+#[doc = " @~English\n @brief Options specifiying ASTC encoding quality levels."]
+pub type ktx_pack_astc_quality_levels_e = ::std::os::raw::c_uint;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x4:
+    ktx_pack_astc_block_dimension_e = 0;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x4:
+    ktx_pack_astc_block_dimension_e = 1;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x5:
+    ktx_pack_astc_block_dimension_e = 2;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x5:
+    ktx_pack_astc_block_dimension_e = 3;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x6:
+    ktx_pack_astc_block_dimension_e = 4;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_8x5:
+    ktx_pack_astc_block_dimension_e = 5;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_8x6:
+    ktx_pack_astc_block_dimension_e = 6;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x5:
+    ktx_pack_astc_block_dimension_e = 7;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x6:
+    ktx_pack_astc_block_dimension_e = 8;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_8x8:
+    ktx_pack_astc_block_dimension_e = 9;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x8:
+    ktx_pack_astc_block_dimension_e = 10;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x10:
+    ktx_pack_astc_block_dimension_e = 11;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_12x10:
+    ktx_pack_astc_block_dimension_e = 12;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_12x12:
+    ktx_pack_astc_block_dimension_e = 13;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_3x3x3:
+    ktx_pack_astc_block_dimension_e = 14;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x3x3:
+    ktx_pack_astc_block_dimension_e = 15;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x4x3:
+    ktx_pack_astc_block_dimension_e = 16;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x4x4:
+    ktx_pack_astc_block_dimension_e = 17;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x4x4:
+    ktx_pack_astc_block_dimension_e = 18;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x5x4:
+    ktx_pack_astc_block_dimension_e = 19;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x5x5:
+    ktx_pack_astc_block_dimension_e = 20;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x5x5:
+    ktx_pack_astc_block_dimension_e = 21;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x6x5:
+    ktx_pack_astc_block_dimension_e = 22;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x6x6:
+    ktx_pack_astc_block_dimension_e = 23;
+pub const ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_MAX:
+    ktx_pack_astc_block_dimension_e = 23;
+#[doc = " @~English\n @brief Options specifiying ASTC encoding block dimensions"]
+pub type ktx_pack_astc_block_dimension_e = ::std::os::raw::c_uint;
+pub const ktx_pack_astc_encoder_mode_e_KTX_PACK_ASTC_ENCODER_MODE_DEFAULT:
+    ktx_pack_astc_encoder_mode_e = 0;
+pub const ktx_pack_astc_encoder_mode_e_KTX_PACK_ASTC_ENCODER_MODE_LDR:
+    ktx_pack_astc_encoder_mode_e = 1;
+pub const ktx_pack_astc_encoder_mode_e_KTX_PACK_ASTC_ENCODER_MODE_HDR:
+    ktx_pack_astc_encoder_mode_e = 2;
+pub const ktx_pack_astc_encoder_mode_e_KTX_PACK_ASTC_ENCODER_MODE_MAX:
+    ktx_pack_astc_encoder_mode_e = 2;
+#[doc = " @~English\n @brief Options specifying ASTC encoder profile mode\n        This and function is used later to derive the profile."]
+pub type ktx_pack_astc_encoder_mode_e = ::std::os::raw::c_uint;
+extern "C" {
+    pub static KTX_ETC1S_DEFAULT_COMPRESSION_LEVEL: ktx_uint32_t;
+}
+#[doc = " @memberof ktxTexture\n @~English\n @brief Structure for passing extended parameters to\n        ktxTexture_CompressAstc.\n\n Passing a struct initialized to 0 (e.g. \" = {0};\") will use blockDimension\n 4x4, mode LDR and qualityLevel FASTEST. Setting qualityLevel to\n KTX_PACK_ASTC_QUALITY_LEVEL_MEDIUM is recommended."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxAstcParams {
+    pub structSize: ktx_uint32_t,
+    pub verbose: ktx_bool_t,
+    pub threadCount: ktx_uint32_t,
+    pub blockDimension: ktx_uint32_t,
+    pub mode: ktx_uint32_t,
+    pub qualityLevel: ktx_uint32_t,
+    pub normalMap: ktx_bool_t,
+    pub perceptual: ktx_bool_t,
+    pub inputSwizzle: [::std::os::raw::c_char; 4usize],
+}
+#[test]
+fn bindgen_test_layout_ktxAstcParams() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxAstcParams> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxAstcParams>(),
+        32usize,
+        concat!("Size of: ", stringify!(ktxAstcParams))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxAstcParams>(),
+        4usize,
+        concat!("Alignment of ", stringify!(ktxAstcParams))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).structSize) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(structSize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).verbose) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(verbose)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).threadCount) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(threadCount)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).blockDimension) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(blockDimension)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mode) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(mode)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qualityLevel) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(qualityLevel)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).normalMap) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(normalMap)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).perceptual) as usize - ptr as usize },
+        25usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(perceptual)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).inputSwizzle) as usize - ptr as usize },
+        26usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxAstcParams),
+            "::",
+            stringify!(inputSwizzle)
+        )
+    );
+}
+extern "C" {
+    pub fn ktxTexture2_CompressAstcEx(
+        This: *mut ktxTexture2,
+        params: *mut ktxAstcParams,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxTexture2_CompressAstc(
+        This: *mut ktxTexture2,
+        quality: ktx_uint32_t,
+    ) -> ktx_error_code_e;
+}
+#[doc = " @memberof ktxTexture2\n @~English\n @brief Structure for passing extended parameters to\n        ktxTexture2_CompressBasisEx().\n\n If you only want default values, use ktxTexture2_CompressBasis(). Here, at a minimum you\n must initialize the structure as follows:\n @code\n  ktxBasisParams params = {0};\n  params.structSize = sizeof(params);\n  params.compressionLevel = KTX_ETC1S_DEFAULT_COMPRESSION_LEVEL;\n @endcode\n\n @e compressionLevel has to be explicitly set because 0 is a valid @e compressionLevel\n but is not the default used by the BasisU encoder when no value is set. Only the other\n settings that are to be non-default must be non-zero."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxBasisParams {
+    pub structSize: ktx_uint32_t,
+    pub uastc: ktx_bool_t,
+    pub verbose: ktx_bool_t,
+    pub noSSE: ktx_bool_t,
+    pub threadCount: ktx_uint32_t,
+    pub compressionLevel: ktx_uint32_t,
+    pub qualityLevel: ktx_uint32_t,
+    pub maxEndpoints: ktx_uint32_t,
+    pub endpointRDOThreshold: f32,
+    pub maxSelectors: ktx_uint32_t,
+    pub selectorRDOThreshold: f32,
+    pub inputSwizzle: [::std::os::raw::c_char; 4usize],
+    pub normalMap: ktx_bool_t,
+    pub separateRGToRGB_A: ktx_bool_t,
+    pub preSwizzle: ktx_bool_t,
+    pub noEndpointRDO: ktx_bool_t,
+    pub noSelectorRDO: ktx_bool_t,
+    pub uastcFlags: ktx_pack_uastc_flags,
+    pub uastcRDO: ktx_bool_t,
+    pub uastcRDOQualityScalar: f32,
+    pub uastcRDODictSize: ktx_uint32_t,
+    pub uastcRDOMaxSmoothBlockErrorScale: f32,
+    pub uastcRDOMaxSmoothBlockStdDev: f32,
+    pub uastcRDODontFavorSimplerModes: ktx_bool_t,
+    pub uastcRDONoMultithreading: ktx_bool_t,
+}
+#[test]
+fn bindgen_test_layout_ktxBasisParams() {
+    const UNINIT: ::std::mem::MaybeUninit<ktxBasisParams> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ktxBasisParams>(),
+        76usize,
+        concat!("Size of: ", stringify!(ktxBasisParams))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ktxBasisParams>(),
+        4usize,
+        concat!("Alignment of ", stringify!(ktxBasisParams))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).structSize) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(structSize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uastc) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).verbose) as usize - ptr as usize },
+        5usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(verbose)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).noSSE) as usize - ptr as usize },
+        6usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(noSSE)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).threadCount) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(threadCount)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).compressionLevel) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(compressionLevel)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).qualityLevel) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(qualityLevel)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).maxEndpoints) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(maxEndpoints)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).endpointRDOThreshold) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(endpointRDOThreshold)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).maxSelectors) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(maxSelectors)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).selectorRDOThreshold) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(selectorRDOThreshold)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).inputSwizzle) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(inputSwizzle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).normalMap) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(normalMap)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).separateRGToRGB_A) as usize - ptr as usize },
+        41usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(separateRGToRGB_A)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).preSwizzle) as usize - ptr as usize },
+        42usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(preSwizzle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).noEndpointRDO) as usize - ptr as usize },
+        43usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(noEndpointRDO)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).noSelectorRDO) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(noSelectorRDO)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uastcFlags) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcFlags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uastcRDO) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcRDO)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uastcRDOQualityScalar) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcRDOQualityScalar)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uastcRDODictSize) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcRDODictSize)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).uastcRDOMaxSmoothBlockErrorScale) as usize - ptr as usize
+        },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcRDOMaxSmoothBlockErrorScale)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).uastcRDOMaxSmoothBlockStdDev) as usize - ptr as usize
+        },
+        68usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcRDOMaxSmoothBlockStdDev)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).uastcRDODontFavorSimplerModes) as usize - ptr as usize
+        },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcRDODontFavorSimplerModes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uastcRDONoMultithreading) as usize - ptr as usize },
+        73usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ktxBasisParams),
+            "::",
+            stringify!(uastcRDONoMultithreading)
+        )
+    );
+}
+extern "C" {
+    pub fn ktxTexture2_CompressBasisEx(
+        This: *mut ktxTexture2,
+        params: *mut ktxBasisParams,
+    ) -> ktx_error_code_e;
+}
+pub const ktx_transcode_fmt_e_KTX_TTF_ETC1_RGB: ktx_transcode_fmt_e = 0;
+pub const ktx_transcode_fmt_e_KTX_TTF_ETC2_RGBA: ktx_transcode_fmt_e = 1;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC1_RGB: ktx_transcode_fmt_e = 2;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC3_RGBA: ktx_transcode_fmt_e = 3;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC4_R: ktx_transcode_fmt_e = 4;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC5_RG: ktx_transcode_fmt_e = 5;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC7_RGBA: ktx_transcode_fmt_e = 6;
+pub const ktx_transcode_fmt_e_KTX_TTF_PVRTC1_4_RGB: ktx_transcode_fmt_e = 8;
+pub const ktx_transcode_fmt_e_KTX_TTF_PVRTC1_4_RGBA: ktx_transcode_fmt_e = 9;
+pub const ktx_transcode_fmt_e_KTX_TTF_ASTC_4x4_RGBA: ktx_transcode_fmt_e = 10;
+pub const ktx_transcode_fmt_e_KTX_TTF_PVRTC2_4_RGB: ktx_transcode_fmt_e = 18;
+pub const ktx_transcode_fmt_e_KTX_TTF_PVRTC2_4_RGBA: ktx_transcode_fmt_e = 19;
+pub const ktx_transcode_fmt_e_KTX_TTF_ETC2_EAC_R11: ktx_transcode_fmt_e = 20;
+pub const ktx_transcode_fmt_e_KTX_TTF_ETC2_EAC_RG11: ktx_transcode_fmt_e = 21;
+pub const ktx_transcode_fmt_e_KTX_TTF_RGBA32: ktx_transcode_fmt_e = 13;
+pub const ktx_transcode_fmt_e_KTX_TTF_RGB565: ktx_transcode_fmt_e = 14;
+pub const ktx_transcode_fmt_e_KTX_TTF_BGR565: ktx_transcode_fmt_e = 15;
+pub const ktx_transcode_fmt_e_KTX_TTF_RGBA4444: ktx_transcode_fmt_e = 16;
+pub const ktx_transcode_fmt_e_KTX_TTF_ETC: ktx_transcode_fmt_e = 22;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC1_OR_3: ktx_transcode_fmt_e = 23;
+pub const ktx_transcode_fmt_e_KTX_TTF_NOSELECTION: ktx_transcode_fmt_e = 2147483647;
+pub const ktx_transcode_fmt_e_KTX_TF_ETC1: ktx_transcode_fmt_e = 0;
+pub const ktx_transcode_fmt_e_KTX_TF_ETC2: ktx_transcode_fmt_e = 22;
+pub const ktx_transcode_fmt_e_KTX_TF_BC1: ktx_transcode_fmt_e = 2;
+pub const ktx_transcode_fmt_e_KTX_TF_BC3: ktx_transcode_fmt_e = 3;
+pub const ktx_transcode_fmt_e_KTX_TF_BC4: ktx_transcode_fmt_e = 4;
+pub const ktx_transcode_fmt_e_KTX_TF_BC5: ktx_transcode_fmt_e = 5;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC7_M6_RGB: ktx_transcode_fmt_e = 6;
+pub const ktx_transcode_fmt_e_KTX_TTF_BC7_M5_RGBA: ktx_transcode_fmt_e = 6;
+pub const ktx_transcode_fmt_e_KTX_TF_BC7_M6_OPAQUE_ONLY: ktx_transcode_fmt_e = 6;
+pub const ktx_transcode_fmt_e_KTX_TF_PVRTC1_4_OPAQUE_ONLY: ktx_transcode_fmt_e = 8;
+#[doc = " @~English\n @brief Enumerators for specifying the transcode target format.\n\n For BasisU/ETC1S format, @e Opaque and @e alpha here refer to 2 separate\n RGB images, a.k.a slices within the BasisU compressed data. For UASTC\n format they refer to the RGB and the alpha components of the UASTC data. If\n the original image had only 2 components, R will be in the opaque portion\n and G in the alpha portion. The R value will be replicated in the RGB\n components. In the case of BasisU the G value will be replicated in all 3\n components of the alpha slice. If the original image had only 1 component\n it's value is replicated in all 3 components of the opaque portion and\n there is no alpha.\n\n @note You should not transcode sRGB encoded data to @c KTX_TTF_BC4_R,\n @c KTX_TTF_BC5_RG, @c KTX_TTF_ETC2_EAC_R{,G}11, @c KTX_TTF_RGB565,\n @c KTX_TTF_BGR565 or @c KTX_TTF_RGBA4444 formats as neither OpenGL nor\n Vulkan support sRGB variants of these. Doing sRGB decoding in the shader\n will not produce correct results if any texture filtering is being used."]
+pub type ktx_transcode_fmt_e = ::std::os::raw::c_uint;
+pub const ktx_transcode_flag_bits_e_KTX_TF_PVRTC_DECODE_TO_NEXT_POW2: ktx_transcode_flag_bits_e = 2;
+pub const ktx_transcode_flag_bits_e_KTX_TF_TRANSCODE_ALPHA_DATA_TO_OPAQUE_FORMATS:
+    ktx_transcode_flag_bits_e = 4;
+pub const ktx_transcode_flag_bits_e_KTX_TF_HIGH_QUALITY: ktx_transcode_flag_bits_e = 32;
+#[doc = " @~English\n @brief Flags guiding transcoding of Basis Universal compressed textures."]
+pub type ktx_transcode_flag_bits_e = ::std::os::raw::c_uint;
+pub type ktx_transcode_flags = ktx_uint32_t;
+extern "C" {
+    pub fn ktxTexture2_TranscodeBasis(
+        This: *mut ktxTexture2,
+        fmt: ktx_transcode_fmt_e,
+        transcodeFlags: ktx_transcode_flags,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxErrorString(error: ktx_error_code_e) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn ktxSupercompressionSchemeString(
+        scheme: ktxSupercmpScheme,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn ktxTranscodeFormatString(format: ktx_transcode_fmt_e) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn ktxHashList_Create(ppHl: *mut *mut ktxHashList) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_CreateCopy(
+        ppHl: *mut *mut ktxHashList,
+        orig: ktxHashList,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_Construct(pHl: *mut ktxHashList);
+}
+extern "C" {
+    pub fn ktxHashList_ConstructCopy(pHl: *mut ktxHashList, orig: ktxHashList);
+}
+extern "C" {
+    pub fn ktxHashList_Destroy(head: *mut ktxHashList);
+}
+extern "C" {
+    pub fn ktxHashList_Destruct(head: *mut ktxHashList);
+}
+extern "C" {
+    pub fn ktxHashList_AddKVPair(
+        pHead: *mut ktxHashList,
+        key: *const ::std::os::raw::c_char,
+        valueLen: ::std::os::raw::c_uint,
+        value: *const ::std::os::raw::c_void,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_DeleteEntry(
+        pHead: *mut ktxHashList,
+        pEntry: *mut ktxHashListEntry,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_DeleteKVPair(
+        pHead: *mut ktxHashList,
+        key: *const ::std::os::raw::c_char,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_FindEntry(
+        pHead: *mut ktxHashList,
+        key: *const ::std::os::raw::c_char,
+        ppEntry: *mut *mut ktxHashListEntry,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_FindValue(
+        pHead: *mut ktxHashList,
+        key: *const ::std::os::raw::c_char,
+        pValueLen: *mut ::std::os::raw::c_uint,
+        pValue: *mut *mut ::std::os::raw::c_void,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_Next(entry: *mut ktxHashListEntry) -> *mut ktxHashListEntry;
+}
+extern "C" {
+    pub fn ktxHashList_Sort(pHead: *mut ktxHashList) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_Serialize(
+        pHead: *mut ktxHashList,
+        kvdLen: *mut ::std::os::raw::c_uint,
+        kvd: *mut *mut ::std::os::raw::c_uchar,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashList_Deserialize(
+        pHead: *mut ktxHashList,
+        kvdLen: ::std::os::raw::c_uint,
+        kvd: *mut ::std::os::raw::c_void,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashListEntry_GetKey(
+        This: *mut ktxHashListEntry,
+        pKeyLen: *mut ::std::os::raw::c_uint,
+        ppKey: *mut *mut ::std::os::raw::c_char,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxHashListEntry_GetValue(
+        This: *mut ktxHashListEntry,
+        pValueLen: *mut ::std::os::raw::c_uint,
+        ppValue: *mut *mut ::std::os::raw::c_void,
+    ) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxPrintInfoForStdioStream(stdioStream: *mut FILE) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxPrintInfoForNamedFile(filename: *const ::std::os::raw::c_char) -> ktx_error_code_e;
+}
+extern "C" {
+    pub fn ktxPrintInfoForMemory(bytes: *const ktx_uint8_t, size: ktx_size_t) -> ktx_error_code_e;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture_vvtbl {
+    pub _address: u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture_protected {
+    pub _address: u8,
+}
+#[doc = "< Private data."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture1_private {
+    pub _address: u8,
+}
+#[doc = "< Private data."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ktxTexture2_private {
+    pub _address: u8,
+}
@@ -0,0 +1,16 @@
+/* automatically generated by rust-bindgen 0.69.1 */
+
+pub type GLboolean = ::std::os::raw::c_uchar;
+pub type GLenum = ::std::os::raw::c_uint;
+pub type GLint = ::std::os::raw::c_int;
+pub type GLsizei = ::std::os::raw::c_int;
+pub type GLuint = ::std::os::raw::c_uint;
+pub type GLubyte = ::std::os::raw::c_uchar;
+extern "C" {
+    pub fn ktxTexture_GLUpload(
+        This: *mut ktxTexture,
+        pTexture: *mut GLuint,
+        pTarget: *mut GLenum,
+        pGlerror: *mut GLenum,
+    ) -> ktx_error_code_e;
+}
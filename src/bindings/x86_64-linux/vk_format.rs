@@ -0,0 +1,308 @@
+/* automatically generated by rust-bindgen 0.69.1 */
+
+pub const VkFormat_VK_FORMAT_UNDEFINED: VkFormat = 0;
+pub const VkFormat_VK_FORMAT_R4G4_UNORM_PACK8: VkFormat = 1;
+pub const VkFormat_VK_FORMAT_R4G4B4A4_UNORM_PACK16: VkFormat = 2;
+pub const VkFormat_VK_FORMAT_B4G4R4A4_UNORM_PACK16: VkFormat = 3;
+pub const VkFormat_VK_FORMAT_R5G6B5_UNORM_PACK16: VkFormat = 4;
+pub const VkFormat_VK_FORMAT_B5G6R5_UNORM_PACK16: VkFormat = 5;
+pub const VkFormat_VK_FORMAT_R5G5B5A1_UNORM_PACK16: VkFormat = 6;
+pub const VkFormat_VK_FORMAT_B5G5R5A1_UNORM_PACK16: VkFormat = 7;
+pub const VkFormat_VK_FORMAT_A1R5G5B5_UNORM_PACK16: VkFormat = 8;
+pub const VkFormat_VK_FORMAT_R8_UNORM: VkFormat = 9;
+pub const VkFormat_VK_FORMAT_R8_SNORM: VkFormat = 10;
+pub const VkFormat_VK_FORMAT_R8_USCALED: VkFormat = 11;
+pub const VkFormat_VK_FORMAT_R8_SSCALED: VkFormat = 12;
+pub const VkFormat_VK_FORMAT_R8_UINT: VkFormat = 13;
+pub const VkFormat_VK_FORMAT_R8_SINT: VkFormat = 14;
+pub const VkFormat_VK_FORMAT_R8_SRGB: VkFormat = 15;
+pub const VkFormat_VK_FORMAT_R8G8_UNORM: VkFormat = 16;
+pub const VkFormat_VK_FORMAT_R8G8_SNORM: VkFormat = 17;
+pub const VkFormat_VK_FORMAT_R8G8_USCALED: VkFormat = 18;
+pub const VkFormat_VK_FORMAT_R8G8_SSCALED: VkFormat = 19;
+pub const VkFormat_VK_FORMAT_R8G8_UINT: VkFormat = 20;
+pub const VkFormat_VK_FORMAT_R8G8_SINT: VkFormat = 21;
+pub const VkFormat_VK_FORMAT_R8G8_SRGB: VkFormat = 22;
+pub const VkFormat_VK_FORMAT_R8G8B8_UNORM: VkFormat = 23;
+pub const VkFormat_VK_FORMAT_R8G8B8_SNORM: VkFormat = 24;
+pub const VkFormat_VK_FORMAT_R8G8B8_USCALED: VkFormat = 25;
+pub const VkFormat_VK_FORMAT_R8G8B8_SSCALED: VkFormat = 26;
+pub const VkFormat_VK_FORMAT_R8G8B8_UINT: VkFormat = 27;
+pub const VkFormat_VK_FORMAT_R8G8B8_SINT: VkFormat = 28;
+pub const VkFormat_VK_FORMAT_R8G8B8_SRGB: VkFormat = 29;
+pub const VkFormat_VK_FORMAT_B8G8R8_UNORM: VkFormat = 30;
+pub const VkFormat_VK_FORMAT_B8G8R8_SNORM: VkFormat = 31;
+pub const VkFormat_VK_FORMAT_B8G8R8_USCALED: VkFormat = 32;
+pub const VkFormat_VK_FORMAT_B8G8R8_SSCALED: VkFormat = 33;
+pub const VkFormat_VK_FORMAT_B8G8R8_UINT: VkFormat = 34;
+pub const VkFormat_VK_FORMAT_B8G8R8_SINT: VkFormat = 35;
+pub const VkFormat_VK_FORMAT_B8G8R8_SRGB: VkFormat = 36;
+pub const VkFormat_VK_FORMAT_R8G8B8A8_UNORM: VkFormat = 37;
+pub const VkFormat_VK_FORMAT_R8G8B8A8_SNORM: VkFormat = 38;
+pub const VkFormat_VK_FORMAT_R8G8B8A8_USCALED: VkFormat = 39;
+pub const VkFormat_VK_FORMAT_R8G8B8A8_SSCALED: VkFormat = 40;
+pub const VkFormat_VK_FORMAT_R8G8B8A8_UINT: VkFormat = 41;
+pub const VkFormat_VK_FORMAT_R8G8B8A8_SINT: VkFormat = 42;
+pub const VkFormat_VK_FORMAT_R8G8B8A8_SRGB: VkFormat = 43;
+pub const VkFormat_VK_FORMAT_B8G8R8A8_UNORM: VkFormat = 44;
+pub const VkFormat_VK_FORMAT_B8G8R8A8_SNORM: VkFormat = 45;
+pub const VkFormat_VK_FORMAT_B8G8R8A8_USCALED: VkFormat = 46;
+pub const VkFormat_VK_FORMAT_B8G8R8A8_SSCALED: VkFormat = 47;
+pub const VkFormat_VK_FORMAT_B8G8R8A8_UINT: VkFormat = 48;
+pub const VkFormat_VK_FORMAT_B8G8R8A8_SINT: VkFormat = 49;
+pub const VkFormat_VK_FORMAT_B8G8R8A8_SRGB: VkFormat = 50;
+pub const VkFormat_VK_FORMAT_A8B8G8R8_UNORM_PACK32: VkFormat = 51;
+pub const VkFormat_VK_FORMAT_A8B8G8R8_SNORM_PACK32: VkFormat = 52;
+pub const VkFormat_VK_FORMAT_A8B8G8R8_USCALED_PACK32: VkFormat = 53;
+pub const VkFormat_VK_FORMAT_A8B8G8R8_SSCALED_PACK32: VkFormat = 54;
+pub const VkFormat_VK_FORMAT_A8B8G8R8_UINT_PACK32: VkFormat = 55;
+pub const VkFormat_VK_FORMAT_A8B8G8R8_SINT_PACK32: VkFormat = 56;
+pub const VkFormat_VK_FORMAT_A8B8G8R8_SRGB_PACK32: VkFormat = 57;
+pub const VkFormat_VK_FORMAT_A2R10G10B10_UNORM_PACK32: VkFormat = 58;
+pub const VkFormat_VK_FORMAT_A2R10G10B10_SNORM_PACK32: VkFormat = 59;
+pub const VkFormat_VK_FORMAT_A2R10G10B10_USCALED_PACK32: VkFormat = 60;
+pub const VkFormat_VK_FORMAT_A2R10G10B10_SSCALED_PACK32: VkFormat = 61;
+pub const VkFormat_VK_FORMAT_A2R10G10B10_UINT_PACK32: VkFormat = 62;
+pub const VkFormat_VK_FORMAT_A2R10G10B10_SINT_PACK32: VkFormat = 63;
+pub const VkFormat_VK_FORMAT_A2B10G10R10_UNORM_PACK32: VkFormat = 64;
+pub const VkFormat_VK_FORMAT_A2B10G10R10_SNORM_PACK32: VkFormat = 65;
+pub const VkFormat_VK_FORMAT_A2B10G10R10_USCALED_PACK32: VkFormat = 66;
+pub const VkFormat_VK_FORMAT_A2B10G10R10_SSCALED_PACK32: VkFormat = 67;
+pub const VkFormat_VK_FORMAT_A2B10G10R10_UINT_PACK32: VkFormat = 68;
+pub const VkFormat_VK_FORMAT_A2B10G10R10_SINT_PACK32: VkFormat = 69;
+pub const VkFormat_VK_FORMAT_R16_UNORM: VkFormat = 70;
+pub const VkFormat_VK_FORMAT_R16_SNORM: VkFormat = 71;
+pub const VkFormat_VK_FORMAT_R16_USCALED: VkFormat = 72;
+pub const VkFormat_VK_FORMAT_R16_SSCALED: VkFormat = 73;
+pub const VkFormat_VK_FORMAT_R16_UINT: VkFormat = 74;
+pub const VkFormat_VK_FORMAT_R16_SINT: VkFormat = 75;
+pub const VkFormat_VK_FORMAT_R16_SFLOAT: VkFormat = 76;
+pub const VkFormat_VK_FORMAT_R16G16_UNORM: VkFormat = 77;
+pub const VkFormat_VK_FORMAT_R16G16_SNORM: VkFormat = 78;
+pub const VkFormat_VK_FORMAT_R16G16_USCALED: VkFormat = 79;
+pub const VkFormat_VK_FORMAT_R16G16_SSCALED: VkFormat = 80;
+pub const VkFormat_VK_FORMAT_R16G16_UINT: VkFormat = 81;
+pub const VkFormat_VK_FORMAT_R16G16_SINT: VkFormat = 82;
+pub const VkFormat_VK_FORMAT_R16G16_SFLOAT: VkFormat = 83;
+pub const VkFormat_VK_FORMAT_R16G16B16_UNORM: VkFormat = 84;
+pub const VkFormat_VK_FORMAT_R16G16B16_SNORM: VkFormat = 85;
+pub const VkFormat_VK_FORMAT_R16G16B16_USCALED: VkFormat = 86;
+pub const VkFormat_VK_FORMAT_R16G16B16_SSCALED: VkFormat = 87;
+pub const VkFormat_VK_FORMAT_R16G16B16_UINT: VkFormat = 88;
+pub const VkFormat_VK_FORMAT_R16G16B16_SINT: VkFormat = 89;
+pub const VkFormat_VK_FORMAT_R16G16B16_SFLOAT: VkFormat = 90;
+pub const VkFormat_VK_FORMAT_R16G16B16A16_UNORM: VkFormat = 91;
+pub const VkFormat_VK_FORMAT_R16G16B16A16_SNORM: VkFormat = 92;
+pub const VkFormat_VK_FORMAT_R16G16B16A16_USCALED: VkFormat = 93;
+pub const VkFormat_VK_FORMAT_R16G16B16A16_SSCALED: VkFormat = 94;
+pub const VkFormat_VK_FORMAT_R16G16B16A16_UINT: VkFormat = 95;
+pub const VkFormat_VK_FORMAT_R16G16B16A16_SINT: VkFormat = 96;
+pub const VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT: VkFormat = 97;
+pub const VkFormat_VK_FORMAT_R32_UINT: VkFormat = 98;
+pub const VkFormat_VK_FORMAT_R32_SINT: VkFormat = 99;
+pub const VkFormat_VK_FORMAT_R32_SFLOAT: VkFormat = 100;
+pub const VkFormat_VK_FORMAT_R32G32_UINT: VkFormat = 101;
+pub const VkFormat_VK_FORMAT_R32G32_SINT: VkFormat = 102;
+pub const VkFormat_VK_FORMAT_R32G32_SFLOAT: VkFormat = 103;
+pub const VkFormat_VK_FORMAT_R32G32B32_UINT: VkFormat = 104;
+pub const VkFormat_VK_FORMAT_R32G32B32_SINT: VkFormat = 105;
+pub const VkFormat_VK_FORMAT_R32G32B32_SFLOAT: VkFormat = 106;
+pub const VkFormat_VK_FORMAT_R32G32B32A32_UINT: VkFormat = 107;
+pub const VkFormat_VK_FORMAT_R32G32B32A32_SINT: VkFormat = 108;
+pub const VkFormat_VK_FORMAT_R32G32B32A32_SFLOAT: VkFormat = 109;
+pub const VkFormat_VK_FORMAT_R64_UINT: VkFormat = 110;
+pub const VkFormat_VK_FORMAT_R64_SINT: VkFormat = 111;
+pub const VkFormat_VK_FORMAT_R64_SFLOAT: VkFormat = 112;
+pub const VkFormat_VK_FORMAT_R64G64_UINT: VkFormat = 113;
+pub const VkFormat_VK_FORMAT_R64G64_SINT: VkFormat = 114;
+pub const VkFormat_VK_FORMAT_R64G64_SFLOAT: VkFormat = 115;
+pub const VkFormat_VK_FORMAT_R64G64B64_UINT: VkFormat = 116;
+pub const VkFormat_VK_FORMAT_R64G64B64_SINT: VkFormat = 117;
+pub const VkFormat_VK_FORMAT_R64G64B64_SFLOAT: VkFormat = 118;
+pub const VkFormat_VK_FORMAT_R64G64B64A64_UINT: VkFormat = 119;
+pub const VkFormat_VK_FORMAT_R64G64B64A64_SINT: VkFormat = 120;
+pub const VkFormat_VK_FORMAT_R64G64B64A64_SFLOAT: VkFormat = 121;
+pub const VkFormat_VK_FORMAT_B10G11R11_UFLOAT_PACK32: VkFormat = 122;
+pub const VkFormat_VK_FORMAT_E5B9G9R9_UFLOAT_PACK32: VkFormat = 123;
+pub const VkFormat_VK_FORMAT_D16_UNORM: VkFormat = 124;
+pub const VkFormat_VK_FORMAT_X8_D24_UNORM_PACK32: VkFormat = 125;
+pub const VkFormat_VK_FORMAT_D32_SFLOAT: VkFormat = 126;
+pub const VkFormat_VK_FORMAT_S8_UINT: VkFormat = 127;
+pub const VkFormat_VK_FORMAT_D16_UNORM_S8_UINT: VkFormat = 128;
+pub const VkFormat_VK_FORMAT_D24_UNORM_S8_UINT: VkFormat = 129;
+pub const VkFormat_VK_FORMAT_D32_SFLOAT_S8_UINT: VkFormat = 130;
+pub const VkFormat_VK_FORMAT_BC1_RGB_UNORM_BLOCK: VkFormat = 131;
+pub const VkFormat_VK_FORMAT_BC1_RGB_SRGB_BLOCK: VkFormat = 132;
+pub const VkFormat_VK_FORMAT_BC1_RGBA_UNORM_BLOCK: VkFormat = 133;
+pub const VkFormat_VK_FORMAT_BC1_RGBA_SRGB_BLOCK: VkFormat = 134;
+pub const VkFormat_VK_FORMAT_BC2_UNORM_BLOCK: VkFormat = 135;
+pub const VkFormat_VK_FORMAT_BC2_SRGB_BLOCK: VkFormat = 136;
+pub const VkFormat_VK_FORMAT_BC3_UNORM_BLOCK: VkFormat = 137;
+pub const VkFormat_VK_FORMAT_BC3_SRGB_BLOCK: VkFormat = 138;
+pub const VkFormat_VK_FORMAT_BC4_UNORM_BLOCK: VkFormat = 139;
+pub const VkFormat_VK_FORMAT_BC4_SNORM_BLOCK: VkFormat = 140;
+pub const VkFormat_VK_FORMAT_BC5_UNORM_BLOCK: VkFormat = 141;
+pub const VkFormat_VK_FORMAT_BC5_SNORM_BLOCK: VkFormat = 142;
+pub const VkFormat_VK_FORMAT_BC6H_UFLOAT_BLOCK: VkFormat = 143;
+pub const VkFormat_VK_FORMAT_BC6H_SFLOAT_BLOCK: VkFormat = 144;
+pub const VkFormat_VK_FORMAT_BC7_UNORM_BLOCK: VkFormat = 145;
+pub const VkFormat_VK_FORMAT_BC7_SRGB_BLOCK: VkFormat = 146;
+pub const VkFormat_VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK: VkFormat = 147;
+pub const VkFormat_VK_FORMAT_ETC2_R8G8B8_SRGB_BLOCK: VkFormat = 148;
+pub const VkFormat_VK_FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK: VkFormat = 149;
+pub const VkFormat_VK_FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK: VkFormat = 150;
+pub const VkFormat_VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK: VkFormat = 151;
+pub const VkFormat_VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK: VkFormat = 152;
+pub const VkFormat_VK_FORMAT_EAC_R11_UNORM_BLOCK: VkFormat = 153;
+pub const VkFormat_VK_FORMAT_EAC_R11_SNORM_BLOCK: VkFormat = 154;
+pub const VkFormat_VK_FORMAT_EAC_R11G11_UNORM_BLOCK: VkFormat = 155;
+pub const VkFormat_VK_FORMAT_EAC_R11G11_SNORM_BLOCK: VkFormat = 156;
+pub const VkFormat_VK_FORMAT_ASTC_4x4_UNORM_BLOCK: VkFormat = 157;
+pub const VkFormat_VK_FORMAT_ASTC_4x4_SRGB_BLOCK: VkFormat = 158;
+pub const VkFormat_VK_FORMAT_ASTC_5x4_UNORM_BLOCK: VkFormat = 159;
+pub const VkFormat_VK_FORMAT_ASTC_5x4_SRGB_BLOCK: VkFormat = 160;
+pub const VkFormat_VK_FORMAT_ASTC_5x5_UNORM_BLOCK: VkFormat = 161;
+pub const VkFormat_VK_FORMAT_ASTC_5x5_SRGB_BLOCK: VkFormat = 162;
+pub const VkFormat_VK_FORMAT_ASTC_6x5_UNORM_BLOCK: VkFormat = 163;
+pub const VkFormat_VK_FORMAT_ASTC_6x5_SRGB_BLOCK: VkFormat = 164;
+pub const VkFormat_VK_FORMAT_ASTC_6x6_UNORM_BLOCK: VkFormat = 165;
+pub const VkFormat_VK_FORMAT_ASTC_6x6_SRGB_BLOCK: VkFormat = 166;
+pub const VkFormat_VK_FORMAT_ASTC_8x5_UNORM_BLOCK: VkFormat = 167;
+pub const VkFormat_VK_FORMAT_ASTC_8x5_SRGB_BLOCK: VkFormat = 168;
+pub const VkFormat_VK_FORMAT_ASTC_8x6_UNORM_BLOCK: VkFormat = 169;
+pub const VkFormat_VK_FORMAT_ASTC_8x6_SRGB_BLOCK: VkFormat = 170;
+pub const VkFormat_VK_FORMAT_ASTC_8x8_UNORM_BLOCK: VkFormat = 171;
+pub const VkFormat_VK_FORMAT_ASTC_8x8_SRGB_BLOCK: VkFormat = 172;
+pub const VkFormat_VK_FORMAT_ASTC_10x5_UNORM_BLOCK: VkFormat = 173;
+pub const VkFormat_VK_FORMAT_ASTC_10x5_SRGB_BLOCK: VkFormat = 174;
+pub const VkFormat_VK_FORMAT_ASTC_10x6_UNORM_BLOCK: VkFormat = 175;
+pub const VkFormat_VK_FORMAT_ASTC_10x6_SRGB_BLOCK: VkFormat = 176;
+pub const VkFormat_VK_FORMAT_ASTC_10x8_UNORM_BLOCK: VkFormat = 177;
+pub const VkFormat_VK_FORMAT_ASTC_10x8_SRGB_BLOCK: VkFormat = 178;
+pub const VkFormat_VK_FORMAT_ASTC_10x10_UNORM_BLOCK: VkFormat = 179;
+pub const VkFormat_VK_FORMAT_ASTC_10x10_SRGB_BLOCK: VkFormat = 180;
+pub const VkFormat_VK_FORMAT_ASTC_12x10_UNORM_BLOCK: VkFormat = 181;
+pub const VkFormat_VK_FORMAT_ASTC_12x10_SRGB_BLOCK: VkFormat = 182;
+pub const VkFormat_VK_FORMAT_ASTC_12x12_UNORM_BLOCK: VkFormat = 183;
+pub const VkFormat_VK_FORMAT_ASTC_12x12_SRGB_BLOCK: VkFormat = 184;
+pub const VkFormat_VK_FORMAT_G8B8G8R8_422_UNORM: VkFormat = 1000156000;
+pub const VkFormat_VK_FORMAT_B8G8R8G8_422_UNORM: VkFormat = 1000156001;
+pub const VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM: VkFormat = 1000156002;
+pub const VkFormat_VK_FORMAT_G8_B8R8_2PLANE_420_UNORM: VkFormat = 1000156003;
+pub const VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM: VkFormat = 1000156004;
+pub const VkFormat_VK_FORMAT_G8_B8R8_2PLANE_422_UNORM: VkFormat = 1000156005;
+pub const VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM: VkFormat = 1000156006;
+pub const VkFormat_VK_FORMAT_R10X6_UNORM_PACK16: VkFormat = 1000156007;
+pub const VkFormat_VK_FORMAT_R10X6G10X6_UNORM_2PACK16: VkFormat = 1000156008;
+pub const VkFormat_VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16: VkFormat = 1000156009;
+pub const VkFormat_VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16: VkFormat = 1000156010;
+pub const VkFormat_VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16: VkFormat = 1000156011;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16: VkFormat = 1000156012;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16: VkFormat = 1000156013;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16: VkFormat = 1000156014;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16: VkFormat = 1000156015;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16: VkFormat = 1000156016;
+pub const VkFormat_VK_FORMAT_R12X4_UNORM_PACK16: VkFormat = 1000156017;
+pub const VkFormat_VK_FORMAT_R12X4G12X4_UNORM_2PACK16: VkFormat = 1000156018;
+pub const VkFormat_VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16: VkFormat = 1000156019;
+pub const VkFormat_VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16: VkFormat = 1000156020;
+pub const VkFormat_VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16: VkFormat = 1000156021;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16: VkFormat = 1000156022;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16: VkFormat = 1000156023;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16: VkFormat = 1000156024;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16: VkFormat = 1000156025;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16: VkFormat = 1000156026;
+pub const VkFormat_VK_FORMAT_G16B16G16R16_422_UNORM: VkFormat = 1000156027;
+pub const VkFormat_VK_FORMAT_B16G16R16G16_422_UNORM: VkFormat = 1000156028;
+pub const VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM: VkFormat = 1000156029;
+pub const VkFormat_VK_FORMAT_G16_B16R16_2PLANE_420_UNORM: VkFormat = 1000156030;
+pub const VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM: VkFormat = 1000156031;
+pub const VkFormat_VK_FORMAT_G16_B16R16_2PLANE_422_UNORM: VkFormat = 1000156032;
+pub const VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM: VkFormat = 1000156033;
+pub const VkFormat_VK_FORMAT_G8_B8R8_2PLANE_444_UNORM: VkFormat = 1000330000;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16: VkFormat = 1000330001;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16: VkFormat = 1000330002;
+pub const VkFormat_VK_FORMAT_G16_B16R16_2PLANE_444_UNORM: VkFormat = 1000330003;
+pub const VkFormat_VK_FORMAT_A4R4G4B4_UNORM_PACK16: VkFormat = 1000340000;
+pub const VkFormat_VK_FORMAT_A4B4G4R4_UNORM_PACK16: VkFormat = 1000340001;
+pub const VkFormat_VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK: VkFormat = 1000066000;
+pub const VkFormat_VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK: VkFormat = 1000066001;
+pub const VkFormat_VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK: VkFormat = 1000066002;
+pub const VkFormat_VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK: VkFormat = 1000066003;
+pub const VkFormat_VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK: VkFormat = 1000066004;
+pub const VkFormat_VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK: VkFormat = 1000066005;
+pub const VkFormat_VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK: VkFormat = 1000066006;
+pub const VkFormat_VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK: VkFormat = 1000066007;
+pub const VkFormat_VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK: VkFormat = 1000066008;
+pub const VkFormat_VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK: VkFormat = 1000066009;
+pub const VkFormat_VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK: VkFormat = 1000066010;
+pub const VkFormat_VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK: VkFormat = 1000066011;
+pub const VkFormat_VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK: VkFormat = 1000066012;
+pub const VkFormat_VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK: VkFormat = 1000066013;
+pub const VkFormat_VK_FORMAT_PVRTC1_2BPP_UNORM_BLOCK_IMG: VkFormat = 1000054000;
+pub const VkFormat_VK_FORMAT_PVRTC1_4BPP_UNORM_BLOCK_IMG: VkFormat = 1000054001;
+pub const VkFormat_VK_FORMAT_PVRTC2_2BPP_UNORM_BLOCK_IMG: VkFormat = 1000054002;
+pub const VkFormat_VK_FORMAT_PVRTC2_4BPP_UNORM_BLOCK_IMG: VkFormat = 1000054003;
+pub const VkFormat_VK_FORMAT_PVRTC1_2BPP_SRGB_BLOCK_IMG: VkFormat = 1000054004;
+pub const VkFormat_VK_FORMAT_PVRTC1_4BPP_SRGB_BLOCK_IMG: VkFormat = 1000054005;
+pub const VkFormat_VK_FORMAT_PVRTC2_2BPP_SRGB_BLOCK_IMG: VkFormat = 1000054006;
+pub const VkFormat_VK_FORMAT_PVRTC2_4BPP_SRGB_BLOCK_IMG: VkFormat = 1000054007;
+pub const VkFormat_VK_FORMAT_R16G16_S10_5_NV: VkFormat = 1000464000;
+pub const VkFormat_VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR: VkFormat = 1000470000;
+pub const VkFormat_VK_FORMAT_A8_UNORM_KHR: VkFormat = 1000470001;
+pub const VkFormat_VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK_EXT: VkFormat = 1000066000;
+pub const VkFormat_VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK_EXT: VkFormat = 1000066001;
+pub const VkFormat_VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK_EXT: VkFormat = 1000066002;
+pub const VkFormat_VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK_EXT: VkFormat = 1000066003;
+pub const VkFormat_VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK_EXT: VkFormat = 1000066004;
+pub const VkFormat_VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK_EXT: VkFormat = 1000066005;
+pub const VkFormat_VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK_EXT: VkFormat = 1000066006;
+pub const VkFormat_VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK_EXT: VkFormat = 1000066007;
+pub const VkFormat_VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK_EXT: VkFormat = 1000066008;
+pub const VkFormat_VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK_EXT: VkFormat = 1000066009;
+pub const VkFormat_VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK_EXT: VkFormat = 1000066010;
+pub const VkFormat_VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK_EXT: VkFormat = 1000066011;
+pub const VkFormat_VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK_EXT: VkFormat = 1000066012;
+pub const VkFormat_VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK_EXT: VkFormat = 1000066013;
+pub const VkFormat_VK_FORMAT_G8B8G8R8_422_UNORM_KHR: VkFormat = 1000156000;
+pub const VkFormat_VK_FORMAT_B8G8R8G8_422_UNORM_KHR: VkFormat = 1000156001;
+pub const VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM_KHR: VkFormat = 1000156002;
+pub const VkFormat_VK_FORMAT_G8_B8R8_2PLANE_420_UNORM_KHR: VkFormat = 1000156003;
+pub const VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM_KHR: VkFormat = 1000156004;
+pub const VkFormat_VK_FORMAT_G8_B8R8_2PLANE_422_UNORM_KHR: VkFormat = 1000156005;
+pub const VkFormat_VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM_KHR: VkFormat = 1000156006;
+pub const VkFormat_VK_FORMAT_R10X6_UNORM_PACK16_KHR: VkFormat = 1000156007;
+pub const VkFormat_VK_FORMAT_R10X6G10X6_UNORM_2PACK16_KHR: VkFormat = 1000156008;
+pub const VkFormat_VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR: VkFormat = 1000156009;
+pub const VkFormat_VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR: VkFormat = 1000156010;
+pub const VkFormat_VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR: VkFormat = 1000156011;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR: VkFormat = 1000156012;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR: VkFormat = 1000156013;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR: VkFormat = 1000156014;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR: VkFormat = 1000156015;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR: VkFormat = 1000156016;
+pub const VkFormat_VK_FORMAT_R12X4_UNORM_PACK16_KHR: VkFormat = 1000156017;
+pub const VkFormat_VK_FORMAT_R12X4G12X4_UNORM_2PACK16_KHR: VkFormat = 1000156018;
+pub const VkFormat_VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR: VkFormat = 1000156019;
+pub const VkFormat_VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR: VkFormat = 1000156020;
+pub const VkFormat_VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR: VkFormat = 1000156021;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR: VkFormat = 1000156022;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR: VkFormat = 1000156023;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR: VkFormat = 1000156024;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR: VkFormat = 1000156025;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR: VkFormat = 1000156026;
+pub const VkFormat_VK_FORMAT_G16B16G16R16_422_UNORM_KHR: VkFormat = 1000156027;
+pub const VkFormat_VK_FORMAT_B16G16R16G16_422_UNORM_KHR: VkFormat = 1000156028;
+pub const VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM_KHR: VkFormat = 1000156029;
+pub const VkFormat_VK_FORMAT_G16_B16R16_2PLANE_420_UNORM_KHR: VkFormat = 1000156030;
+pub const VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM_KHR: VkFormat = 1000156031;
+pub const VkFormat_VK_FORMAT_G16_B16R16_2PLANE_422_UNORM_KHR: VkFormat = 1000156032;
+pub const VkFormat_VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM_KHR: VkFormat = 1000156033;
+pub const VkFormat_VK_FORMAT_G8_B8R8_2PLANE_444_UNORM_EXT: VkFormat = 1000330000;
+pub const VkFormat_VK_FORMAT_G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16_EXT: VkFormat = 1000330001;
+pub const VkFormat_VK_FORMAT_G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16_EXT: VkFormat = 1000330002;
+pub const VkFormat_VK_FORMAT_G16_B16R16_2PLANE_444_UNORM_EXT: VkFormat = 1000330003;
+pub const VkFormat_VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT: VkFormat = 1000340000;
+pub const VkFormat_VK_FORMAT_A4B4G4R4_UNORM_PACK16_EXT: VkFormat = 1000340001;
+pub const VkFormat_VK_FORMAT_MAX_ENUM: VkFormat = 2147483647;
+pub type VkFormat = ::std::os::raw::c_uint;
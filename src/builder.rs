@@ -0,0 +1,311 @@
+//! Creating textures from scratch through `ktxTextureCreateInfo`.
+
+use std::{fmt, ptr};
+
+use crate::{ffi::*, texture::create, KtxError, Texture1, Texture2};
+
+/// Builder for new [`Texture1`]s and [`Texture2`]s.
+///
+/// The combination of settings is validated before libktx is called, then
+/// images can be filled in with [`BaseTexture::set_image`](crate::BaseTexture::set_image).
+#[derive(Clone, Debug)]
+pub struct TextureBuilder {
+    gl_internalformat: u32,
+    vk_format: u32,
+    dfd: Option<Vec<u32>>,
+    width: u32,
+    height: u32,
+    depth: u32,
+    num_dimensions: u32,
+    levels: u32,
+    layers: u32,
+    faces: u32,
+    is_array: bool,
+    generate_mipmaps: bool,
+    allocate_storage: bool,
+}
+
+impl TextureBuilder {
+    /// A single 1x1 2D image with storage allocated and no format set.
+    pub fn new() -> Self {
+        Self {
+            gl_internalformat: 0,
+            vk_format: 0,
+            dfd: None,
+            width: 1,
+            height: 1,
+            depth: 1,
+            num_dimensions: 2,
+            levels: 1,
+            layers: 1,
+            faces: 1,
+            is_array: false,
+            generate_mipmaps: false,
+            allocate_storage: true,
+        }
+    }
+
+    /// The `VkFormat` of a KTX2 texture. [`TextureBuilder::build_ktx1`] also
+    /// derives the `glInternalformat` from it when none is set.
+    pub fn vk_format(mut self, vk_format: u32) -> Self {
+        self.vk_format = vk_format;
+        self
+    }
+
+    /// The data format descriptor of a KTX2 texture, only used with `VK_FORMAT_UNDEFINED`.
+    pub fn dfd(mut self, dfd: Vec<u32>) -> Self {
+        self.dfd = Some(dfd);
+        self
+    }
+
+    /// The sized internal format of a KTX1 texture, e.g. `GL_RGBA8`.
+    /// Ignored by [`TextureBuilder::build_ktx2`].
+    pub fn gl_internalformat(mut self, gl_internalformat: u32) -> Self {
+        self.gl_internalformat = gl_internalformat;
+        self
+    }
+
+    pub fn extent_1d(mut self, width: u32) -> Self {
+        (self.width, self.height, self.depth, self.num_dimensions) = (width, 1, 1, 1);
+        self
+    }
+
+    pub fn extent_2d(mut self, width: u32, height: u32) -> Self {
+        (self.width, self.height, self.depth, self.num_dimensions) = (width, height, 1, 2);
+        self
+    }
+
+    pub fn extent_3d(mut self, width: u32, height: u32, depth: u32) -> Self {
+        (self.width, self.height, self.depth, self.num_dimensions) = (width, height, depth, 3);
+        self
+    }
+
+    /// Number of mip levels, at most enough to reach 1x1.
+    pub fn levels(mut self, levels: u32) -> Self {
+        self.levels = levels;
+        self
+    }
+
+    /// Number of array layers. More than one requires [`TextureBuilder::array`].
+    pub fn layers(mut self, layers: u32) -> Self {
+        self.layers = layers;
+        self
+    }
+
+    /// 1, or 6 for a cubemap.
+    pub fn faces(mut self, faces: u32) -> Self {
+        self.faces = faces;
+        self
+    }
+
+    pub fn cubemap(self, cubemap: bool) -> Self {
+        self.faces(if cubemap { 6 } else { 1 })
+    }
+
+    pub fn array(mut self, is_array: bool) -> Self {
+        self.is_array = is_array;
+        self
+    }
+
+    /// Asks the loader to generate mipmaps. Requires a single level.
+    pub fn generate_mipmaps(mut self, generate_mipmaps: bool) -> Self {
+        self.generate_mipmaps = generate_mipmaps;
+        self
+    }
+
+    /// Whether to allocate storage for the images, which is needed to set them.
+    pub fn allocate_storage(mut self, allocate_storage: bool) -> Self {
+        self.allocate_storage = allocate_storage;
+        self
+    }
+
+    pub fn build_ktx1(&self) -> Result<Texture1, CreateError> {
+        let gl_internalformat = self.ktx1_internalformat();
+        if gl_internalformat == 0 {
+            return Err(CreateError::Invalid(
+                "KTX1 textures need a glInternalformat, or a VkFormat that has one",
+            ));
+        }
+        let (mut info, storage) = self.create_info()?;
+        info.glInternalformat = gl_internalformat;
+        let raw = create(|out| unsafe { ktxTexture1_Create(&mut info, storage, out) })?;
+        Ok(unsafe { Texture1::from_raw(raw.as_ptr()) })
+    }
+
+    pub fn build_ktx2(&self) -> Result<Texture2, CreateError> {
+        if self.vk_format == 0 && self.dfd.is_none() {
+            return Err(CreateError::Invalid(
+                "KTX2 textures need a vkFormat, or a DFD with VK_FORMAT_UNDEFINED",
+            ));
+        }
+        let (mut info, storage) = self.create_info()?;
+        // libktx copies the DFD, so it only has to outlive the call.
+        let mut dfd = self.dfd.clone();
+        if self.vk_format == 0 {
+            info.pDfd = dfd.as_mut().map_or(ptr::null_mut(), |dfd| dfd.as_mut_ptr());
+        }
+        let raw = create(|out| unsafe { ktxTexture2_Create(&mut info, storage, out) })?;
+        Ok(unsafe { Texture2::from_raw(raw.as_ptr()) })
+    }
+
+    fn ktx1_internalformat(&self) -> u32 {
+        if self.gl_internalformat == 0 {
+            return crate::GlFormat::from_vk(self.vk_format).map_or(0, |gl| gl.internalformat);
+        }
+        self.gl_internalformat
+    }
+
+    fn create_info(
+        &self,
+    ) -> Result<(ktxTextureCreateInfo, ktxTextureCreateStorageEnum), CreateError> {
+        self.validate()?;
+        let info = ktxTextureCreateInfo {
+            glInternalformat: self.gl_internalformat,
+            vkFormat: self.vk_format,
+            pDfd: ptr::null_mut(),
+            baseWidth: self.width,
+            baseHeight: self.height,
+            baseDepth: self.depth,
+            numDimensions: self.num_dimensions,
+            numLevels: self.levels,
+            numLayers: self.layers,
+            numFaces: self.faces,
+            isArray: self.is_array,
+            generateMipmaps: self.generate_mipmaps,
+        };
+        let storage = if self.allocate_storage {
+            ktxTextureCreateStorageEnum_KTX_TEXTURE_CREATE_ALLOC_STORAGE
+        } else {
+            ktxTextureCreateStorageEnum_KTX_TEXTURE_CREATE_NO_STORAGE
+        };
+        Ok((info, storage))
+    }
+
+    fn validate(&self) -> Result<(), CreateError> {
+        let invalid = |reason| Err(CreateError::Invalid(reason));
+        if self.width == 0 || self.height == 0 || self.depth == 0 {
+            return invalid("dimensions must be non-zero");
+        }
+        if self.faces != 1 && self.faces != 6 {
+            return invalid("a texture has either 1 face or 6 for a cubemap");
+        }
+        if self.faces == 6 && (self.num_dimensions != 2 || self.width != self.height) {
+            return invalid("cubemaps must be 2D and square");
+        }
+        if self.layers == 0 {
+            return invalid("a texture needs at least one layer");
+        }
+        if self.layers > 1 && !self.is_array {
+            return invalid("multiple layers require an array texture");
+        }
+        if self.num_dimensions == 3 && self.is_array {
+            return invalid("3D array textures are not supported");
+        }
+        let max_levels = 32 - self.width.max(self.height).max(self.depth).leading_zeros();
+        if self.levels == 0 || self.levels > max_levels {
+            return invalid("levels must be between 1 and enough to reach a 1x1 image");
+        }
+        if self.generate_mipmaps && self.levels != 1 {
+            return invalid("generate_mipmaps requires a single level");
+        }
+        Ok(())
+    }
+}
+
+impl Default for TextureBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Failure to create a texture with [`TextureBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CreateError {
+    /// The settings are rejected before reaching libktx.
+    Invalid(&'static str),
+    Ktx(KtxError),
+}
+
+impl From<KtxError> for CreateError {
+    fn from(error: KtxError) -> Self {
+        CreateError::Ktx(error)
+    }
+}
+
+impl fmt::Display for CreateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CreateError::Invalid(reason) => write!(f, "invalid texture settings: {reason}"),
+            CreateError::Ktx(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CreateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CreateError::Invalid(_) => None,
+            CreateError::Ktx(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejection(builder: TextureBuilder) -> &'static str {
+        match builder.validate() {
+            Err(CreateError::Invalid(reason)) => reason,
+            other => panic!("{builder:?} not rejected: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn validate_rejects_each_rule() {
+        let base = TextureBuilder::new().extent_2d(8, 8);
+        assert_eq!(base.validate(), Ok(()));
+        assert_eq!(base.clone().cubemap(true).levels(4).validate(), Ok(()));
+        assert_eq!(base.clone().array(true).layers(3).validate(), Ok(()));
+
+        for (builder, reason) in [
+            (base.clone().extent_2d(0, 8), "dimensions must be non-zero"),
+            (base.clone().faces(2), "a texture has either 1 face or 6"),
+            (
+                base.clone().extent_2d(8, 4).cubemap(true),
+                "cubemaps must be 2D and square",
+            ),
+            (
+                base.clone().extent_3d(8, 8, 8).cubemap(true),
+                "cubemaps must be 2D and square",
+            ),
+            (base.clone().layers(0).array(true), "at least one layer"),
+            (base.clone().layers(2), "multiple layers require an array"),
+            (
+                base.clone().extent_3d(8, 8, 2).array(true),
+                "3D array textures",
+            ),
+            (base.clone().levels(0), "levels must be between 1"),
+            (base.clone().levels(5), "levels must be between 1"),
+            (
+                base.clone().extent_3d(2, 2, 16).levels(6),
+                "levels must be between 1",
+            ),
+            (
+                base.clone().levels(2).generate_mipmaps(true),
+                "generate_mipmaps requires",
+            ),
+        ] {
+            let rejected = rejection(builder.clone());
+            assert!(rejected.contains(reason), "{builder:?}: {rejected}");
+        }
+        assert_eq!(base.extent_3d(2, 2, 16).levels(5).validate(), Ok(()));
+    }
+
+    #[test]
+    fn build_needs_a_format() {
+        let builder = TextureBuilder::new();
+        assert!(matches!(builder.build_ktx1(), Err(CreateError::Invalid(_))));
+        assert!(matches!(builder.build_ktx2(), Err(CreateError::Invalid(_))));
+    }
+}
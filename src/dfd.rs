@@ -0,0 +1,699 @@
+//! Parsing the Khronos Data Format Descriptor of KTX2 textures.
+
+use std::{fmt, slice};
+
+use crate::{
+    ffi::*,
+    reader::{DescriptorView, SampleView},
+    BaseTexture, Texture2,
+};
+
+/// The basic descriptor block of a data format descriptor.
+///
+/// Values stored minus one in the block, such as texel block dimensions and
+/// sample bit lengths, are given here as the actual values.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BasicDescriptor {
+    pub vendor_id: khr_df_vendorid_e,
+    pub descriptor_type: khr_df_khr_descriptortype_e,
+    pub version_number: khr_df_versionnumber_e,
+    pub color_model: khr_df_model_e,
+    pub color_primaries: khr_df_primaries_e,
+    pub transfer_function: khr_df_transfer_e,
+    /// `KHR_DF_FLAG_*` bits.
+    pub flags: khr_df_flags_e,
+    /// Width, height, depth and fourth dimension of a texel block, 1 when unused.
+    pub texel_block_dimensions: [u32; 4],
+    /// Bytes per plane of a texel block, 0 for unused planes and supercompressed data.
+    pub bytes_planes: [u8; 8],
+    pub samples: Vec<SampleDescriptor>,
+}
+
+/// One sample of a [`BasicDescriptor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SampleDescriptor {
+    pub bit_offset: u16,
+    pub bit_length: u16,
+    /// The channel within the color model, e.g. `KHR_DF_CHANNEL_RGBSDA_RED`.
+    pub channel: u8,
+    /// `KHR_DF_SAMPLE_DATATYPE_*` bits.
+    pub qualifiers: u8,
+    pub sample_positions: [u8; 4],
+    pub lower: u32,
+    pub upper: u32,
+}
+
+// Sizes of the basic descriptor block parts, in bytes.
+const HEADER_SIZE: usize = 24;
+const SAMPLE_SIZE: usize = 16;
+
+impl BasicDescriptor {
+    /// Parses the basic descriptor block of `dfd`, a full descriptor starting
+    /// with its `dfdTotalSize` word like `ktxTexture2::pDfd`.
+    pub fn parse(dfd: &[u32]) -> Result<Self, DfdError> {
+        let total_size = *dfd.first().ok_or(DfdError::Truncated)? as usize;
+        if total_size % 4 != 0 {
+            return Err(DfdError::Malformed("dfdTotalSize is not a multiple of 4"));
+        }
+        if total_size > dfd.len() * 4 {
+            return Err(DfdError::Truncated);
+        }
+        let block = &dfd[1..total_size.max(4) / 4];
+        if block.len() * 4 < HEADER_SIZE {
+            return Err(DfdError::Truncated);
+        }
+
+        let vendor_id = block[0] & 0x1ffff;
+        let descriptor_type = block[0] >> 17;
+        if vendor_id != _khr_df_vendorid_e_KHR_DF_VENDORID_KHRONOS
+            || descriptor_type != _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_BASICFORMAT
+        {
+            return Err(DfdError::Malformed(
+                "the first descriptor block is not a basic descriptor block",
+            ));
+        }
+        let block_size = (block[1] >> 16) as usize;
+        if block_size < HEADER_SIZE || (block_size - HEADER_SIZE) % SAMPLE_SIZE != 0 {
+            return Err(DfdError::Malformed(
+                "descriptorBlockSize does not hold a whole number of samples",
+            ));
+        }
+        if block_size > block.len() * 4 {
+            return Err(DfdError::Truncated);
+        }
+
+        let bytes = |word: u32| word.to_le_bytes();
+        let [model, primaries, transfer, flags] = bytes(block[2]);
+        let [p0, p1, p2, p3] = bytes(block[4]);
+        let [p4, p5, p6, p7] = bytes(block[5]);
+        let samples = block[HEADER_SIZE / 4..block_size / 4]
+            .chunks_exact(SAMPLE_SIZE / 4)
+            .map(|sample| {
+                let [_, _, length, channel_type] = bytes(sample[0]);
+                SampleDescriptor {
+                    bit_offset: sample[0] as u16,
+                    bit_length: u16::from(length) + 1,
+                    channel: channel_type & 0x0f,
+                    qualifiers: channel_type & 0xf0,
+                    sample_positions: bytes(sample[1]),
+                    lower: sample[2],
+                    upper: sample[3],
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            vendor_id,
+            descriptor_type,
+            version_number: block[1] & 0xffff,
+            color_model: model.into(),
+            color_primaries: primaries.into(),
+            transfer_function: transfer.into(),
+            flags: flags.into(),
+            texel_block_dimensions: bytes(block[3]).map(|dimension| u32::from(dimension) + 1),
+            bytes_planes: [p0, p1, p2, p3, p4, p5, p6, p7],
+            samples,
+        })
+    }
+
+    /// Size of a texel block in bytes, or 0 if unknown, e.g. for supercompressed data.
+    pub fn texel_block_size(&self) -> u32 {
+        self.bytes_planes
+            .iter()
+            .map(|&bytes| u32::from(bytes))
+            .sum()
+    }
+
+    /// Encodes the descriptor as a full DFD with a single basic descriptor block,
+    /// as expected by `ktxTextureCreateInfo::pDfd`.
+    pub fn to_words(&self) -> Result<Vec<u32>, DfdError> {
+        let invalid = |reason| Err(DfdError::Malformed(reason));
+        if self.vendor_id > 0x1ffff || self.descriptor_type > 0x7fff || self.version_number > 0xffff
+        {
+            return invalid("vendor id, descriptor type or version number out of range");
+        }
+        if [
+            self.color_model,
+            self.color_primaries,
+            self.transfer_function,
+            self.flags,
+        ]
+        .iter()
+        .any(|&field| field > 0xff)
+        {
+            return invalid(
+                "color model, primaries, transfer function and flags must fit in 8 bits",
+            );
+        }
+        if self
+            .texel_block_dimensions
+            .iter()
+            .any(|dimension| !(1..=256).contains(dimension))
+        {
+            return invalid("texel block dimensions must be between 1 and 256");
+        }
+        let block_size = HEADER_SIZE + SAMPLE_SIZE * self.samples.len();
+        if block_size > 0xffff {
+            return invalid("too many samples for one descriptor block");
+        }
+        let block_bits = self.texel_block_size() * 8;
+        for sample in &self.samples {
+            if !(1..=256).contains(&sample.bit_length) {
+                return invalid("sample bit lengths must be between 1 and 256");
+            }
+            if sample.channel > 0x0f || sample.qualifiers & 0x0f != 0 {
+                return invalid("channel ids must fit in 4 bits, qualifiers in the top 4");
+            }
+            // A bit offset of 0xffff marks a sample holding a constant.
+            if block_bits != 0
+                && sample.bit_offset != 0xffff
+                && u32::from(sample.bit_offset) + u32::from(sample.bit_length) > block_bits
+            {
+                return invalid("sample extends past the texel block");
+            }
+        }
+
+        let word = |bytes: [u8; 4]| u32::from_le_bytes(bytes);
+        let [p0, p1, p2, p3, p4, p5, p6, p7] = self.bytes_planes;
+        let mut words = Vec::with_capacity(1 + block_size / 4);
+        words.extend([
+            (4 + block_size) as u32,
+            self.vendor_id | self.descriptor_type << 17,
+            self.version_number | (block_size as u32) << 16,
+            self.color_model
+                | self.color_primaries << 8
+                | self.transfer_function << 16
+                | self.flags << 24,
+            word(
+                self.texel_block_dimensions
+                    .map(|dimension| (dimension - 1) as u8),
+            ),
+            word([p0, p1, p2, p3]),
+            word([p4, p5, p6, p7]),
+        ]);
+        for sample in &self.samples {
+            words.extend([
+                u32::from(sample.bit_offset)
+                    | u32::from(sample.bit_length - 1) << 16
+                    | u32::from(sample.channel | sample.qualifiers) << 24,
+                word(sample.sample_positions),
+                sample.lower,
+                sample.upper,
+            ]);
+        }
+        Ok(words)
+    }
+}
+
+/// Builder for data format descriptors, e.g. to create textures with `VK_FORMAT_UNDEFINED`.
+///
+/// [`DfdBuilder::for_vk_format`] starts from the descriptor libktx would write
+/// for a format, otherwise samples are laid out by hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DfdBuilder {
+    descriptor: BasicDescriptor,
+}
+
+impl DfdBuilder {
+    /// A descriptor without samples for `color_model`, with BT.709 primaries,
+    /// linear transfer, straight alpha and 1x1 texel blocks.
+    pub fn new(color_model: khr_df_model_e) -> Self {
+        Self {
+            descriptor: BasicDescriptor {
+                vendor_id: _khr_df_vendorid_e_KHR_DF_VENDORID_KHRONOS,
+                descriptor_type: _khr_df_khr_descriptortype_e_KHR_DF_KHR_DESCRIPTORTYPE_BASICFORMAT,
+                version_number: _khr_df_versionnumber_e_KHR_DF_VERSIONNUMBER_LATEST,
+                color_model,
+                color_primaries: _khr_df_primaries_e_KHR_DF_PRIMARIES_BT709,
+                transfer_function: _khr_df_transfer_e_KHR_DF_TRANSFER_LINEAR,
+                flags: _khr_df_flags_e_KHR_DF_FLAG_ALPHA_STRAIGHT,
+                texel_block_dimensions: [1; 4],
+                bytes_planes: [0; 8],
+                samples: Vec::new(),
+            },
+        }
+    }
+
+    /// The descriptor of `vk_format`, or `None` for formats KTX2 can't describe,
+    /// such as multi-planar ones.
+    pub fn for_vk_format(vk_format: VkFormat) -> Option<Self> {
+        crate::format::layout(vk_format).map(vk::from_layout)
+    }
+
+    pub fn primaries(mut self, primaries: khr_df_primaries_e) -> Self {
+        self.descriptor.color_primaries = primaries;
+        self
+    }
+
+    pub fn transfer_function(mut self, transfer_function: khr_df_transfer_e) -> Self {
+        self.descriptor.transfer_function = transfer_function;
+        self
+    }
+
+    /// `KHR_DF_FLAG_*` bits.
+    pub fn flags(mut self, flags: khr_df_flags_e) -> Self {
+        self.descriptor.flags = flags;
+        self
+    }
+
+    /// Texel block extent, e.g. 4x4x1 for BC formats.
+    pub fn texel_block(mut self, width: u32, height: u32, depth: u32) -> Self {
+        self.descriptor.texel_block_dimensions = [width, height, depth, 1];
+        self
+    }
+
+    /// Bytes per texel block, stored in `bytesPlane0`. Supercompressed data uses 0.
+    pub fn texel_block_size(mut self, bytes: u8) -> Self {
+        self.descriptor.bytes_planes = [bytes, 0, 0, 0, 0, 0, 0, 0];
+        self
+    }
+
+    /// Appends a sample. Samples are usually listed from the least significant bits.
+    pub fn sample(mut self, sample: SampleDescriptor) -> Self {
+        self.descriptor.samples.push(sample);
+        self
+    }
+
+    pub fn descriptor(&self) -> &BasicDescriptor {
+        &self.descriptor
+    }
+
+    /// Validates the descriptor and encodes it, see [`BasicDescriptor::to_words`].
+    pub fn build(&self) -> Result<Vec<u32>, DfdError> {
+        self.descriptor.to_words()
+    }
+}
+
+impl From<DescriptorView<'_>> for BasicDescriptor {
+    fn from(view: DescriptorView<'_>) -> Self {
+        Self {
+            vendor_id: view.vendor_id(),
+            descriptor_type: view.descriptor_type(),
+            version_number: view.version_number(),
+            color_model: view.color_model(),
+            color_primaries: view.color_primaries(),
+            transfer_function: view.transfer_function(),
+            flags: view.flags(),
+            texel_block_dimensions: view.texel_block_dimensions(),
+            bytes_planes: view.bytes_planes(),
+            samples: view.samples().map(SampleDescriptor::from).collect(),
+        }
+    }
+}
+
+impl From<SampleView<'_>> for SampleDescriptor {
+    fn from(view: SampleView<'_>) -> Self {
+        Self {
+            bit_offset: view.bit_offset(),
+            bit_length: view.bit_length(),
+            channel: view.channel(),
+            qualifiers: view.qualifiers(),
+            sample_positions: view.sample_positions(),
+            lower: view.lower(),
+            upper: view.upper(),
+        }
+    }
+}
+
+impl SampleDescriptor {
+    /// An unsigned normalized sample of `channel` covering `bit_length` bits from `bit_offset`.
+    pub fn new(channel: u8, bit_offset: u16, bit_length: u16) -> Self {
+        Self {
+            bit_offset,
+            bit_length,
+            channel,
+            qualifiers: 0,
+            sample_positions: [0; 4],
+            lower: 0,
+            upper: 1u64
+                .checked_shl(bit_length.into())
+                .map_or(u32::MAX, |max| (max - 1).min(u32::MAX.into()) as u32),
+        }
+    }
+
+    pub fn is_linear(&self) -> bool {
+        self.has_qualifier(_khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_LINEAR)
+    }
+
+    pub fn is_exponent(&self) -> bool {
+        self.has_qualifier(_khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_EXPONENT)
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.has_qualifier(_khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_SIGNED)
+    }
+
+    pub fn is_float(&self) -> bool {
+        self.has_qualifier(_khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_FLOAT)
+    }
+
+    fn has_qualifier(&self, qualifier: khr_df_sample_datatype_qualifiers_e) -> bool {
+        u32::from(self.qualifiers) & qualifier != 0
+    }
+}
+
+impl Texture2 {
+    /// Parses the basic descriptor block of the texture's data format descriptor.
+    pub fn dfd(&self) -> Result<BasicDescriptor, DfdError> {
+        unsafe {
+            let dfd = (*self.as_raw().cast::<ktxTexture2>()).pDfd;
+            if dfd.is_null() {
+                return Err(DfdError::Truncated);
+            }
+            // libktx allocates exactly dfdTotalSize bytes.
+            let words = (*dfd as usize / 4).max(1);
+            BasicDescriptor::parse(slice::from_raw_parts(dfd, words))
+        }
+    }
+}
+
+mod vk {
+    use super::{DfdBuilder, SampleDescriptor};
+    use crate::{
+        ffi::*,
+        format::{Layout, Scheme, Suffix},
+    };
+
+    const ALPHA: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_ALPHA as u8;
+    const DEPTH: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_DEPTH as u8;
+    const STENCIL: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_STENCIL as u8;
+
+    // Mirrors `vk2dfd` from libktx, so textures created either way match.
+    pub(super) fn from_layout(layout: Layout) -> DfdBuilder {
+        let suffix = layout.suffix().unwrap_or(Suffix::Unorm);
+        let mut builder = DfdBuilder::new(_khr_df_model_e_KHR_DF_MODEL_RGBSDA);
+        if suffix == Suffix::Srgb {
+            builder = builder.transfer_function(_khr_df_transfer_e_KHR_DF_TRANSFER_SRGB);
+        }
+        match layout {
+            Layout::Unpacked {
+                channels,
+                bytes,
+                bgr,
+                suffix,
+            } => {
+                builder = builder.texel_block_size(channels * bytes);
+                for index in 0..channels {
+                    let channel = if bgr && index != 1 && index < 3 {
+                        2 - index
+                    } else {
+                        index
+                    };
+                    let bits = u16::from(bytes) * 8;
+                    builder =
+                        builder.sample(sample(channel, u16::from(index) * bits, bits, suffix));
+                }
+            }
+            Layout::Packed { components, suffix } => {
+                let bits: u16 = components.iter().map(|&(_, bits)| u16::from(bits)).sum();
+                builder = builder.texel_block_size((bits / 8) as u8);
+                let mut offset = 0;
+                for &(channel, bits) in components.iter().rev() {
+                    builder = builder.sample(sample(channel, offset, bits.into(), suffix));
+                    offset += u16::from(bits);
+                }
+            }
+            Layout::Padded16 { channels, bits } => {
+                builder = builder.texel_block_size(channels * 2);
+                for index in 0..channels {
+                    let offset = u16::from(index) * 16 + 16 - u16::from(bits);
+                    builder = builder.sample(sample(index, offset, bits.into(), Suffix::Unorm));
+                }
+            }
+            Layout::Ycbcr422 { channels, bits } => {
+                let word = if bits == 8 { 8 } else { 16 };
+                builder = DfdBuilder::new(_khr_df_model_e_KHR_DF_MODEL_YUVSDA)
+                    .texel_block(2, 1, 1)
+                    .texel_block_size((word / 2) as u8);
+                let mut luma = 0;
+                for (index, channel) in (0..).zip(channels) {
+                    // Chroma is sited with the first luma sample.
+                    let x = if channel == 0 {
+                        luma += 1;
+                        if luma == 1 {
+                            64
+                        } else {
+                            192
+                        }
+                    } else {
+                        64
+                    };
+                    let offset = index * word + word - u16::from(bits);
+                    builder = builder.sample(SampleDescriptor {
+                        sample_positions: [x, 128, 0, 0],
+                        ..sample(channel, offset, bits.into(), Suffix::Unorm)
+                    });
+                }
+            }
+            Layout::SharedExponent => {
+                builder = builder.texel_block_size(4);
+                for (channel, offset) in [(0, 0), (1, 9), (2, 18)] {
+                    let exponent =
+                        _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_EXPONENT;
+                    builder = builder
+                        .sample(SampleDescriptor {
+                            lower: 0,
+                            upper: 8448,
+                            ..SampleDescriptor::new(channel, offset, 9)
+                        })
+                        .sample(SampleDescriptor {
+                            qualifiers: exponent as u8,
+                            lower: 15,
+                            upper: 31,
+                            ..SampleDescriptor::new(channel, 27, 5)
+                        });
+                }
+            }
+            Layout::DepthStencil {
+                depth,
+                stencil,
+                bytes,
+            } => {
+                builder = builder
+                    .primaries(_khr_df_primaries_e_KHR_DF_PRIMARIES_UNSPECIFIED)
+                    .texel_block_size(bytes);
+                if depth > 0 {
+                    let suffix = if depth == 32 {
+                        Suffix::Sfloat
+                    } else {
+                        Suffix::Unorm
+                    };
+                    builder = builder.sample(sample(DEPTH, 0, depth.into(), suffix));
+                }
+                if stencil > 0 {
+                    builder =
+                        builder.sample(sample(STENCIL, depth.into(), stencil.into(), Suffix::Uint));
+                }
+            }
+            Layout::Compressed {
+                scheme,
+                block: [width, height],
+                suffix,
+            } => {
+                let (model, channels) = compressed(scheme);
+                let bytes = scheme.bytes_per_block();
+                builder.descriptor.color_model = model;
+                builder = builder
+                    .texel_block(width.into(), height.into(), 1)
+                    .texel_block_size(bytes);
+                let bits = u16::from(bytes) * 8 / channels.len() as u16;
+                for (index, &channel) in (0..).zip(channels) {
+                    let mut sample = sample(channel, index * bits, bits, suffix);
+                    if suffix == Suffix::Snorm {
+                        sample.lower = 0x8000_0000;
+                    }
+                    builder = builder.sample(sample);
+                }
+            }
+        }
+        builder
+    }
+
+    // Color model and sample channels of each scheme.
+    fn compressed(scheme: Scheme) -> (khr_df_model_e, &'static [u8]) {
+        const COLOR: u8 = 0;
+        const ETC2_COLOR: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC2_COLOR as u8;
+        const BC1A_ALPHAPRESENT: u8 =
+            _khr_df_model_channels_e_KHR_DF_CHANNEL_BC1A_ALPHAPRESENT as u8;
+        match scheme {
+            Scheme::Bc1Rgb => (_khr_df_model_e_KHR_DF_MODEL_BC1A, &[COLOR]),
+            Scheme::Bc1Rgba => (_khr_df_model_e_KHR_DF_MODEL_BC1A, &[BC1A_ALPHAPRESENT]),
+            Scheme::Bc2 => (_khr_df_model_e_KHR_DF_MODEL_BC2, &[ALPHA, COLOR]),
+            Scheme::Bc3 => (_khr_df_model_e_KHR_DF_MODEL_BC3, &[ALPHA, COLOR]),
+            Scheme::Bc4 => (_khr_df_model_e_KHR_DF_MODEL_BC4, &[0]),
+            Scheme::Bc5 => (_khr_df_model_e_KHR_DF_MODEL_BC5, &[0, 1]),
+            Scheme::Bc6h => (_khr_df_model_e_KHR_DF_MODEL_BC6H, &[COLOR]),
+            Scheme::Bc7 => (_khr_df_model_e_KHR_DF_MODEL_BC7, &[COLOR]),
+            Scheme::Etc2Rgb | Scheme::Etc2Rgba1 => {
+                (_khr_df_model_e_KHR_DF_MODEL_ETC2, &[ETC2_COLOR])
+            }
+            Scheme::Etc2Rgba8 => (_khr_df_model_e_KHR_DF_MODEL_ETC2, &[ALPHA, ETC2_COLOR]),
+            Scheme::EacR11 => (_khr_df_model_e_KHR_DF_MODEL_ETC2, &[0]),
+            Scheme::EacRg11 => (_khr_df_model_e_KHR_DF_MODEL_ETC2, &[0, 1]),
+            Scheme::Astc => (_khr_df_model_e_KHR_DF_MODEL_ASTC, &[0]),
+            Scheme::Pvrtc1 => (_khr_df_model_e_KHR_DF_MODEL_PVRTC, &[0]),
+            Scheme::Pvrtc2 => (_khr_df_model_e_KHR_DF_MODEL_PVRTC2, &[0]),
+        }
+    }
+
+    fn sample(channel: u8, bit_offset: u16, bit_length: u16, suffix: Suffix) -> SampleDescriptor {
+        const LINEAR: u32 = _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_LINEAR;
+        const SIGNED: u32 = _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_SIGNED;
+        const FLOAT: u32 = _khr_df_sample_datatype_qualifiers_e_KHR_DF_SAMPLE_DATATYPE_FLOAT;
+
+        // RGBSDA has no channel 3, the fourth channel of a format is alpha.
+        let channel = if channel == 3 { ALPHA } else { channel };
+        let unorm = SampleDescriptor::new(channel, bit_offset, bit_length);
+        let (qualifiers, lower, upper) = match suffix {
+            Suffix::Unorm => (0, 0, unorm.upper),
+            // Alpha stays linear in sRGB formats.
+            Suffix::Srgb => (if channel == ALPHA { LINEAR } else { 0 }, 0, unorm.upper),
+            Suffix::Snorm => {
+                let upper = unorm.upper >> 1;
+                (SIGNED, upper.wrapping_neg(), upper)
+            }
+            Suffix::Uint | Suffix::Uscaled => (0, 0, 1),
+            Suffix::Sint | Suffix::Sscaled => (SIGNED, u32::MAX, 1),
+            Suffix::Sfloat => (FLOAT | SIGNED, (-1.0f32).to_bits(), 1.0f32.to_bits()),
+            Suffix::Ufloat => (FLOAT, 0.0f32.to_bits(), 1.0f32.to_bits()),
+        };
+        SampleDescriptor {
+            qualifiers: qualifiers as u8,
+            lower,
+            upper,
+            ..unorm
+        }
+    }
+}
+
+/// Failure to parse or encode a data format descriptor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DfdError {
+    /// The descriptor ends before a size it declares.
+    Truncated,
+    /// The descriptor violates the Khronos Data Format specification, or has
+    /// values that don't fit in its fields.
+    Malformed(&'static str),
+}
+
+impl fmt::Display for DfdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DfdError::Truncated => f.write_str("truncated data format descriptor"),
+            DfdError::Malformed(reason) => write!(f, "malformed data format descriptor: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DfdError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TextureBuilder;
+
+    #[test]
+    fn parse_rejects_truncated() {
+        let words = DfdBuilder::new(_khr_df_model_e_KHR_DF_MODEL_RGBSDA)
+            .texel_block_size(1)
+            .sample(SampleDescriptor::new(0, 0, 8))
+            .build()
+            .unwrap();
+        assert_eq!(
+            BasicDescriptor::parse(&words[..words.len() - 1]),
+            Err(DfdError::Truncated)
+        );
+        assert_eq!(BasicDescriptor::parse(&[]), Err(DfdError::Truncated));
+    }
+
+    #[test]
+    fn build_rejects_out_of_range() {
+        let builder = DfdBuilder::new(_khr_df_model_e_KHR_DF_MODEL_RGBSDA).texel_block_size(1);
+        let too_long = builder.clone().sample(SampleDescriptor::new(0, 0, 16));
+        assert!(matches!(too_long.build(), Err(DfdError::Malformed(_))));
+        let empty = builder.sample(SampleDescriptor::new(0, 0, 0));
+        assert!(matches!(empty.build(), Err(DfdError::Malformed(_))));
+    }
+
+    #[test]
+    fn custom_layout_round_trip() {
+        // 10 bit luminance and 6 bit alpha packed into 16 bits.
+        let builder = DfdBuilder::new(_khr_df_model_e_KHR_DF_MODEL_RGBSDA)
+            .texel_block_size(2)
+            .sample(SampleDescriptor::new(0, 0, 10))
+            .sample(SampleDescriptor::new(1, 0, 10))
+            .sample(SampleDescriptor::new(2, 0, 10))
+            .sample(SampleDescriptor::new(15, 10, 6));
+        let words = builder.build().unwrap();
+        assert_eq!(
+            BasicDescriptor::parse(&words).as_ref(),
+            Ok(builder.descriptor())
+        );
+
+        let texture = TextureBuilder::new()
+            .extent_2d(4, 4)
+            .dfd(words)
+            .build_ktx2()
+            .unwrap();
+        assert_eq!(texture.dfd().as_ref(), Ok(builder.descriptor()));
+    }
+
+    #[test]
+    fn vk_formats_round_trip() {
+        for &(vk_format, ..) in crate::format::FORMATS {
+            let builder = DfdBuilder::for_vk_format(vk_format).unwrap();
+            let words = builder.build().unwrap();
+            assert_eq!(
+                BasicDescriptor::parse(&words).as_ref(),
+                Ok(builder.descriptor()),
+                "VkFormat {vk_format}"
+            );
+        }
+    }
+
+    #[test]
+    fn vk_formats_match_libktx() {
+        for vk_format in [
+            VkFormat_VK_FORMAT_R8_UNORM,
+            VkFormat_VK_FORMAT_R8G8B8A8_UNORM,
+            VkFormat_VK_FORMAT_R8G8B8A8_SRGB,
+            VkFormat_VK_FORMAT_B8G8R8A8_SRGB,
+            VkFormat_VK_FORMAT_R5G6B5_UNORM_PACK16,
+            VkFormat_VK_FORMAT_A2B10G10R10_UNORM_PACK32,
+            VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT,
+            VkFormat_VK_FORMAT_R32G32_SFLOAT,
+            VkFormat_VK_FORMAT_B10G11R11_UFLOAT_PACK32,
+            VkFormat_VK_FORMAT_BC1_RGB_UNORM_BLOCK,
+            VkFormat_VK_FORMAT_BC3_SRGB_BLOCK,
+            VkFormat_VK_FORMAT_BC5_UNORM_BLOCK,
+            VkFormat_VK_FORMAT_BC7_SRGB_BLOCK,
+            VkFormat_VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK,
+            VkFormat_VK_FORMAT_ASTC_4x4_UNORM_BLOCK,
+            VkFormat_VK_FORMAT_ASTC_8x6_SRGB_BLOCK,
+        ] {
+            let expected = DfdBuilder::for_vk_format(vk_format).unwrap();
+            let texture = TextureBuilder::new()
+                .extent_2d(16, 16)
+                .vk_format(vk_format)
+                .build_ktx2()
+                .unwrap();
+            assert_eq!(
+                texture.dfd().as_ref(),
+                Ok(expected.descriptor()),
+                "VkFormat {vk_format}"
+            );
+
+            // libktx derives the same layout from the DFD alone.
+            let undefined = TextureBuilder::new()
+                .extent_2d(16, 16)
+                .dfd(expected.build().unwrap())
+                .build_ktx2()
+                .unwrap();
+            assert_eq!(
+                undefined.data_size(),
+                texture.data_size(),
+                "VkFormat {vk_format}"
+            );
+        }
+    }
+}
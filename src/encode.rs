@@ -0,0 +1,804 @@
+//! Typed parameters for the Basis Universal and ASTC encoders of libktx.
+
+use std::{fmt, mem, os::raw::c_char};
+
+use crate::{error::check, ffi::*, BaseTexture, FormatInfo, KtxError, Swizzle, Texture2};
+
+/// Settings for [`Texture2::compress_basis`], over `ktxBasisParams`.
+///
+/// Fields that libktx treats as "use the encoder default" when zero are `Option`s here.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncodeBasis {
+    pub codec: BasisCodec,
+    /// Number of encoder threads, at least 1.
+    pub thread_count: u32,
+    /// Tunes the encoder for normal maps stored as X in RGB and Y in alpha.
+    pub normal_map: bool,
+    /// Rearranges the input components before encoding.
+    pub input_swizzle: Option<Swizzle>,
+    /// Applies the swizzle stored in the texture's `KTXswizzle` metadata first.
+    pub pre_swizzle: bool,
+    pub verbose: bool,
+    pub no_sse: bool,
+}
+
+/// The two Basis Universal codecs and the settings only they use.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BasisCodec {
+    Etc1s(Etc1s),
+    Uastc(Uastc),
+}
+
+/// ETC1S settings, producing small BasisLZ supercompressed files.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Etc1s {
+    /// Speed vs. quality tradeoff, 0 to 5.
+    pub compression_level: u32,
+    /// 1 to 255, or the encoder default. Overrides the endpoint and selector limits.
+    pub quality: Option<u32>,
+    /// Endpoint clusters, 1 to 16128.
+    pub max_endpoints: Option<u32>,
+    /// Selector clusters, 1 to 16128.
+    pub max_selectors: Option<u32>,
+    pub endpoint_rdo_threshold: Option<f32>,
+    pub selector_rdo_threshold: Option<f32>,
+    pub endpoint_rdo: bool,
+    pub selector_rdo: bool,
+    /// Moves the G component of a 2-component input into alpha.
+    pub separate_rg_to_rgb_a: bool,
+}
+
+/// UASTC settings, producing high quality files transcodable to most block formats.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Uastc {
+    pub level: UastcLevel,
+    pub favor_uastc_error: bool,
+    pub favor_bc7_error: bool,
+    pub etc1_faster_hints: bool,
+    pub etc1_fastest_hints: bool,
+    pub etc1_disable_flip_and_individual: bool,
+    /// Rate-distortion optimization, trading quality for better zstd compression.
+    pub rdo: Option<Rdo>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum UastcLevel {
+    Fastest = ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_FASTEST,
+    Faster = ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_FASTER,
+    #[default]
+    Default = ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_DEFAULT,
+    Slower = ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_SLOWER,
+    VerySlow = ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_LEVEL_VERYSLOW,
+}
+
+/// UASTC rate-distortion optimization settings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rdo {
+    /// Lower is higher quality and larger files, 0.001 to 10.
+    pub quality_scalar: f32,
+    /// 64 to 65536.
+    pub dict_size: u32,
+    /// 1 to 300.
+    pub max_smooth_block_error_scale: f32,
+    /// 0.01 to 65536.
+    pub max_smooth_block_std_dev: f32,
+    pub favor_simpler_modes: bool,
+    pub multithreading: bool,
+}
+
+impl EncodeBasis {
+    pub fn new(codec: BasisCodec) -> Self {
+        Self {
+            codec,
+            thread_count: 1,
+            normal_map: false,
+            input_swizzle: None,
+            pre_swizzle: false,
+            verbose: false,
+            no_sse: false,
+        }
+    }
+
+    /// Quick ETC1S encoding for iteration during development.
+    pub fn fast() -> Self {
+        Self::new(BasisCodec::Etc1s(Etc1s {
+            compression_level: 0,
+            ..Etc1s::default()
+        }))
+    }
+
+    /// ETC1S with the encoder's default speed and quality.
+    pub fn default_etc1s() -> Self {
+        Self::new(BasisCodec::Etc1s(Etc1s::default()))
+    }
+
+    /// Highest quality UASTC without RDO.
+    pub fn archival() -> Self {
+        Self::new(BasisCodec::Uastc(Uastc {
+            level: UastcLevel::VerySlow,
+            ..Uastc::default()
+        }))
+    }
+
+    /// UASTC tuned for normal maps, keeping X in RGB and moving Y to alpha.
+    pub fn normal_map() -> Self {
+        Self {
+            normal_map: true,
+            input_swizzle: Some("rrrg".parse().unwrap()),
+            ..Self::new(BasisCodec::Uastc(Uastc::default()))
+        }
+    }
+
+    pub fn thread_count(mut self, thread_count: u32) -> Self {
+        self.thread_count = thread_count;
+        self
+    }
+
+    pub fn input_swizzle(mut self, swizzle: Swizzle) -> Self {
+        self.input_swizzle = Some(swizzle);
+        self
+    }
+
+    pub fn pre_swizzle(mut self, pre_swizzle: bool) -> Self {
+        self.pre_swizzle = pre_swizzle;
+        self
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Validates the settings and converts them to `ktxBasisParams`.
+    pub fn to_raw(&self) -> Result<ktxBasisParams, EncodeError> {
+        if self.thread_count == 0 {
+            return invalid("thread_count must be at least 1");
+        }
+        // All-zero is the documented starting point, with structSize set.
+        let mut params: ktxBasisParams = unsafe { mem::zeroed() };
+        params.structSize = mem::size_of::<ktxBasisParams>() as u32;
+        params.threadCount = self.thread_count;
+        params.normalMap = self.normal_map;
+        params.preSwizzle = self.pre_swizzle;
+        params.verbose = self.verbose;
+        params.noSSE = self.no_sse;
+        if let Some(swizzle) = self.input_swizzle {
+            params.inputSwizzle = swizzle_chars(swizzle);
+        }
+        match &self.codec {
+            BasisCodec::Etc1s(etc1s) => etc1s.apply(&mut params)?,
+            BasisCodec::Uastc(uastc) => uastc.apply(&mut params)?,
+        }
+        Ok(params)
+    }
+}
+
+impl Default for EncodeBasis {
+    fn default() -> Self {
+        Self::default_etc1s()
+    }
+}
+
+impl Etc1s {
+    fn apply(&self, params: &mut ktxBasisParams) -> Result<(), EncodeError> {
+        if self.compression_level > 5 {
+            return invalid("ETC1S compression_level must be between 0 and 5");
+        }
+        if !in_range(self.quality, 1..=255) {
+            return invalid("ETC1S quality must be between 1 and 255");
+        }
+        if !in_range(self.max_endpoints, 1..=16128) || !in_range(self.max_selectors, 1..=16128) {
+            return invalid("ETC1S max_endpoints and max_selectors must be between 1 and 16128");
+        }
+        params.uastc = false;
+        params.compressionLevel = self.compression_level;
+        params.qualityLevel = self.quality.unwrap_or(0);
+        params.maxEndpoints = self.max_endpoints.unwrap_or(0);
+        params.maxSelectors = self.max_selectors.unwrap_or(0);
+        params.endpointRDOThreshold = self.endpoint_rdo_threshold.unwrap_or(0.0);
+        params.selectorRDOThreshold = self.selector_rdo_threshold.unwrap_or(0.0);
+        params.noEndpointRDO = !self.endpoint_rdo;
+        params.noSelectorRDO = !self.selector_rdo;
+        params.separateRGToRGB_A = self.separate_rg_to_rgb_a;
+        Ok(())
+    }
+}
+
+impl Default for Etc1s {
+    fn default() -> Self {
+        Self {
+            // 0 is a valid level, so the encoder's default has to be asked for explicitly.
+            compression_level: unsafe { KTX_ETC1S_DEFAULT_COMPRESSION_LEVEL },
+            quality: None,
+            max_endpoints: None,
+            max_selectors: None,
+            endpoint_rdo_threshold: None,
+            selector_rdo_threshold: None,
+            endpoint_rdo: true,
+            selector_rdo: true,
+            separate_rg_to_rgb_a: false,
+        }
+    }
+}
+
+impl Uastc {
+    fn apply(&self, params: &mut ktxBasisParams) -> Result<(), EncodeError> {
+        params.uastc = true;
+        params.uastcFlags = self.level as u32;
+        for (set, flag) in [
+            (
+                self.favor_uastc_error,
+                ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_FAVOR_UASTC_ERROR,
+            ),
+            (
+                self.favor_bc7_error,
+                ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_FAVOR_BC7_ERROR,
+            ),
+            (
+                self.etc1_faster_hints,
+                ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_ETC1_FASTER_HINTS,
+            ),
+            (
+                self.etc1_fastest_hints,
+                ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_ETC1_FASTEST_HINTS,
+            ),
+            (
+                self.etc1_disable_flip_and_individual,
+                ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC__ETC1_DISABLE_FLIP_AND_INDIVIDUAL,
+            ),
+        ] {
+            if set {
+                params.uastcFlags |= flag;
+            }
+        }
+        if let Some(rdo) = &self.rdo {
+            if !(0.001..=10.0).contains(&rdo.quality_scalar) {
+                return invalid("UASTC RDO quality_scalar must be between 0.001 and 10");
+            }
+            if !(64..=65536).contains(&rdo.dict_size) {
+                return invalid("UASTC RDO dict_size must be between 64 and 65536");
+            }
+            if !(1.0..=300.0).contains(&rdo.max_smooth_block_error_scale) {
+                return invalid("UASTC RDO max_smooth_block_error_scale must be between 1 and 300");
+            }
+            if !(0.01..=65536.0).contains(&rdo.max_smooth_block_std_dev) {
+                return invalid(
+                    "UASTC RDO max_smooth_block_std_dev must be between 0.01 and 65536",
+                );
+            }
+            params.uastcRDO = true;
+            params.uastcRDOQualityScalar = rdo.quality_scalar;
+            params.uastcRDODictSize = rdo.dict_size;
+            params.uastcRDOMaxSmoothBlockErrorScale = rdo.max_smooth_block_error_scale;
+            params.uastcRDOMaxSmoothBlockStdDev = rdo.max_smooth_block_std_dev;
+            params.uastcRDODontFavorSimplerModes = !rdo.favor_simpler_modes;
+            params.uastcRDONoMultithreading = !rdo.multithreading;
+        }
+        Ok(())
+    }
+}
+
+/// The encoder's defaults.
+impl Default for Rdo {
+    fn default() -> Self {
+        Self {
+            quality_scalar: 1.0,
+            dict_size: 4096,
+            max_smooth_block_error_scale: 10.0,
+            max_smooth_block_std_dev: 18.0,
+            favor_simpler_modes: true,
+            multithreading: true,
+        }
+    }
+}
+
+impl Texture2 {
+    /// Encodes the uncompressed image data with Basis Universal.
+    pub fn compress_basis(&mut self, params: &EncodeBasis) -> Result<(), EncodeError> {
+        let mut params = params.to_raw()?;
+        check(unsafe { ktxTexture2_CompressBasisEx(self.as_raw().cast(), &mut params) })?;
+        Ok(())
+    }
+
+    /// Encodes the uncompressed image data as ASTC, after checking `params` suit the texture.
+    pub fn compress_astc(&mut self, params: &AstcParams) -> Result<(), EncodeError> {
+        if params.block.is_3d() && self.num_dimensions() != 3 {
+            return invalid("3D ASTC blocks need a 3D texture");
+        }
+        let hdr_source = FormatInfo::of(self.vk_format())
+            .is_some_and(|info| !info.is_compressed() && (info.is_float || info.channel_bits > 8));
+        if params.mode == AstcMode::Hdr && !hdr_source {
+            return invalid(
+                "HDR ASTC needs an uncompressed floating point source or one with more than 8 bits per channel",
+            );
+        }
+        let mut raw = params.to_raw()?;
+        check(unsafe { ktxTexture2_CompressAstcEx(self.as_raw().cast(), &mut raw) })?;
+        Ok(())
+    }
+}
+
+/// Settings for [`Texture2::compress_astc`], over `ktxAstcParams`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AstcParams {
+    pub block: AstcBlock,
+    pub mode: AstcMode,
+    pub quality: AstcQuality,
+    /// Number of encoder threads, at least 1.
+    pub thread_count: u32,
+    /// Optimizes for perceptual rather than peak signal-to-noise error.
+    pub perceptual: bool,
+    /// Tunes the encoder for normal maps stored as X in RGB and Y in alpha.
+    pub normal_map: bool,
+    /// Rearranges the input components before encoding.
+    pub input_swizzle: Option<Swizzle>,
+    pub verbose: bool,
+}
+
+/// ASTC block footprints, 2D ones followed by 3D ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum AstcBlock {
+    #[default]
+    B4x4 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x4,
+    B5x4 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x4,
+    B5x5 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x5,
+    B6x5 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x5,
+    B6x6 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x6,
+    B8x5 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_8x5,
+    B8x6 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_8x6,
+    B10x5 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x5,
+    B10x6 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x6,
+    B8x8 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_8x8,
+    B10x8 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x8,
+    B10x10 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_10x10,
+    B12x10 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_12x10,
+    B12x12 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_12x12,
+    B3x3x3 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_3x3x3,
+    B4x3x3 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x3x3,
+    B4x4x3 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x4x3,
+    B4x4x4 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_4x4x4,
+    B5x4x4 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x4x4,
+    B5x5x4 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x5x4,
+    B5x5x5 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_5x5x5,
+    B6x5x5 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x5x5,
+    B6x6x5 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x6x5,
+    B6x6x6 = ktx_pack_astc_block_dimension_e_KTX_PACK_ASTC_BLOCK_DIMENSION_6x6x6,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum AstcMode {
+    /// LDR or HDR depending on the texture's format.
+    #[default]
+    Default = ktx_pack_astc_encoder_mode_e_KTX_PACK_ASTC_ENCODER_MODE_DEFAULT,
+    Ldr = ktx_pack_astc_encoder_mode_e_KTX_PACK_ASTC_ENCODER_MODE_LDR,
+    Hdr = ktx_pack_astc_encoder_mode_e_KTX_PACK_ASTC_ENCODER_MODE_HDR,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum AstcQuality {
+    Fastest = ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_FASTEST,
+    Fast = ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_FAST,
+    /// Recommended by libktx.
+    #[default]
+    Medium = ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_MEDIUM,
+    Thorough = ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_THOROUGH,
+    Exhaustive = ktx_pack_astc_quality_levels_e_KTX_PACK_ASTC_QUALITY_LEVEL_EXHAUSTIVE,
+}
+
+impl AstcBlock {
+    pub fn is_3d(self) -> bool {
+        matches!(
+            self,
+            AstcBlock::B3x3x3
+                | AstcBlock::B4x3x3
+                | AstcBlock::B4x4x3
+                | AstcBlock::B4x4x4
+                | AstcBlock::B5x4x4
+                | AstcBlock::B5x5x4
+                | AstcBlock::B5x5x5
+                | AstcBlock::B6x5x5
+                | AstcBlock::B6x6x5
+                | AstcBlock::B6x6x6
+        )
+    }
+}
+
+impl AstcParams {
+    pub fn new(block: AstcBlock) -> Self {
+        Self {
+            block,
+            ..Self::default()
+        }
+    }
+
+    pub fn mode(mut self, mode: AstcMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn quality(mut self, quality: AstcQuality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    pub fn thread_count(mut self, thread_count: u32) -> Self {
+        self.thread_count = thread_count;
+        self
+    }
+
+    pub fn perceptual(mut self, perceptual: bool) -> Self {
+        self.perceptual = perceptual;
+        self
+    }
+
+    pub fn normal_map(mut self, normal_map: bool) -> Self {
+        self.normal_map = normal_map;
+        self
+    }
+
+    pub fn input_swizzle(mut self, swizzle: Swizzle) -> Self {
+        self.input_swizzle = Some(swizzle);
+        self
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Validates the settings on their own and converts them to `ktxAstcParams`.
+    pub fn to_raw(&self) -> Result<ktxAstcParams, EncodeError> {
+        if self.thread_count == 0 {
+            return invalid("thread_count must be at least 1");
+        }
+        let mut params: ktxAstcParams = unsafe { mem::zeroed() };
+        params.structSize = mem::size_of::<ktxAstcParams>() as u32;
+        params.verbose = self.verbose;
+        params.threadCount = self.thread_count;
+        params.blockDimension = self.block as u32;
+        params.mode = self.mode as u32;
+        params.qualityLevel = self.quality as u32;
+        params.normalMap = self.normal_map;
+        params.perceptual = self.perceptual;
+        if let Some(swizzle) = self.input_swizzle {
+            params.inputSwizzle = swizzle_chars(swizzle);
+        }
+        Ok(params)
+    }
+}
+
+impl Default for AstcParams {
+    fn default() -> Self {
+        Self {
+            block: AstcBlock::default(),
+            mode: AstcMode::default(),
+            quality: AstcQuality::default(),
+            thread_count: 1,
+            perceptual: false,
+            normal_map: false,
+            input_swizzle: None,
+            verbose: false,
+        }
+    }
+}
+
+/// Failure to encode a texture.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The parameters are rejected before reaching libktx.
+    Invalid(&'static str),
+    Ktx(KtxError),
+}
+
+impl From<KtxError> for EncodeError {
+    fn from(error: KtxError) -> Self {
+        EncodeError::Ktx(error)
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Invalid(reason) => write!(f, "invalid encoder parameters: {reason}"),
+            EncodeError::Ktx(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EncodeError::Invalid(_) => None,
+            EncodeError::Ktx(error) => Some(error),
+        }
+    }
+}
+
+fn invalid<T>(reason: &'static str) -> Result<T, EncodeError> {
+    Err(EncodeError::Invalid(reason))
+}
+
+fn in_range(value: Option<u32>, range: std::ops::RangeInclusive<u32>) -> bool {
+    value.map_or(true, |value| range.contains(&value))
+}
+
+fn swizzle_chars(swizzle: Swizzle) -> [c_char; 4] {
+    let mut chars = [0; 4];
+    for (out, c) in chars.iter_mut().zip(swizzle.to_string().bytes()) {
+        *out = c as c_char;
+    }
+    chars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::rgba8_gradient, TextureBuilder};
+
+    fn rejection<T>(result: Result<T, EncodeError>) -> &'static str {
+        match result {
+            Err(EncodeError::Invalid(reason)) => reason,
+            Err(error) => panic!("rejected by libktx instead: {error}"),
+            Ok(_) => panic!("not rejected"),
+        }
+    }
+
+    // Etc1s::default reads KTX_ETC1S_DEFAULT_COMPRESSION_LEVEL from libktx.
+    fn etc1s() -> Etc1s {
+        Etc1s {
+            compression_level: 2,
+            quality: None,
+            max_endpoints: None,
+            max_selectors: None,
+            endpoint_rdo_threshold: None,
+            selector_rdo_threshold: None,
+            endpoint_rdo: true,
+            selector_rdo: true,
+            separate_rg_to_rgb_a: false,
+        }
+    }
+
+    #[test]
+    fn basis_rejects_out_of_range_settings() {
+        let etc1s_with = |f: fn(&mut Etc1s)| {
+            let mut etc1s = etc1s();
+            f(&mut etc1s);
+            EncodeBasis::new(BasisCodec::Etc1s(etc1s))
+        };
+        let rdo_with = |f: fn(&mut Rdo)| {
+            let mut rdo = Rdo::default();
+            f(&mut rdo);
+            EncodeBasis::new(BasisCodec::Uastc(Uastc {
+                rdo: Some(rdo),
+                ..Uastc::default()
+            }))
+        };
+        let cases = [
+            (EncodeBasis::archival().thread_count(0), "thread_count"),
+            (etc1s_with(|e| e.compression_level = 6), "compression_level"),
+            (etc1s_with(|e| e.quality = Some(0)), "quality"),
+            (etc1s_with(|e| e.quality = Some(256)), "quality"),
+            (etc1s_with(|e| e.max_endpoints = Some(0)), "max_endpoints"),
+            (
+                etc1s_with(|e| e.max_endpoints = Some(16129)),
+                "max_endpoints",
+            ),
+            (etc1s_with(|e| e.max_selectors = Some(0)), "max_selectors"),
+            (
+                etc1s_with(|e| e.max_selectors = Some(16129)),
+                "max_selectors",
+            ),
+            (rdo_with(|r| r.quality_scalar = 0.0), "quality_scalar"),
+            (rdo_with(|r| r.quality_scalar = 10.5), "quality_scalar"),
+            (rdo_with(|r| r.dict_size = 63), "dict_size"),
+            (rdo_with(|r| r.dict_size = 65537), "dict_size"),
+            (
+                rdo_with(|r| r.max_smooth_block_error_scale = 0.5),
+                "error_scale",
+            ),
+            (
+                rdo_with(|r| r.max_smooth_block_error_scale = 301.0),
+                "error_scale",
+            ),
+            (rdo_with(|r| r.max_smooth_block_std_dev = 0.0), "std_dev"),
+            (
+                rdo_with(|r| r.max_smooth_block_std_dev = 65537.0),
+                "std_dev",
+            ),
+        ];
+        for (params, field) in cases {
+            let reason = rejection(params.to_raw());
+            assert!(reason.contains(field), "{field}: {reason}");
+        }
+
+        // The bounds themselves are accepted.
+        for f in [
+            |e: &mut Etc1s| e.compression_level = 5,
+            |e: &mut Etc1s| e.quality = Some(1),
+            |e: &mut Etc1s| e.quality = Some(255),
+            |e: &mut Etc1s| e.max_endpoints = Some(16128),
+            |e: &mut Etc1s| e.max_selectors = Some(1),
+        ] {
+            etc1s_with(f).to_raw().unwrap();
+        }
+        rdo_with(|r| r.dict_size = 65536).to_raw().unwrap();
+    }
+
+    #[test]
+    fn basis_codec_fields_stay_separate() {
+        let raw = EncodeBasis::new(BasisCodec::Etc1s(Etc1s {
+            quality: Some(128),
+            max_endpoints: Some(100),
+            max_selectors: Some(200),
+            endpoint_rdo: false,
+            ..etc1s()
+        }))
+        .thread_count(4)
+        .to_raw()
+        .unwrap();
+        assert_eq!(raw.structSize as usize, mem::size_of::<ktxBasisParams>());
+        assert!(!raw.uastc);
+        assert_eq!(raw.threadCount, 4);
+        assert_eq!(
+            (
+                raw.compressionLevel,
+                raw.qualityLevel,
+                raw.maxEndpoints,
+                raw.maxSelectors
+            ),
+            (2, 128, 100, 200)
+        );
+        assert!(raw.noEndpointRDO && !raw.noSelectorRDO);
+        assert_eq!(raw.uastcFlags, 0);
+        assert!(!raw.uastcRDO);
+        assert_eq!(raw.uastcRDODictSize, 0);
+
+        let raw = EncodeBasis::new(BasisCodec::Uastc(Uastc {
+            level: UastcLevel::Slower,
+            favor_bc7_error: true,
+            rdo: Some(Rdo {
+                dict_size: 8192,
+                multithreading: false,
+                ..Rdo::default()
+            }),
+            ..Uastc::default()
+        }))
+        .to_raw()
+        .unwrap();
+        assert!(raw.uastc);
+        assert_eq!(
+            raw.uastcFlags,
+            UastcLevel::Slower as u32 | ktx_pack_uastc_flag_bits_e_KTX_PACK_UASTC_FAVOR_BC7_ERROR
+        );
+        assert!(raw.uastcRDO && raw.uastcRDONoMultithreading && !raw.uastcRDODontFavorSimplerModes);
+        assert_eq!(
+            (raw.uastcRDODictSize, raw.uastcRDOQualityScalar),
+            (8192, 1.0)
+        );
+        assert_eq!(
+            (
+                raw.compressionLevel,
+                raw.qualityLevel,
+                raw.maxEndpoints,
+                raw.maxSelectors
+            ),
+            (0, 0, 0, 0)
+        );
+        assert!(!raw.noEndpointRDO && !raw.noSelectorRDO);
+    }
+
+    #[test]
+    fn basis_presets() {
+        let raw = EncodeBasis::archival().to_raw().unwrap();
+        assert!(raw.uastc && !raw.uastcRDO && !raw.normalMap);
+        assert_eq!(raw.uastcFlags, UastcLevel::VerySlow as u32);
+
+        let raw = EncodeBasis::normal_map().to_raw().unwrap();
+        assert!(raw.uastc && raw.normalMap);
+        assert_eq!(raw.uastcFlags, UastcLevel::Default as u32);
+        assert_eq!(raw.inputSwizzle.map(|c| c as u8), *b"rrrg");
+    }
+
+    #[test]
+    fn etc1s_presets() {
+        let raw = EncodeBasis::fast().to_raw().unwrap();
+        assert!(!raw.uastc);
+        assert_eq!((raw.compressionLevel, raw.threadCount), (0, 1));
+
+        let raw = EncodeBasis::default_etc1s().to_raw().unwrap();
+        assert!(!raw.uastc);
+        assert_eq!(raw.compressionLevel, unsafe {
+            KTX_ETC1S_DEFAULT_COMPRESSION_LEVEL
+        });
+        assert_eq!((raw.qualityLevel, raw.maxEndpoints), (0, 0));
+        assert_eq!(EncodeBasis::default(), EncodeBasis::default_etc1s());
+    }
+
+    #[test]
+    fn compress_basis_etc1s() {
+        let mut texture = rgba8_gradient(16, 16);
+        texture.compress_basis(&EncodeBasis::fast()).unwrap();
+        assert_eq!(
+            texture.supercompression_scheme(),
+            ktxSupercmpScheme_KTX_SS_BASIS_LZ
+        );
+        assert_eq!(
+            texture.dfd().unwrap().color_model,
+            _khr_df_model_e_KHR_DF_MODEL_ETC1S
+        );
+        assert!(texture.needs_transcoding());
+    }
+
+    #[test]
+    fn compress_basis_uastc() {
+        let mut texture = rgba8_gradient(16, 16);
+        let params = EncodeBasis::new(BasisCodec::Uastc(Uastc {
+            level: UastcLevel::Fastest,
+            ..Uastc::default()
+        }));
+        texture.compress_basis(&params).unwrap();
+        assert_eq!(
+            texture.supercompression_scheme(),
+            ktxSupercmpScheme_KTX_SS_NONE
+        );
+        assert_eq!(
+            texture.dfd().unwrap().color_model,
+            _khr_df_model_e_KHR_DF_MODEL_UASTC
+        );
+        assert!(texture.needs_transcoding());
+    }
+
+    #[test]
+    fn normal_map_preset_records_its_swizzle() {
+        let mut texture = rgba8_gradient(16, 16);
+        texture.compress_basis(&EncodeBasis::normal_map()).unwrap();
+        assert_eq!(
+            texture.metadata().swizzle(),
+            Ok(Some("rrrg".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn astc_block_dimensions() {
+        use AstcBlock::*;
+        for block in [B4x4, B5x5, B8x6, B10x10, B12x12] {
+            assert!(!block.is_3d(), "{block:?}");
+        }
+        for block in [B3x3x3, B4x4x3, B5x5x5, B6x6x6] {
+            assert!(block.is_3d(), "{block:?}");
+        }
+    }
+
+    #[test]
+    fn compress_astc_rejects_unsuitable_textures() {
+        assert!(rejection(AstcParams::default().thread_count(0).to_raw()).contains("thread_count"));
+
+        let mut rgba8 = TextureBuilder::new()
+            .vk_format(VkFormat_VK_FORMAT_R8G8B8A8_UNORM)
+            .extent_2d(8, 8)
+            .build_ktx2()
+            .unwrap();
+        let reason = rejection(rgba8.compress_astc(&AstcParams::new(AstcBlock::B4x4x4)));
+        assert!(reason.contains("3D ASTC blocks"), "{reason}");
+        let reason = rejection(rgba8.compress_astc(&AstcParams::default().mode(AstcMode::Hdr)));
+        assert!(reason.contains("HDR ASTC"), "{reason}");
+
+        let mut rgba16f = TextureBuilder::new()
+            .vk_format(VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT)
+            .extent_2d(8, 8)
+            .build_ktx2()
+            .unwrap();
+        rgba16f
+            .compress_astc(&AstcParams::default().mode(AstcMode::Hdr))
+            .unwrap();
+        assert_eq!(
+            rgba16f.vk_format(),
+            VkFormat_VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK
+        );
+        assert!(rgba16f.is_compressed());
+    }
+}
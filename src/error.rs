@@ -0,0 +1,129 @@
+//! Rust error type for the `ktx_error_code_e` values returned by libktx.
+
+use std::{ffi::CStr, fmt};
+
+use crate::ffi::*;
+
+// Declares the known variants once and derives both directions of the mapping
+macro_rules! ktx_errors {
+    ($($variant:ident = $code:ident,)*) => {
+        /// A failed `ktx_error_code_e`, e.g. `FileDataError` for `KTX_FILE_DATA_ERROR`.
+        /// `KTX_SUCCESS` has no variant, see [`check`]; [`KtxError::code`] gives
+        /// back the C value.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum KtxError {
+            $($variant,)*
+            /// A code this crate doesn't know, e.g. from a newer libktx.
+            Unknown(ktx_error_code_e),
+        }
+
+        impl KtxError {
+            /// The raw `ktx_error_code_e` value.
+            pub fn code(self) -> ktx_error_code_e {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl TryFrom<ktx_error_code_e> for KtxError {
+            type Error = ktx_error_code_e;
+
+            /// Maps the codes of the supported libktx release, returning any other
+            /// value, including `KTX_SUCCESS`, as the error.
+            fn try_from(code: ktx_error_code_e) -> Result<Self, Self::Error> {
+                match code {
+                    $($code => Ok(Self::$variant),)*
+                    code => Err(code),
+                }
+            }
+        }
+    };
+}
+
+ktx_errors! {
+    FileDataError = ktx_error_code_e_KTX_FILE_DATA_ERROR,
+    FileIsPipe = ktx_error_code_e_KTX_FILE_ISPIPE,
+    FileOpenFailed = ktx_error_code_e_KTX_FILE_OPEN_FAILED,
+    FileOverflow = ktx_error_code_e_KTX_FILE_OVERFLOW,
+    FileReadError = ktx_error_code_e_KTX_FILE_READ_ERROR,
+    FileSeekError = ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+    FileUnexpectedEof = ktx_error_code_e_KTX_FILE_UNEXPECTED_EOF,
+    FileWriteError = ktx_error_code_e_KTX_FILE_WRITE_ERROR,
+    GlError = ktx_error_code_e_KTX_GL_ERROR,
+    InvalidOperation = ktx_error_code_e_KTX_INVALID_OPERATION,
+    InvalidValue = ktx_error_code_e_KTX_INVALID_VALUE,
+    NotFound = ktx_error_code_e_KTX_NOT_FOUND,
+    OutOfMemory = ktx_error_code_e_KTX_OUT_OF_MEMORY,
+    TranscodeFailed = ktx_error_code_e_KTX_TRANSCODE_FAILED,
+    UnknownFileFormat = ktx_error_code_e_KTX_UNKNOWN_FILE_FORMAT,
+    UnsupportedTextureType = ktx_error_code_e_KTX_UNSUPPORTED_TEXTURE_TYPE,
+    UnsupportedFeature = ktx_error_code_e_KTX_UNSUPPORTED_FEATURE,
+    LibraryNotLinked = ktx_error_code_e_KTX_LIBRARY_NOT_LINKED,
+}
+
+/// Converts a libktx return code, treating `KTX_SUCCESS` as `Ok`. Codes outside
+/// the supported libktx release become [`KtxError::Unknown`].
+pub fn check(code: ktx_error_code_e) -> Result<(), KtxError> {
+    match code {
+        ktx_error_code_e_KTX_SUCCESS => Ok(()),
+        code => Err(KtxError::try_from(code).unwrap_or(KtxError::Unknown(code))),
+    }
+}
+
+impl KtxError {
+    /// The message libktx associates with this error.
+    pub fn message(self) -> &'static str {
+        if let Self::Unknown(_) = self {
+            return "Unrecognized error code";
+        }
+        // ktxErrorString returns pointers into a static table of ASCII strings.
+        unsafe { CStr::from_ptr(ktxErrorString(self.code())) }
+            .to_str()
+            .unwrap_or("")
+    }
+}
+
+impl From<KtxError> for ktx_error_code_e {
+    fn from(error: KtxError) -> Self {
+        error.code()
+    }
+}
+
+impl fmt::Display for KtxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(code) => write!(f, "{} {}", self.message(), code),
+            _ => f.write_str(self.message()),
+        }
+    }
+}
+
+impl std::error::Error for KtxError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_maps_every_code() {
+        assert_eq!(check(ktx_error_code_e_KTX_SUCCESS), Ok(()));
+        assert_eq!(
+            check(ktx_error_code_e_KTX_FILE_DATA_ERROR),
+            Err(KtxError::FileDataError)
+        );
+        assert_eq!(check(0xdead), Err(KtxError::Unknown(0xdead)));
+        assert_eq!(KtxError::Unknown(0xdead).code(), 0xdead);
+        assert_eq!(
+            KtxError::Unknown(0xdead).to_string(),
+            "Unrecognized error code 57005"
+        );
+
+        for code in 1..=ktx_error_code_e_KTX_ERROR_MAX_ENUM {
+            let error = KtxError::try_from(code).unwrap();
+            assert_eq!(error.code(), code);
+            assert_eq!(check(code), Err(error));
+        }
+    }
+}
@@ -0,0 +1,701 @@
+//! Properties of the `VkFormat`s KTX2 can hold, without needing a texture.
+
+use crate::ffi::*;
+
+/// `VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR` from VK_KHR_maintenance5, which the
+/// bindings of older Vulkan headers lack.
+pub const VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR: VkFormat = 1000470000;
+/// `VK_FORMAT_A8_UNORM_KHR` from VK_KHR_maintenance5.
+pub const VK_FORMAT_A8_UNORM_KHR: VkFormat = 1000470001;
+
+/// Static properties of a `VkFormat`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FormatInfo {
+    pub vk_format: VkFormat,
+    /// The name without the `VkFormat_` prefix, e.g. `VK_FORMAT_R8G8B8A8_SRGB`.
+    pub name: &'static str,
+    /// Width, height and depth of a texel block, 1x1x1 for uncompressed formats.
+    pub block_extent: [u32; 3],
+    pub bytes_per_block: u32,
+    pub compression: Option<BlockCompression>,
+    pub is_srgb: bool,
+    /// The sRGB format for a UNORM one, or the UNORM format for an sRGB one.
+    pub srgb_pair: Option<VkFormat>,
+    pub has_depth: bool,
+    pub has_stencil: bool,
+    /// Number of channels, counting depth and stencil.
+    pub channels: u32,
+    /// Bits of the widest channel of an uncompressed format, 0 for block
+    /// compressed ones.
+    pub channel_bits: u32,
+    /// Whether channels are floating point, e.g. `R16_SFLOAT` or `BC6H_UFLOAT_BLOCK`.
+    pub is_float: bool,
+    /// The KTX2 specification disallows scaled formats and `A8B8G8R8_*_PACK32`,
+    /// which duplicate other formats.
+    pub prohibited: bool,
+}
+
+/// Families of block compressed formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlockCompression {
+    Bc,
+    /// ETC2 and EAC.
+    Etc2,
+    Astc,
+    /// PVRTC1 and PVRTC2.
+    Pvrtc,
+}
+
+impl FormatInfo {
+    /// The properties of `vk_format`, or `None` if KTX2 can't hold it, e.g. for
+    /// `VK_FORMAT_UNDEFINED` and multi-planar formats.
+    pub fn of(vk_format: VkFormat) -> Option<Self> {
+        FORMATS
+            .iter()
+            .position(|&(format, ..)| format == vk_format)
+            .map(Self::from_entry)
+    }
+
+    /// Every format KTX2 permits.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..FORMATS.len())
+            .map(Self::from_entry)
+            .filter(|info| !info.prohibited)
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.compression.is_some()
+    }
+
+    /// Size in bytes of an image of `width` x `height` x `depth` texels.
+    pub fn image_size(&self, width: u32, height: u32, depth: u32) -> u64 {
+        let [block_width, block_height, block_depth] = self.block_extent;
+        [
+            width.div_ceil(block_width),
+            height.div_ceil(block_height),
+            depth.div_ceil(block_depth),
+        ]
+        .iter()
+        .fold(u64::from(self.bytes_per_block), |size, &blocks| {
+            size * u64::from(blocks)
+        })
+    }
+
+    fn from_entry(index: usize) -> Self {
+        let (vk_format, name, layout) = FORMATS[index];
+        let suffix = layout.suffix();
+        let srgb_pair = match suffix {
+            Some(Unorm) => Some(Srgb),
+            Some(Srgb) => Some(Unorm),
+            _ => None,
+        }
+        .and_then(|pair| {
+            FORMATS
+                .iter()
+                .find(|&&(_, _, other)| other == layout.with_suffix(pair))
+        })
+        .map(|&(format, ..)| format);
+        let (block_extent, bytes_per_block, channels) = match layout {
+            Layout::Unpacked {
+                channels, bytes, ..
+            } => ([1; 3], u32::from(channels * bytes), channels.into()),
+            Layout::Packed { components, .. } => {
+                let bits: u32 = components.iter().map(|&(_, bits)| u32::from(bits)).sum();
+                ([1; 3], bits / 8, components.len() as u32)
+            }
+            Layout::Padded16 { channels, .. } => ([1; 3], 2 * u32::from(channels), channels.into()),
+            Layout::Ycbcr422 { bits, .. } => ([2, 1, 1], if bits == 8 { 4 } else { 8 }, 3),
+            Layout::SharedExponent => ([1; 3], 4, 3),
+            Layout::DepthStencil {
+                depth,
+                stencil,
+                bytes,
+            } => (
+                [1; 3],
+                bytes.into(),
+                u32::from(depth > 0) + u32::from(stencil > 0),
+            ),
+            Layout::Compressed {
+                scheme,
+                block: [width, height],
+                ..
+            } => (
+                [width.into(), height.into(), 1],
+                scheme.bytes_per_block().into(),
+                scheme.channels(),
+            ),
+        };
+        let channel_bits = match layout {
+            Layout::Unpacked { bytes, .. } => 8 * u32::from(bytes),
+            Layout::Packed { components, .. } => components
+                .iter()
+                .map(|&(_, bits)| u32::from(bits))
+                .max()
+                .unwrap_or(0),
+            Layout::Padded16 { bits, .. } | Layout::Ycbcr422 { bits, .. } => bits.into(),
+            // 9 bit mantissas sharing a 5 bit exponent
+            Layout::SharedExponent => 9,
+            Layout::DepthStencil { depth, stencil, .. } => depth.max(stencil).into(),
+            Layout::Compressed { .. } => 0,
+        };
+        Self {
+            vk_format,
+            name: name.trim_start_matches("VkFormat_"),
+            block_extent,
+            bytes_per_block,
+            compression: match layout {
+                Layout::Compressed { scheme, .. } => Some(scheme.family()),
+                _ => None,
+            },
+            is_srgb: suffix == Some(Srgb),
+            srgb_pair,
+            has_depth: matches!(layout, Layout::DepthStencil { depth, .. } if depth > 0),
+            has_stencil: matches!(layout, Layout::DepthStencil { stencil, .. } if stencil > 0),
+            channels,
+            channel_bits,
+            is_float: matches!(suffix, Some(Sfloat | Ufloat))
+                || matches!(
+                    layout,
+                    Layout::SharedExponent | Layout::DepthStencil { depth: 32, .. }
+                ),
+            prohibited: matches!(suffix, Some(Uscaled | Sscaled))
+                || (VkFormat_VK_FORMAT_A8B8G8R8_UNORM_PACK32
+                    ..=VkFormat_VK_FORMAT_A8B8G8R8_SRGB_PACK32)
+                    .contains(&vk_format),
+        }
+    }
+}
+
+/// How the bits of a channel are interpreted, after the suffix of `VkFormat` names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Suffix {
+    Unorm,
+    Snorm,
+    Uscaled,
+    Sscaled,
+    Uint,
+    Sint,
+    Sfloat,
+    Ufloat,
+    Srgb,
+}
+
+/// Block compression schemes, each with a fixed set of samples.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Scheme {
+    Bc1Rgb,
+    Bc1Rgba,
+    Bc2,
+    Bc3,
+    Bc4,
+    Bc5,
+    Bc6h,
+    Bc7,
+    Etc2Rgb,
+    Etc2Rgba1,
+    Etc2Rgba8,
+    EacR11,
+    EacRg11,
+    Astc,
+    Pvrtc1,
+    Pvrtc2,
+}
+
+// Memory layouts, from which both the properties above and DFDs are derived.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Layout {
+    /// Channels of `bytes` each, in R, G, B, A order or B, G, R, A with `bgr`.
+    Unpacked {
+        channels: u8,
+        bytes: u8,
+        bgr: bool,
+        suffix: Suffix,
+    },
+    /// `(channel, bits)` packed into one little-endian word, listed from the
+    /// most significant bits as in the format name.
+    Packed {
+        components: &'static [(u8, u8)],
+        suffix: Suffix,
+    },
+    /// UNORM channels of `bits` in the top of consecutive 16-bit words.
+    Padded16 {
+        channels: u8,
+        bits: u8,
+    },
+    /// 2x1 blocks of Y'CbCr samples in memory order, 8 bits or the top of 16-bit words.
+    Ycbcr422 {
+        channels: [u8; 4],
+        bits: u8,
+    },
+    /// `E5B9G9R9_UFLOAT_PACK32`.
+    SharedExponent,
+    DepthStencil {
+        depth: u8,
+        stencil: u8,
+        bytes: u8,
+    },
+    Compressed {
+        scheme: Scheme,
+        block: [u8; 2],
+        suffix: Suffix,
+    },
+}
+
+impl Layout {
+    pub(crate) fn suffix(self) -> Option<Suffix> {
+        match self {
+            Layout::Unpacked { suffix, .. }
+            | Layout::Packed { suffix, .. }
+            | Layout::Compressed { suffix, .. } => Some(suffix),
+            _ => None,
+        }
+    }
+
+    /// The `typeSize` a KTX2 header gives formats with this layout.
+    pub(crate) fn type_size(self) -> u32 {
+        match self {
+            Layout::Unpacked { bytes, .. } => bytes.into(),
+            Layout::Packed { components, .. } => {
+                components
+                    .iter()
+                    .map(|&(_, bits)| u32::from(bits))
+                    .sum::<u32>()
+                    / 8
+            }
+            Layout::Padded16 { .. } => 2,
+            Layout::Ycbcr422 { bits, .. } => {
+                if bits == 8 {
+                    1
+                } else {
+                    2
+                }
+            }
+            Layout::SharedExponent => 4,
+            Layout::DepthStencil { depth, .. } => match depth {
+                0 => 1,
+                16 => 2,
+                _ => 4,
+            },
+            Layout::Compressed { .. } => 1,
+        }
+    }
+
+    fn with_suffix(mut self, new: Suffix) -> Self {
+        if let Layout::Unpacked { suffix, .. }
+        | Layout::Packed { suffix, .. }
+        | Layout::Compressed { suffix, .. } = &mut self
+        {
+            *suffix = new;
+        }
+        self
+    }
+}
+
+impl Scheme {
+    pub(crate) fn bytes_per_block(self) -> u8 {
+        match self {
+            Scheme::Bc1Rgb
+            | Scheme::Bc1Rgba
+            | Scheme::Bc4
+            | Scheme::Etc2Rgb
+            | Scheme::Etc2Rgba1
+            | Scheme::EacR11
+            | Scheme::Pvrtc1
+            | Scheme::Pvrtc2 => 8,
+            Scheme::Bc2
+            | Scheme::Bc3
+            | Scheme::Bc5
+            | Scheme::Bc6h
+            | Scheme::Bc7
+            | Scheme::Etc2Rgba8
+            | Scheme::EacRg11
+            | Scheme::Astc => 16,
+        }
+    }
+
+    fn channels(self) -> u32 {
+        match self {
+            Scheme::Bc4 | Scheme::EacR11 => 1,
+            Scheme::Bc5 | Scheme::EacRg11 => 2,
+            Scheme::Bc1Rgb | Scheme::Bc6h | Scheme::Etc2Rgb => 3,
+            _ => 4,
+        }
+    }
+
+    fn family(self) -> BlockCompression {
+        match self {
+            Scheme::Bc1Rgb
+            | Scheme::Bc1Rgba
+            | Scheme::Bc2
+            | Scheme::Bc3
+            | Scheme::Bc4
+            | Scheme::Bc5
+            | Scheme::Bc6h
+            | Scheme::Bc7 => BlockCompression::Bc,
+            Scheme::Etc2Rgb
+            | Scheme::Etc2Rgba1
+            | Scheme::Etc2Rgba8
+            | Scheme::EacR11
+            | Scheme::EacRg11 => BlockCompression::Etc2,
+            Scheme::Astc => BlockCompression::Astc,
+            Scheme::Pvrtc1 | Scheme::Pvrtc2 => BlockCompression::Pvrtc,
+        }
+    }
+}
+
+use Suffix::*;
+
+const R: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_RED as u8;
+const G: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_GREEN as u8;
+const B: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_BLUE as u8;
+const A: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_RGBSDA_ALPHA as u8;
+const Y: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_Y as u8;
+const U: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_U as u8;
+const V: u8 = _khr_df_model_channels_e_KHR_DF_CHANNEL_YUVSDA_V as u8;
+
+const fn unpacked(channels: u8, bytes: u8, suffix: Suffix) -> Layout {
+    Layout::Unpacked {
+        channels,
+        bytes,
+        bgr: false,
+        suffix,
+    }
+}
+
+const fn unpacked_bgr(channels: u8, bytes: u8, suffix: Suffix) -> Layout {
+    Layout::Unpacked {
+        channels,
+        bytes,
+        bgr: true,
+        suffix,
+    }
+}
+
+const fn packed(components: &'static [(u8, u8)], suffix: Suffix) -> Layout {
+    Layout::Packed { components, suffix }
+}
+
+const fn compressed(scheme: Scheme, width: u8, height: u8, suffix: Suffix) -> Layout {
+    Layout::Compressed {
+        scheme,
+        block: [width, height],
+        suffix,
+    }
+}
+
+macro_rules! formats {
+    ($($vk_format:ident => $layout:expr,)*) => {
+        pub(crate) static FORMATS: &[(VkFormat, &str, Layout)] =
+            &[$(($vk_format, stringify!($vk_format), $layout),)*];
+    };
+}
+
+// Every single-plane format, without the aliases of promoted extension formats.
+formats! {
+    VkFormat_VK_FORMAT_R4G4_UNORM_PACK8 => packed(&[(R, 4), (G, 4)], Unorm),
+    VkFormat_VK_FORMAT_R4G4B4A4_UNORM_PACK16 => packed(&[(R, 4), (G, 4), (B, 4), (A, 4)], Unorm),
+    VkFormat_VK_FORMAT_B4G4R4A4_UNORM_PACK16 => packed(&[(B, 4), (G, 4), (R, 4), (A, 4)], Unorm),
+    VkFormat_VK_FORMAT_R5G6B5_UNORM_PACK16 => packed(&[(R, 5), (G, 6), (B, 5)], Unorm),
+    VkFormat_VK_FORMAT_B5G6R5_UNORM_PACK16 => packed(&[(B, 5), (G, 6), (R, 5)], Unorm),
+    VkFormat_VK_FORMAT_R5G5B5A1_UNORM_PACK16 => packed(&[(R, 5), (G, 5), (B, 5), (A, 1)], Unorm),
+    VkFormat_VK_FORMAT_B5G5R5A1_UNORM_PACK16 => packed(&[(B, 5), (G, 5), (R, 5), (A, 1)], Unorm),
+    VkFormat_VK_FORMAT_A1R5G5B5_UNORM_PACK16 => packed(&[(A, 1), (R, 5), (G, 5), (B, 5)], Unorm),
+    VkFormat_VK_FORMAT_R8_UNORM => unpacked(1, 1, Unorm),
+    VkFormat_VK_FORMAT_R8_SNORM => unpacked(1, 1, Snorm),
+    VkFormat_VK_FORMAT_R8_USCALED => unpacked(1, 1, Uscaled),
+    VkFormat_VK_FORMAT_R8_SSCALED => unpacked(1, 1, Sscaled),
+    VkFormat_VK_FORMAT_R8_UINT => unpacked(1, 1, Uint),
+    VkFormat_VK_FORMAT_R8_SINT => unpacked(1, 1, Sint),
+    VkFormat_VK_FORMAT_R8_SRGB => unpacked(1, 1, Srgb),
+    VkFormat_VK_FORMAT_R8G8_UNORM => unpacked(2, 1, Unorm),
+    VkFormat_VK_FORMAT_R8G8_SNORM => unpacked(2, 1, Snorm),
+    VkFormat_VK_FORMAT_R8G8_USCALED => unpacked(2, 1, Uscaled),
+    VkFormat_VK_FORMAT_R8G8_SSCALED => unpacked(2, 1, Sscaled),
+    VkFormat_VK_FORMAT_R8G8_UINT => unpacked(2, 1, Uint),
+    VkFormat_VK_FORMAT_R8G8_SINT => unpacked(2, 1, Sint),
+    VkFormat_VK_FORMAT_R8G8_SRGB => unpacked(2, 1, Srgb),
+    VkFormat_VK_FORMAT_R8G8B8_UNORM => unpacked(3, 1, Unorm),
+    VkFormat_VK_FORMAT_R8G8B8_SNORM => unpacked(3, 1, Snorm),
+    VkFormat_VK_FORMAT_R8G8B8_USCALED => unpacked(3, 1, Uscaled),
+    VkFormat_VK_FORMAT_R8G8B8_SSCALED => unpacked(3, 1, Sscaled),
+    VkFormat_VK_FORMAT_R8G8B8_UINT => unpacked(3, 1, Uint),
+    VkFormat_VK_FORMAT_R8G8B8_SINT => unpacked(3, 1, Sint),
+    VkFormat_VK_FORMAT_R8G8B8_SRGB => unpacked(3, 1, Srgb),
+    VkFormat_VK_FORMAT_B8G8R8_UNORM => unpacked_bgr(3, 1, Unorm),
+    VkFormat_VK_FORMAT_B8G8R8_SNORM => unpacked_bgr(3, 1, Snorm),
+    VkFormat_VK_FORMAT_B8G8R8_USCALED => unpacked_bgr(3, 1, Uscaled),
+    VkFormat_VK_FORMAT_B8G8R8_SSCALED => unpacked_bgr(3, 1, Sscaled),
+    VkFormat_VK_FORMAT_B8G8R8_UINT => unpacked_bgr(3, 1, Uint),
+    VkFormat_VK_FORMAT_B8G8R8_SINT => unpacked_bgr(3, 1, Sint),
+    VkFormat_VK_FORMAT_B8G8R8_SRGB => unpacked_bgr(3, 1, Srgb),
+    VkFormat_VK_FORMAT_R8G8B8A8_UNORM => unpacked(4, 1, Unorm),
+    VkFormat_VK_FORMAT_R8G8B8A8_SNORM => unpacked(4, 1, Snorm),
+    VkFormat_VK_FORMAT_R8G8B8A8_USCALED => unpacked(4, 1, Uscaled),
+    VkFormat_VK_FORMAT_R8G8B8A8_SSCALED => unpacked(4, 1, Sscaled),
+    VkFormat_VK_FORMAT_R8G8B8A8_UINT => unpacked(4, 1, Uint),
+    VkFormat_VK_FORMAT_R8G8B8A8_SINT => unpacked(4, 1, Sint),
+    VkFormat_VK_FORMAT_R8G8B8A8_SRGB => unpacked(4, 1, Srgb),
+    VkFormat_VK_FORMAT_B8G8R8A8_UNORM => unpacked_bgr(4, 1, Unorm),
+    VkFormat_VK_FORMAT_B8G8R8A8_SNORM => unpacked_bgr(4, 1, Snorm),
+    VkFormat_VK_FORMAT_B8G8R8A8_USCALED => unpacked_bgr(4, 1, Uscaled),
+    VkFormat_VK_FORMAT_B8G8R8A8_SSCALED => unpacked_bgr(4, 1, Sscaled),
+    VkFormat_VK_FORMAT_B8G8R8A8_UINT => unpacked_bgr(4, 1, Uint),
+    VkFormat_VK_FORMAT_B8G8R8A8_SINT => unpacked_bgr(4, 1, Sint),
+    VkFormat_VK_FORMAT_B8G8R8A8_SRGB => unpacked_bgr(4, 1, Srgb),
+    VkFormat_VK_FORMAT_A8B8G8R8_UNORM_PACK32 => packed(&[(A, 8), (B, 8), (G, 8), (R, 8)], Unorm),
+    VkFormat_VK_FORMAT_A8B8G8R8_SNORM_PACK32 => packed(&[(A, 8), (B, 8), (G, 8), (R, 8)], Snorm),
+    VkFormat_VK_FORMAT_A8B8G8R8_USCALED_PACK32 => packed(&[(A, 8), (B, 8), (G, 8), (R, 8)], Uscaled),
+    VkFormat_VK_FORMAT_A8B8G8R8_SSCALED_PACK32 => packed(&[(A, 8), (B, 8), (G, 8), (R, 8)], Sscaled),
+    VkFormat_VK_FORMAT_A8B8G8R8_UINT_PACK32 => packed(&[(A, 8), (B, 8), (G, 8), (R, 8)], Uint),
+    VkFormat_VK_FORMAT_A8B8G8R8_SINT_PACK32 => packed(&[(A, 8), (B, 8), (G, 8), (R, 8)], Sint),
+    VkFormat_VK_FORMAT_A8B8G8R8_SRGB_PACK32 => packed(&[(A, 8), (B, 8), (G, 8), (R, 8)], Srgb),
+    VkFormat_VK_FORMAT_A2R10G10B10_UNORM_PACK32 => packed(&[(A, 2), (R, 10), (G, 10), (B, 10)], Unorm),
+    VkFormat_VK_FORMAT_A2R10G10B10_SNORM_PACK32 => packed(&[(A, 2), (R, 10), (G, 10), (B, 10)], Snorm),
+    VkFormat_VK_FORMAT_A2R10G10B10_USCALED_PACK32 => packed(&[(A, 2), (R, 10), (G, 10), (B, 10)], Uscaled),
+    VkFormat_VK_FORMAT_A2R10G10B10_SSCALED_PACK32 => packed(&[(A, 2), (R, 10), (G, 10), (B, 10)], Sscaled),
+    VkFormat_VK_FORMAT_A2R10G10B10_UINT_PACK32 => packed(&[(A, 2), (R, 10), (G, 10), (B, 10)], Uint),
+    VkFormat_VK_FORMAT_A2R10G10B10_SINT_PACK32 => packed(&[(A, 2), (R, 10), (G, 10), (B, 10)], Sint),
+    VkFormat_VK_FORMAT_A2B10G10R10_UNORM_PACK32 => packed(&[(A, 2), (B, 10), (G, 10), (R, 10)], Unorm),
+    VkFormat_VK_FORMAT_A2B10G10R10_SNORM_PACK32 => packed(&[(A, 2), (B, 10), (G, 10), (R, 10)], Snorm),
+    VkFormat_VK_FORMAT_A2B10G10R10_USCALED_PACK32 => packed(&[(A, 2), (B, 10), (G, 10), (R, 10)], Uscaled),
+    VkFormat_VK_FORMAT_A2B10G10R10_SSCALED_PACK32 => packed(&[(A, 2), (B, 10), (G, 10), (R, 10)], Sscaled),
+    VkFormat_VK_FORMAT_A2B10G10R10_UINT_PACK32 => packed(&[(A, 2), (B, 10), (G, 10), (R, 10)], Uint),
+    VkFormat_VK_FORMAT_A2B10G10R10_SINT_PACK32 => packed(&[(A, 2), (B, 10), (G, 10), (R, 10)], Sint),
+    VkFormat_VK_FORMAT_R16_UNORM => unpacked(1, 2, Unorm),
+    VkFormat_VK_FORMAT_R16_SNORM => unpacked(1, 2, Snorm),
+    VkFormat_VK_FORMAT_R16_USCALED => unpacked(1, 2, Uscaled),
+    VkFormat_VK_FORMAT_R16_SSCALED => unpacked(1, 2, Sscaled),
+    VkFormat_VK_FORMAT_R16_UINT => unpacked(1, 2, Uint),
+    VkFormat_VK_FORMAT_R16_SINT => unpacked(1, 2, Sint),
+    VkFormat_VK_FORMAT_R16_SFLOAT => unpacked(1, 2, Sfloat),
+    VkFormat_VK_FORMAT_R16G16_UNORM => unpacked(2, 2, Unorm),
+    VkFormat_VK_FORMAT_R16G16_SNORM => unpacked(2, 2, Snorm),
+    VkFormat_VK_FORMAT_R16G16_USCALED => unpacked(2, 2, Uscaled),
+    VkFormat_VK_FORMAT_R16G16_SSCALED => unpacked(2, 2, Sscaled),
+    VkFormat_VK_FORMAT_R16G16_UINT => unpacked(2, 2, Uint),
+    VkFormat_VK_FORMAT_R16G16_SINT => unpacked(2, 2, Sint),
+    VkFormat_VK_FORMAT_R16G16_SFLOAT => unpacked(2, 2, Sfloat),
+    VkFormat_VK_FORMAT_R16G16B16_UNORM => unpacked(3, 2, Unorm),
+    VkFormat_VK_FORMAT_R16G16B16_SNORM => unpacked(3, 2, Snorm),
+    VkFormat_VK_FORMAT_R16G16B16_USCALED => unpacked(3, 2, Uscaled),
+    VkFormat_VK_FORMAT_R16G16B16_SSCALED => unpacked(3, 2, Sscaled),
+    VkFormat_VK_FORMAT_R16G16B16_UINT => unpacked(3, 2, Uint),
+    VkFormat_VK_FORMAT_R16G16B16_SINT => unpacked(3, 2, Sint),
+    VkFormat_VK_FORMAT_R16G16B16_SFLOAT => unpacked(3, 2, Sfloat),
+    VkFormat_VK_FORMAT_R16G16B16A16_UNORM => unpacked(4, 2, Unorm),
+    VkFormat_VK_FORMAT_R16G16B16A16_SNORM => unpacked(4, 2, Snorm),
+    VkFormat_VK_FORMAT_R16G16B16A16_USCALED => unpacked(4, 2, Uscaled),
+    VkFormat_VK_FORMAT_R16G16B16A16_SSCALED => unpacked(4, 2, Sscaled),
+    VkFormat_VK_FORMAT_R16G16B16A16_UINT => unpacked(4, 2, Uint),
+    VkFormat_VK_FORMAT_R16G16B16A16_SINT => unpacked(4, 2, Sint),
+    VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT => unpacked(4, 2, Sfloat),
+    VkFormat_VK_FORMAT_R32_UINT => unpacked(1, 4, Uint),
+    VkFormat_VK_FORMAT_R32_SINT => unpacked(1, 4, Sint),
+    VkFormat_VK_FORMAT_R32_SFLOAT => unpacked(1, 4, Sfloat),
+    VkFormat_VK_FORMAT_R32G32_UINT => unpacked(2, 4, Uint),
+    VkFormat_VK_FORMAT_R32G32_SINT => unpacked(2, 4, Sint),
+    VkFormat_VK_FORMAT_R32G32_SFLOAT => unpacked(2, 4, Sfloat),
+    VkFormat_VK_FORMAT_R32G32B32_UINT => unpacked(3, 4, Uint),
+    VkFormat_VK_FORMAT_R32G32B32_SINT => unpacked(3, 4, Sint),
+    VkFormat_VK_FORMAT_R32G32B32_SFLOAT => unpacked(3, 4, Sfloat),
+    VkFormat_VK_FORMAT_R32G32B32A32_UINT => unpacked(4, 4, Uint),
+    VkFormat_VK_FORMAT_R32G32B32A32_SINT => unpacked(4, 4, Sint),
+    VkFormat_VK_FORMAT_R32G32B32A32_SFLOAT => unpacked(4, 4, Sfloat),
+    VkFormat_VK_FORMAT_R64_UINT => unpacked(1, 8, Uint),
+    VkFormat_VK_FORMAT_R64_SINT => unpacked(1, 8, Sint),
+    VkFormat_VK_FORMAT_R64_SFLOAT => unpacked(1, 8, Sfloat),
+    VkFormat_VK_FORMAT_R64G64_UINT => unpacked(2, 8, Uint),
+    VkFormat_VK_FORMAT_R64G64_SINT => unpacked(2, 8, Sint),
+    VkFormat_VK_FORMAT_R64G64_SFLOAT => unpacked(2, 8, Sfloat),
+    VkFormat_VK_FORMAT_R64G64B64_UINT => unpacked(3, 8, Uint),
+    VkFormat_VK_FORMAT_R64G64B64_SINT => unpacked(3, 8, Sint),
+    VkFormat_VK_FORMAT_R64G64B64_SFLOAT => unpacked(3, 8, Sfloat),
+    VkFormat_VK_FORMAT_R64G64B64A64_UINT => unpacked(4, 8, Uint),
+    VkFormat_VK_FORMAT_R64G64B64A64_SINT => unpacked(4, 8, Sint),
+    VkFormat_VK_FORMAT_R64G64B64A64_SFLOAT => unpacked(4, 8, Sfloat),
+    VkFormat_VK_FORMAT_B10G11R11_UFLOAT_PACK32 => packed(&[(B, 10), (G, 11), (R, 11)], Ufloat),
+    VkFormat_VK_FORMAT_E5B9G9R9_UFLOAT_PACK32 => Layout::SharedExponent,
+    VkFormat_VK_FORMAT_D16_UNORM => Layout::DepthStencil { depth: 16, stencil: 0, bytes: 2 },
+    VkFormat_VK_FORMAT_X8_D24_UNORM_PACK32 => Layout::DepthStencil { depth: 24, stencil: 0, bytes: 4 },
+    VkFormat_VK_FORMAT_D32_SFLOAT => Layout::DepthStencil { depth: 32, stencil: 0, bytes: 4 },
+    VkFormat_VK_FORMAT_S8_UINT => Layout::DepthStencil { depth: 0, stencil: 8, bytes: 1 },
+    VkFormat_VK_FORMAT_D16_UNORM_S8_UINT => Layout::DepthStencil { depth: 16, stencil: 8, bytes: 3 },
+    VkFormat_VK_FORMAT_D24_UNORM_S8_UINT => Layout::DepthStencil { depth: 24, stencil: 8, bytes: 4 },
+    VkFormat_VK_FORMAT_D32_SFLOAT_S8_UINT => Layout::DepthStencil { depth: 32, stencil: 8, bytes: 8 },
+    VkFormat_VK_FORMAT_BC1_RGB_UNORM_BLOCK => compressed(Scheme::Bc1Rgb, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_BC1_RGB_SRGB_BLOCK => compressed(Scheme::Bc1Rgb, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_BC1_RGBA_UNORM_BLOCK => compressed(Scheme::Bc1Rgba, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_BC1_RGBA_SRGB_BLOCK => compressed(Scheme::Bc1Rgba, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_BC2_UNORM_BLOCK => compressed(Scheme::Bc2, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_BC2_SRGB_BLOCK => compressed(Scheme::Bc2, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_BC3_UNORM_BLOCK => compressed(Scheme::Bc3, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_BC3_SRGB_BLOCK => compressed(Scheme::Bc3, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_BC4_UNORM_BLOCK => compressed(Scheme::Bc4, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_BC4_SNORM_BLOCK => compressed(Scheme::Bc4, 4, 4, Snorm),
+    VkFormat_VK_FORMAT_BC5_UNORM_BLOCK => compressed(Scheme::Bc5, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_BC5_SNORM_BLOCK => compressed(Scheme::Bc5, 4, 4, Snorm),
+    VkFormat_VK_FORMAT_BC6H_UFLOAT_BLOCK => compressed(Scheme::Bc6h, 4, 4, Ufloat),
+    VkFormat_VK_FORMAT_BC6H_SFLOAT_BLOCK => compressed(Scheme::Bc6h, 4, 4, Sfloat),
+    VkFormat_VK_FORMAT_BC7_UNORM_BLOCK => compressed(Scheme::Bc7, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_BC7_SRGB_BLOCK => compressed(Scheme::Bc7, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK => compressed(Scheme::Etc2Rgb, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_ETC2_R8G8B8_SRGB_BLOCK => compressed(Scheme::Etc2Rgb, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK => compressed(Scheme::Etc2Rgba1, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK => compressed(Scheme::Etc2Rgba1, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK => compressed(Scheme::Etc2Rgba8, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK => compressed(Scheme::Etc2Rgba8, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_EAC_R11_UNORM_BLOCK => compressed(Scheme::EacR11, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_EAC_R11_SNORM_BLOCK => compressed(Scheme::EacR11, 4, 4, Snorm),
+    VkFormat_VK_FORMAT_EAC_R11G11_UNORM_BLOCK => compressed(Scheme::EacRg11, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_EAC_R11G11_SNORM_BLOCK => compressed(Scheme::EacRg11, 4, 4, Snorm),
+    VkFormat_VK_FORMAT_ASTC_4x4_UNORM_BLOCK => compressed(Scheme::Astc, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_ASTC_4x4_SRGB_BLOCK => compressed(Scheme::Astc, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_ASTC_5x4_UNORM_BLOCK => compressed(Scheme::Astc, 5, 4, Unorm),
+    VkFormat_VK_FORMAT_ASTC_5x4_SRGB_BLOCK => compressed(Scheme::Astc, 5, 4, Srgb),
+    VkFormat_VK_FORMAT_ASTC_5x5_UNORM_BLOCK => compressed(Scheme::Astc, 5, 5, Unorm),
+    VkFormat_VK_FORMAT_ASTC_5x5_SRGB_BLOCK => compressed(Scheme::Astc, 5, 5, Srgb),
+    VkFormat_VK_FORMAT_ASTC_6x5_UNORM_BLOCK => compressed(Scheme::Astc, 6, 5, Unorm),
+    VkFormat_VK_FORMAT_ASTC_6x5_SRGB_BLOCK => compressed(Scheme::Astc, 6, 5, Srgb),
+    VkFormat_VK_FORMAT_ASTC_6x6_UNORM_BLOCK => compressed(Scheme::Astc, 6, 6, Unorm),
+    VkFormat_VK_FORMAT_ASTC_6x6_SRGB_BLOCK => compressed(Scheme::Astc, 6, 6, Srgb),
+    VkFormat_VK_FORMAT_ASTC_8x5_UNORM_BLOCK => compressed(Scheme::Astc, 8, 5, Unorm),
+    VkFormat_VK_FORMAT_ASTC_8x5_SRGB_BLOCK => compressed(Scheme::Astc, 8, 5, Srgb),
+    VkFormat_VK_FORMAT_ASTC_8x6_UNORM_BLOCK => compressed(Scheme::Astc, 8, 6, Unorm),
+    VkFormat_VK_FORMAT_ASTC_8x6_SRGB_BLOCK => compressed(Scheme::Astc, 8, 6, Srgb),
+    VkFormat_VK_FORMAT_ASTC_8x8_UNORM_BLOCK => compressed(Scheme::Astc, 8, 8, Unorm),
+    VkFormat_VK_FORMAT_ASTC_8x8_SRGB_BLOCK => compressed(Scheme::Astc, 8, 8, Srgb),
+    VkFormat_VK_FORMAT_ASTC_10x5_UNORM_BLOCK => compressed(Scheme::Astc, 10, 5, Unorm),
+    VkFormat_VK_FORMAT_ASTC_10x5_SRGB_BLOCK => compressed(Scheme::Astc, 10, 5, Srgb),
+    VkFormat_VK_FORMAT_ASTC_10x6_UNORM_BLOCK => compressed(Scheme::Astc, 10, 6, Unorm),
+    VkFormat_VK_FORMAT_ASTC_10x6_SRGB_BLOCK => compressed(Scheme::Astc, 10, 6, Srgb),
+    VkFormat_VK_FORMAT_ASTC_10x8_UNORM_BLOCK => compressed(Scheme::Astc, 10, 8, Unorm),
+    VkFormat_VK_FORMAT_ASTC_10x8_SRGB_BLOCK => compressed(Scheme::Astc, 10, 8, Srgb),
+    VkFormat_VK_FORMAT_ASTC_10x10_UNORM_BLOCK => compressed(Scheme::Astc, 10, 10, Unorm),
+    VkFormat_VK_FORMAT_ASTC_10x10_SRGB_BLOCK => compressed(Scheme::Astc, 10, 10, Srgb),
+    VkFormat_VK_FORMAT_ASTC_12x10_UNORM_BLOCK => compressed(Scheme::Astc, 12, 10, Unorm),
+    VkFormat_VK_FORMAT_ASTC_12x10_SRGB_BLOCK => compressed(Scheme::Astc, 12, 10, Srgb),
+    VkFormat_VK_FORMAT_ASTC_12x12_UNORM_BLOCK => compressed(Scheme::Astc, 12, 12, Unorm),
+    VkFormat_VK_FORMAT_ASTC_12x12_SRGB_BLOCK => compressed(Scheme::Astc, 12, 12, Srgb),
+    VkFormat_VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK => compressed(Scheme::Astc, 4, 4, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK => compressed(Scheme::Astc, 5, 4, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK => compressed(Scheme::Astc, 5, 5, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK => compressed(Scheme::Astc, 6, 5, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK => compressed(Scheme::Astc, 6, 6, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK => compressed(Scheme::Astc, 8, 5, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK => compressed(Scheme::Astc, 8, 6, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK => compressed(Scheme::Astc, 8, 8, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK => compressed(Scheme::Astc, 10, 5, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK => compressed(Scheme::Astc, 10, 6, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK => compressed(Scheme::Astc, 10, 8, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK => compressed(Scheme::Astc, 10, 10, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK => compressed(Scheme::Astc, 12, 10, Sfloat),
+    VkFormat_VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK => compressed(Scheme::Astc, 12, 12, Sfloat),
+    VkFormat_VK_FORMAT_PVRTC1_2BPP_UNORM_BLOCK_IMG => compressed(Scheme::Pvrtc1, 8, 4, Unorm),
+    VkFormat_VK_FORMAT_PVRTC1_4BPP_UNORM_BLOCK_IMG => compressed(Scheme::Pvrtc1, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_PVRTC1_2BPP_SRGB_BLOCK_IMG => compressed(Scheme::Pvrtc1, 8, 4, Srgb),
+    VkFormat_VK_FORMAT_PVRTC1_4BPP_SRGB_BLOCK_IMG => compressed(Scheme::Pvrtc1, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_PVRTC2_2BPP_UNORM_BLOCK_IMG => compressed(Scheme::Pvrtc2, 8, 4, Unorm),
+    VkFormat_VK_FORMAT_PVRTC2_4BPP_UNORM_BLOCK_IMG => compressed(Scheme::Pvrtc2, 4, 4, Unorm),
+    VkFormat_VK_FORMAT_PVRTC2_2BPP_SRGB_BLOCK_IMG => compressed(Scheme::Pvrtc2, 8, 4, Srgb),
+    VkFormat_VK_FORMAT_PVRTC2_4BPP_SRGB_BLOCK_IMG => compressed(Scheme::Pvrtc2, 4, 4, Srgb),
+    VkFormat_VK_FORMAT_G8B8G8R8_422_UNORM => Layout::Ycbcr422 { channels: [Y, U, Y, V], bits: 8 },
+    VkFormat_VK_FORMAT_B8G8R8G8_422_UNORM => Layout::Ycbcr422 { channels: [U, Y, V, Y], bits: 8 },
+    VkFormat_VK_FORMAT_R10X6_UNORM_PACK16 => Layout::Padded16 { channels: 1, bits: 10 },
+    VkFormat_VK_FORMAT_R10X6G10X6_UNORM_2PACK16 => Layout::Padded16 { channels: 2, bits: 10 },
+    VkFormat_VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16 => Layout::Padded16 { channels: 4, bits: 10 },
+    VkFormat_VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 => Layout::Ycbcr422 { channels: [Y, U, Y, V], bits: 10 },
+    VkFormat_VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 => Layout::Ycbcr422 { channels: [U, Y, V, Y], bits: 10 },
+    VkFormat_VK_FORMAT_R12X4_UNORM_PACK16 => Layout::Padded16 { channels: 1, bits: 12 },
+    VkFormat_VK_FORMAT_R12X4G12X4_UNORM_2PACK16 => Layout::Padded16 { channels: 2, bits: 12 },
+    VkFormat_VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16 => Layout::Padded16 { channels: 4, bits: 12 },
+    VkFormat_VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 => Layout::Ycbcr422 { channels: [Y, U, Y, V], bits: 12 },
+    VkFormat_VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 => Layout::Ycbcr422 { channels: [U, Y, V, Y], bits: 12 },
+    VkFormat_VK_FORMAT_G16B16G16R16_422_UNORM => Layout::Ycbcr422 { channels: [Y, U, Y, V], bits: 16 },
+    VkFormat_VK_FORMAT_B16G16R16G16_422_UNORM => Layout::Ycbcr422 { channels: [U, Y, V, Y], bits: 16 },
+    VkFormat_VK_FORMAT_A4R4G4B4_UNORM_PACK16 => packed(&[(A, 4), (R, 4), (G, 4), (B, 4)], Unorm),
+    VkFormat_VK_FORMAT_A4B4G4R4_UNORM_PACK16 => packed(&[(A, 4), (B, 4), (G, 4), (R, 4)], Unorm),
+    VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR => packed(&[(A, 1), (B, 5), (G, 5), (R, 5)], Unorm),
+    VK_FORMAT_A8_UNORM_KHR => packed(&[(A, 8)], Unorm),
+}
+
+pub(crate) fn layout(vk_format: VkFormat) -> Option<Layout> {
+    FORMATS
+        .iter()
+        .find(|&&(format, ..)| format == vk_format)
+        .map(|&(.., layout)| layout)
+}
+
+/// Whether the libktx release the bindings target knows `vk_format`. It predates
+/// VK_KHR_maintenance5, which added `A1B5G5R5_UNORM_PACK16` and `A8_UNORM`.
+#[cfg(test)]
+pub(crate) fn known_to_libktx(vk_format: VkFormat) -> bool {
+    !matches!(
+        vk_format,
+        VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR | VK_FORMAT_A8_UNORM_KHR
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseTexture, TextureBuilder};
+
+    #[test]
+    fn srgb_pairs_are_symmetric() {
+        for info in FormatInfo::all() {
+            if let Some(pair) = info.srgb_pair {
+                let pair = FormatInfo::of(pair).unwrap();
+                assert_eq!(pair.srgb_pair, Some(info.vk_format), "{}", info.name);
+                assert_ne!(pair.is_srgb, info.is_srgb, "{}", info.name);
+            }
+        }
+    }
+
+    #[test]
+    fn channel_properties() {
+        for (vk_format, channel_bits, is_float) in [
+            (VkFormat_VK_FORMAT_R8G8B8A8_SRGB, 8, false),
+            (VkFormat_VK_FORMAT_R5G6B5_UNORM_PACK16, 6, false),
+            (VkFormat_VK_FORMAT_A2B10G10R10_UNORM_PACK32, 10, false),
+            (VkFormat_VK_FORMAT_R16_UNORM, 16, false),
+            (VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT, 16, true),
+            (VkFormat_VK_FORMAT_B10G11R11_UFLOAT_PACK32, 11, true),
+            (VkFormat_VK_FORMAT_E5B9G9R9_UFLOAT_PACK32, 9, true),
+            (VkFormat_VK_FORMAT_D24_UNORM_S8_UINT, 24, false),
+            (VkFormat_VK_FORMAT_D32_SFLOAT, 32, true),
+            (VkFormat_VK_FORMAT_BC7_UNORM_BLOCK, 0, false),
+            (VkFormat_VK_FORMAT_BC6H_UFLOAT_BLOCK, 0, true),
+        ] {
+            let info = FormatInfo::of(vk_format).unwrap();
+            assert_eq!(
+                (info.channel_bits, info.is_float),
+                (channel_bits, is_float),
+                "{}",
+                info.name
+            );
+        }
+    }
+
+    #[test]
+    fn sizes_match_libktx() {
+        for info in FormatInfo::all().filter(|info| known_to_libktx(info.vk_format)) {
+            let texture = TextureBuilder::new()
+                .vk_format(info.vk_format)
+                .extent_2d(13, 7)
+                .levels(4)
+                .build_ktx2()
+                .unwrap_or_else(|error| panic!("{}: {error}", info.name));
+            let element_size = unsafe { ktxTexture_GetElementSize(texture.as_raw()) };
+            assert_eq!(element_size, info.bytes_per_block, "{}", info.name);
+            for image in texture.images().unwrap() {
+                assert_eq!(
+                    image.data.len() as u64,
+                    info.image_size(image.width, image.height, 1),
+                    "{} level {}",
+                    info.name,
+                    image.level
+                );
+            }
+        }
+    }
+}
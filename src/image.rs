@@ -0,0 +1,340 @@
+//! Iteration over the individual images of a texture.
+
+use std::{marker::PhantomData, slice};
+
+use crate::{error::check, ffi::*, KtxError};
+
+/// One image of a texture: a single face or depth slice of one layer of one level.
+#[derive(Clone, Copy, Debug)]
+pub struct ImageRef<'a> {
+    pub level: u32,
+    pub layer: u32,
+    /// The cubemap face, or the depth slice of a 3D texture.
+    pub face_or_slice: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Depth of the level the image belongs to. `data` holds a single slice.
+    pub depth: u32,
+    pub data: &'a [u8],
+}
+
+/// Mutable counterpart of [`ImageRef`].
+#[derive(Debug)]
+pub struct ImageMut<'a> {
+    pub level: u32,
+    pub layer: u32,
+    /// The cubemap face, or the depth slice of a 3D texture.
+    pub face_or_slice: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Depth of the level the image belongs to. `data` holds a single slice.
+    pub depth: u32,
+    pub data: &'a mut [u8],
+}
+
+/// Iterator over the images of a texture, level by level, then layer, then face or slice.
+pub struct Images<'a> {
+    cursor: Cursor,
+    _marker: PhantomData<&'a [u8]>,
+}
+
+/// Iterator over the images of a texture, yielding mutable data.
+pub struct ImagesMut<'a> {
+    cursor: Cursor,
+    _marker: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> Images<'a> {
+    /// # Safety
+    /// `texture` must be valid and not be mutated for `'a`.
+    pub(crate) unsafe fn new(texture: *mut ktxTexture) -> Result<Self, KtxError> {
+        Ok(Self {
+            cursor: Cursor::new(texture)?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a> ImagesMut<'a> {
+    /// # Safety
+    /// `texture` must be valid and not be accessed through other pointers for `'a`.
+    pub(crate) unsafe fn new(texture: *mut ktxTexture) -> Result<Self, KtxError> {
+        Ok(Self {
+            cursor: Cursor::new(texture)?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a> Iterator for Images<'a> {
+    type Item = ImageRef<'a>;
+
+    fn next(&mut self) -> Option<ImageRef<'a>> {
+        let image = self.cursor.next()?;
+        Some(ImageRef {
+            level: image.level,
+            layer: image.layer,
+            face_or_slice: image.face_or_slice,
+            width: image.width,
+            height: image.height,
+            depth: image.depth,
+            data: unsafe { slice::from_raw_parts(image.data, image.size) },
+        })
+    }
+}
+
+impl<'a> Iterator for ImagesMut<'a> {
+    type Item = ImageMut<'a>;
+
+    fn next(&mut self) -> Option<ImageMut<'a>> {
+        let image = self.cursor.next()?;
+        Some(ImageMut {
+            level: image.level,
+            layer: image.layer,
+            face_or_slice: image.face_or_slice,
+            width: image.width,
+            height: image.height,
+            depth: image.depth,
+            // Images never overlap, so each slice is handed out only once.
+            data: unsafe { slice::from_raw_parts_mut(image.data, image.size) },
+        })
+    }
+}
+
+struct Location {
+    level: u32,
+    layer: u32,
+    face_or_slice: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    data: *mut u8,
+    size: usize,
+}
+
+// Walks the (level, layer, face or slice) triples of a texture with loaded image data.
+struct Cursor {
+    texture: *mut ktxTexture,
+    level: u32,
+    layer: u32,
+    face_or_slice: u32,
+}
+
+impl Cursor {
+    unsafe fn new(texture: *mut ktxTexture) -> Result<Self, KtxError> {
+        let base = &*texture;
+        // BasisLZ payloads are one global codebook plus per-image slices, not plain images.
+        if base.pData.is_null()
+            || (base.classId == class_id_ktxTexture2_c
+                && (*texture.cast::<ktxTexture2>()).supercompressionScheme
+                    == ktxSupercmpScheme_KTX_SS_BASIS_LZ)
+        {
+            return Err(KtxError::InvalidOperation);
+        }
+        let mut cursor = Self {
+            texture,
+            level: 0,
+            layer: 0,
+            face_or_slice: 0,
+        };
+        // Check every image lies within the data up front, so iteration can't fail.
+        let vtbl = &*base.vtbl;
+        let get_image_offset = vtbl.GetImageOffset.unwrap();
+        let get_image_size = vtbl.GetImageSize.unwrap();
+        for level in 0..base.numLevels {
+            let size = get_image_size(texture, level);
+            for layer in 0..base.numLayers {
+                for face_or_slice in 0..cursor.faces_or_slices(level) {
+                    let mut offset = 0;
+                    check(get_image_offset(
+                        texture,
+                        level,
+                        layer,
+                        face_or_slice,
+                        &mut offset,
+                    ))?;
+                    match offset.checked_add(size) {
+                        Some(end) if end <= base.dataSize => {}
+                        _ => return Err(KtxError::FileDataError),
+                    }
+                }
+            }
+        }
+        if base.numLevels == 0 || base.numLayers == 0 {
+            cursor.level = base.numLevels;
+        }
+        Ok(cursor)
+    }
+
+    unsafe fn faces_or_slices(&self, level: u32) -> u32 {
+        let base = &*self.texture;
+        if base.numDimensions == 3 {
+            level_size(base.baseDepth, level)
+        } else {
+            base.numFaces
+        }
+    }
+
+    fn next(&mut self) -> Option<Location> {
+        unsafe {
+            let base = &*self.texture;
+            if self.level >= base.numLevels {
+                return None;
+            }
+            let (level, layer, face_or_slice) = (self.level, self.layer, self.face_or_slice);
+            self.face_or_slice += 1;
+            if self.face_or_slice == self.faces_or_slices(level) {
+                self.face_or_slice = 0;
+                self.layer += 1;
+                if self.layer == base.numLayers {
+                    self.layer = 0;
+                    self.level += 1;
+                }
+            }
+            let vtbl = &*base.vtbl;
+            let mut offset = 0;
+            vtbl.GetImageOffset.unwrap()(self.texture, level, layer, face_or_slice, &mut offset);
+            Some(Location {
+                level,
+                layer,
+                face_or_slice,
+                width: level_size(base.baseWidth, level),
+                height: level_size(base.baseHeight, level),
+                depth: level_size(base.baseDepth, level),
+                data: base.pData.add(offset),
+                size: vtbl.GetImageSize.unwrap()(self.texture, level),
+            })
+        }
+    }
+}
+
+fn level_size(base: u32, level: u32) -> u32 {
+    base.checked_shr(level).unwrap_or(0).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+    use crate::{BaseTexture, TextureBuilder};
+
+    // Two layers of a 4x2 level and a 2x1 level of one byte texels, level by level.
+    unsafe extern "C" fn image_size(_texture: *mut ktxTexture, level: u32) -> usize {
+        8 >> (2 * level)
+    }
+
+    unsafe extern "C" fn image_offset(
+        _texture: *mut ktxTexture,
+        level: u32,
+        layer: u32,
+        _face_or_slice: u32,
+        offset: *mut usize,
+    ) -> ktx_error_code_e {
+        *offset = if level == 0 {
+            8 * layer
+        } else {
+            16 + 2 * layer
+        } as usize;
+        ktx_error_code_e_KTX_SUCCESS
+    }
+
+    fn fake_texture(vtbl: &mut ktxTexture_vtbl, data: &mut [u8]) -> ktxTexture {
+        vtbl.GetImageSize = Some(image_size);
+        vtbl.GetImageOffset = Some(image_offset);
+        let mut texture: ktxTexture = unsafe { std::mem::zeroed() };
+        texture.classId = class_id_ktxTexture1_c;
+        texture.vtbl = vtbl;
+        (texture.baseWidth, texture.baseHeight, texture.baseDepth) = (4, 2, 1);
+        (texture.numDimensions, texture.numLevels) = (2, 2);
+        (texture.numLayers, texture.numFaces, texture.isArray) = (2, 1, true);
+        texture.pData = data.as_mut_ptr();
+        texture.dataSize = data.len();
+        texture
+    }
+
+    #[test]
+    fn iterates_in_level_layer_order() {
+        let mut vtbl: ktxTexture_vtbl = unsafe { std::mem::zeroed() };
+        let mut data: Vec<u8> = (0..20).collect();
+        let mut texture = fake_texture(&mut vtbl, &mut data);
+        let images: Vec<_> = unsafe { Images::new(&mut texture) }
+            .unwrap()
+            .map(|image| {
+                (
+                    image.level,
+                    image.layer,
+                    image.width,
+                    image.height,
+                    image.data,
+                )
+            })
+            .collect();
+        assert_eq!(
+            images,
+            [
+                (0, 0, 4, 2, &[0, 1, 2, 3, 4, 5, 6, 7][..]),
+                (0, 1, 4, 2, &[8, 9, 10, 11, 12, 13, 14, 15][..]),
+                (1, 0, 2, 1, &[16, 17][..]),
+                (1, 1, 2, 1, &[18, 19][..]),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_images_outside_the_data() {
+        let mut vtbl: ktxTexture_vtbl = unsafe { std::mem::zeroed() };
+        let mut data = vec![0; 19];
+        let mut texture = fake_texture(&mut vtbl, &mut data);
+        assert_eq!(
+            unsafe { Images::new(&mut texture) }.err(),
+            Some(KtxError::FileDataError)
+        );
+        texture.pData = ptr::null_mut();
+        assert_eq!(
+            unsafe { ImagesMut::new(&mut texture) }.err(),
+            Some(KtxError::InvalidOperation)
+        );
+    }
+
+    #[test]
+    fn counts_faces_and_slices() {
+        let r8 = TextureBuilder::new().vk_format(VkFormat_VK_FORMAT_R8_UNORM);
+        let cubemap_array = r8
+            .clone()
+            .extent_2d(4, 4)
+            .levels(2)
+            .cubemap(true)
+            .array(true)
+            .layers(2)
+            .build_ktx2()
+            .unwrap();
+        assert_eq!(cubemap_array.images().unwrap().count(), 2 * 2 * 6);
+
+        let volume = r8.extent_3d(4, 4, 4).levels(3).build_ktx2().unwrap();
+        let slices: Vec<_> = volume
+            .images()
+            .unwrap()
+            .map(|image| {
+                (
+                    image.level,
+                    image.face_or_slice,
+                    image.depth,
+                    image.data.len(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            slices,
+            [
+                (0, 0, 4, 16),
+                (0, 1, 4, 16),
+                (0, 2, 4, 16),
+                (0, 3, 4, 16),
+                (1, 0, 2, 4),
+                (1, 1, 2, 4),
+                (2, 0, 1, 1),
+            ]
+        );
+    }
+}
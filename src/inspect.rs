@@ -0,0 +1,860 @@
+//! Structured equivalent of the `ktxPrintInfo*` functions of libktx.
+//!
+//! Those print a description of a KTX file to stdout. [`inspect`] returns the
+//! same information as a [`Report`] instead, which can be rendered as the
+//! upstream text with [`Report::to_text`] or as JSON with [`Report::to_json`].
+
+use std::{
+    ffi::CStr,
+    fmt::{self, Write},
+};
+
+use crate::{
+    dfd::BasicDescriptor,
+    ffi::*,
+    metadata::string_value,
+    reader::{
+        read_header, read_level_index, read_u32, DescriptorView, Header, Index, KeyValues,
+        Ktx2Reader, LevelIndex, ReadError, HEADER_SIZE, KTX2_IDENTIFIER, LEVEL_INDEX_ENTRY_SIZE,
+    },
+    validate::{BASIS_LZ_HEADER_SIZE, BASIS_LZ_IMAGE_DESC_SIZE},
+};
+
+/// Everything `ktxPrintInfoForMemory` shows about a KTX2 file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub header: Header,
+    pub index: Index,
+    /// The level index, from level 0 down.
+    pub levels: Vec<LevelIndex>,
+    /// `dfdTotalSize`, the first word of the DFD.
+    pub dfd_total_size: u32,
+    pub dfd: Result<BasicDescriptor, ReadError>,
+    /// Key/value pairs in file order. Keys that aren't UTF-8 are converted lossily.
+    pub key_values: Vec<(String, Vec<u8>)>,
+    /// The supercompression global data of BasisLZ files.
+    pub basis_global_data: Option<BasisGlobalData>,
+    /// Why [`Ktx2Reader`] rejected the file, if it did. Sections extending past
+    /// the end of the data are then left out.
+    pub error: Option<ReadError>,
+}
+
+/// The header of BasisLZ supercompression global data, with an image
+/// descriptor for every image in the file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BasisGlobalData {
+    pub endpoint_count: u16,
+    pub selector_count: u16,
+    pub endpoints_byte_length: u32,
+    pub selectors_byte_length: u32,
+    pub tables_byte_length: u32,
+    pub extended_byte_length: u32,
+    pub images: Vec<BasisImageDesc>,
+}
+
+/// Where the slices of one image are in the level data of a BasisLZ file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasisImageDesc {
+    pub image_flags: u32,
+    pub rgb_slice_byte_offset: u32,
+    pub rgb_slice_byte_length: u32,
+    pub alpha_slice_byte_offset: u32,
+    pub alpha_slice_byte_length: u32,
+}
+
+/// Describes the KTX2 file in `data`.
+///
+/// Like `ktxPrintInfoForMemory`, this still describes files [`Ktx2Reader`]
+/// rejects as far as possible, with [`Report::error`] saying why. Only data
+/// without a whole KTX2 header is an error.
+pub fn inspect(data: &[u8]) -> Result<Report, ReadError> {
+    let error = match Ktx2Reader::new(data) {
+        Ok(reader) => return Ok(Report::from_reader(&reader)),
+        Err(error) => error,
+    };
+    if !data.starts_with(&KTX2_IDENTIFIER) {
+        return Err(error);
+    }
+    let (header, index) = read_header(data)?;
+    Ok(Report::partial(data, header, index, error))
+}
+
+impl Report {
+    pub fn from_reader(reader: &Ktx2Reader<'_>) -> Self {
+        let header = *reader.header();
+        let basis_global_data = (header.supercompression_scheme
+            == ktxSupercmpScheme_KTX_SS_BASIS_LZ)
+            .then(|| BasisGlobalData::parse(reader.supercompression_global_data()))
+            .flatten();
+        Self {
+            header,
+            index: *reader.index(),
+            levels: reader.levels().collect(),
+            dfd_total_size: read_u32(reader.dfd(), 0),
+            dfd: reader.basic_descriptor().map(BasicDescriptor::from),
+            key_values: owned_key_values(reader.key_values()),
+            basis_global_data,
+            error: None,
+        }
+    }
+
+    // Reads what is within `data` of a file the reader rejected with `error`.
+    fn partial(data: &[u8], header: Header, index: Index, error: ReadError) -> Self {
+        let section = |offset: u64, length: u64| {
+            let start = usize::try_from(offset).ok()?;
+            let end = start.checked_add(usize::try_from(length).ok()?)?;
+            data.get(start..end)
+        };
+        let levels = (0..header.level_count.max(1) as usize)
+            .take_while(|&level| HEADER_SIZE + (level + 1) * LEVEL_INDEX_ENTRY_SIZE <= data.len())
+            .map(|level| read_level_index(data, level))
+            .collect();
+        let dfd = section(index.dfd_byte_offset.into(), index.dfd_byte_length.into())
+            .filter(|dfd| dfd.len() >= 4);
+        let kvd = section(index.kvd_byte_offset.into(), index.kvd_byte_length.into());
+        let basis_global_data = (header.supercompression_scheme
+            == ktxSupercmpScheme_KTX_SS_BASIS_LZ)
+            .then(|| section(index.sgd_byte_offset, index.sgd_byte_length))
+            .flatten()
+            .and_then(BasisGlobalData::parse);
+        Self {
+            header,
+            index,
+            levels,
+            dfd_total_size: dfd.map_or(0, |dfd| read_u32(dfd, 0)),
+            dfd: dfd
+                .ok_or(ReadError::Truncated)
+                .and_then(|dfd| DescriptorView::new(&dfd[4..]))
+                .map(BasicDescriptor::from),
+            key_values: kvd.map_or_else(Vec::new, |kvd| owned_key_values(KeyValues::new(kvd))),
+            basis_global_data,
+            error: Some(error),
+        }
+    }
+
+    /// Renders the report in the layout of `ktxPrintInfoForMemory`, followed by
+    /// [`Report::error`] if there is one.
+    pub fn to_text(&self) -> String {
+        self.to_string()
+    }
+
+    /// Renders the report as a JSON object, using the field names of the KTX2
+    /// specification.
+    pub fn to_json(&self) -> String {
+        let header = &self.header;
+        let index = &self.index;
+        let section = |offset: u64, length: u64| {
+            Json::Object(vec![
+                ("byteOffset", offset.into()),
+                ("byteLength", length.into()),
+            ])
+        };
+        let dfd = match &self.dfd {
+            Ok(dfd) => Json::Object(vec![
+                ("totalSize", self.dfd_total_size.into()),
+                ("vendorId", dfd.vendor_id.into()),
+                ("descriptorType", dfd.descriptor_type.into()),
+                ("versionNumber", dfd.version_number.into()),
+                ("colorModel", dfd.color_model.into()),
+                ("colorPrimaries", dfd.color_primaries.into()),
+                ("transferFunction", dfd.transfer_function.into()),
+                ("flags", dfd.flags.into()),
+                ("texelBlockDimension", dfd.texel_block_dimensions.into()),
+                ("bytesPlane", dfd.bytes_planes.into()),
+                (
+                    "samples",
+                    Json::Array(
+                        dfd.samples
+                            .iter()
+                            .map(|sample| {
+                                Json::Object(vec![
+                                    ("bitOffset", sample.bit_offset.into()),
+                                    ("bitLength", sample.bit_length.into()),
+                                    ("channelType", sample.channel.into()),
+                                    ("qualifiers", sample.qualifiers.into()),
+                                    ("samplePosition", sample.sample_positions.into()),
+                                    ("sampleLower", sample.lower.into()),
+                                    ("sampleUpper", sample.upper.into()),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+            ]),
+            Err(err) => Json::Object(vec![
+                ("totalSize", self.dfd_total_size.into()),
+                ("error", err.to_string().into()),
+            ]),
+        };
+        let global_data = match &self.basis_global_data {
+            Some(sgd) => Json::Object(vec![
+                ("endpointCount", sgd.endpoint_count.into()),
+                ("selectorCount", sgd.selector_count.into()),
+                ("endpointsByteLength", sgd.endpoints_byte_length.into()),
+                ("selectorsByteLength", sgd.selectors_byte_length.into()),
+                ("tablesByteLength", sgd.tables_byte_length.into()),
+                ("extendedByteLength", sgd.extended_byte_length.into()),
+                (
+                    "images",
+                    Json::Array(
+                        sgd.images
+                            .iter()
+                            .map(|image| {
+                                Json::Object(vec![
+                                    ("imageFlags", image.image_flags.into()),
+                                    ("rgbSliceByteOffset", image.rgb_slice_byte_offset.into()),
+                                    ("rgbSliceByteLength", image.rgb_slice_byte_length.into()),
+                                    ("alphaSliceByteOffset", image.alpha_slice_byte_offset.into()),
+                                    ("alphaSliceByteLength", image.alpha_slice_byte_length.into()),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                ),
+            ]),
+            None => Json::Null,
+        };
+
+        let mut fields = vec![
+            (
+                "header",
+                Json::Object(vec![
+                    ("vkFormat", header.vk_format.into()),
+                    ("typeSize", header.type_size.into()),
+                    ("pixelWidth", header.pixel_width.into()),
+                    ("pixelHeight", header.pixel_height.into()),
+                    ("pixelDepth", header.pixel_depth.into()),
+                    ("layerCount", header.layer_count.into()),
+                    ("faceCount", header.face_count.into()),
+                    ("levelCount", header.level_count.into()),
+                    (
+                        "supercompressionScheme",
+                        header.supercompression_scheme.into(),
+                    ),
+                ]),
+            ),
+            (
+                "index",
+                Json::Object(vec![
+                    (
+                        "dataFormatDescriptor",
+                        section(index.dfd_byte_offset.into(), index.dfd_byte_length.into()),
+                    ),
+                    (
+                        "keyValueData",
+                        section(index.kvd_byte_offset.into(), index.kvd_byte_length.into()),
+                    ),
+                    (
+                        "supercompressionGlobalData",
+                        section(index.sgd_byte_offset, index.sgd_byte_length),
+                    ),
+                ]),
+            ),
+            (
+                "levelIndex",
+                Json::Array(
+                    self.levels
+                        .iter()
+                        .map(|level| {
+                            Json::Object(vec![
+                                ("byteOffset", level.byte_offset.into()),
+                                ("byteLength", level.byte_length.into()),
+                                (
+                                    "uncompressedByteLength",
+                                    level.uncompressed_byte_length.into(),
+                                ),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+            ("dataFormatDescriptor", dfd),
+            (
+                "keyValueData",
+                Json::Array(
+                    self.key_values
+                        .iter()
+                        .map(|(key, value)| {
+                            let value = match string_text(value) {
+                                Some(value) => value.into(),
+                                None => {
+                                    Json::Array(value.iter().map(|&byte| byte.into()).collect())
+                                }
+                            };
+                            Json::Object(vec![("key", key.as_str().into()), ("value", value)])
+                        })
+                        .collect(),
+                ),
+            ),
+            ("supercompressionGlobalData", global_data),
+        ];
+        if let Some(error) = &self.error {
+            fields.push(("error", error.to_string().into()));
+        }
+        Json::Object(fields).to_string()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = &self.header;
+        let index = &self.index;
+        writeln!(f, "Header\n")?;
+        writeln!(f, "identifier: «KTX 20»\\r\\n\\x1A\\n")?;
+        match vk_format_name(header.vk_format) {
+            Some(name) => writeln!(f, "vkFormat: {name}")?,
+            None => writeln!(f, "vkFormat: {}", header.vk_format)?,
+        }
+        writeln!(f, "typeSize: {}", header.type_size)?;
+        writeln!(f, "pixelWidth: {}", header.pixel_width)?;
+        writeln!(f, "pixelHeight: {}", header.pixel_height)?;
+        writeln!(f, "pixelDepth: {}", header.pixel_depth)?;
+        writeln!(f, "layerCount: {}", header.layer_count)?;
+        writeln!(f, "faceCount: {}", header.face_count)?;
+        writeln!(f, "levelCount: {}", header.level_count)?;
+        writeln!(
+            f,
+            "supercompressionScheme: {}",
+            scheme_name(header.supercompression_scheme)
+        )?;
+        writeln!(
+            f,
+            "dataFormatDescriptor.byteOffset: {}",
+            Hex(index.dfd_byte_offset.into())
+        )?;
+        writeln!(
+            f,
+            "dataFormatDescriptor.byteLength: {}",
+            index.dfd_byte_length
+        )?;
+        writeln!(
+            f,
+            "keyValueData.byteOffset: {}",
+            Hex(index.kvd_byte_offset.into())
+        )?;
+        writeln!(f, "keyValueData.byteLength: {}", index.kvd_byte_length)?;
+        writeln!(
+            f,
+            "supercompressionGlobalData.byteOffset: {}",
+            Hex(index.sgd_byte_offset)
+        )?;
+        writeln!(
+            f,
+            "supercompressionGlobalData.byteLength: {}",
+            index.sgd_byte_length
+        )?;
+
+        writeln!(f, "\nLevel Index\n")?;
+        for (level, entry) in self.levels.iter().enumerate() {
+            writeln!(f, "Level{level}.byteOffset: {}", Hex(entry.byte_offset))?;
+            writeln!(f, "Level{level}.byteLength: {}", entry.byte_length)?;
+            writeln!(
+                f,
+                "Level{level}.uncompressedByteLength: {}",
+                entry.uncompressed_byte_length
+            )?;
+        }
+
+        writeln!(f, "\nData Format Descriptor\n")?;
+        writeln!(f, "DFD total bytes: {}", self.dfd_total_size)?;
+        match &self.dfd {
+            Ok(dfd) => write_dfd(f, dfd)?,
+            Err(err) => writeln!(f, "{err}")?,
+        }
+
+        writeln!(f, "\nKey/Value Data\n")?;
+        if self.key_values.is_empty() {
+            writeln!(f, "None")?;
+        }
+        for (key, value) in &self.key_values {
+            match string_text(value) {
+                Some(value) => writeln!(f, "{key}: {value}")?,
+                None => {
+                    write!(f, "{key}:")?;
+                    for byte in value {
+                        write!(f, " {byte:02x}")?;
+                    }
+                    writeln!(f)?;
+                }
+            }
+        }
+
+        if let Some(sgd) = &self.basis_global_data {
+            writeln!(f, "\nBasis Supercompression Global Data\n")?;
+            writeln!(f, "endpointCount: {}", sgd.endpoint_count)?;
+            writeln!(f, "selectorCount: {}", sgd.selector_count)?;
+            writeln!(f, "endpointsByteLength: {}", sgd.endpoints_byte_length)?;
+            writeln!(f, "selectorsByteLength: {}", sgd.selectors_byte_length)?;
+            writeln!(f, "tablesByteLength: {}", sgd.tables_byte_length)?;
+            writeln!(f, "extendedByteLength: {}", sgd.extended_byte_length)?;
+            for (i, image) in sgd.images.iter().enumerate() {
+                writeln!(f, "\nImage{i}")?;
+                writeln!(f, "imageFlags: {}", Hex(image.image_flags.into()))?;
+                writeln!(f, "rgbSliceByteLength: {}", image.rgb_slice_byte_length)?;
+                writeln!(
+                    f,
+                    "rgbSliceByteOffset: {}",
+                    Hex(image.rgb_slice_byte_offset.into())
+                )?;
+                writeln!(f, "alphaSliceByteLength: {}", image.alpha_slice_byte_length)?;
+                writeln!(
+                    f,
+                    "alphaSliceByteOffset: {}",
+                    Hex(image.alpha_slice_byte_offset.into())
+                )?;
+            }
+        }
+        if let Some(error) = &self.error {
+            writeln!(f, "\n{error}")?;
+        }
+        Ok(())
+    }
+}
+
+// Follows printDFD from the dfdutils of KTX-Software.
+fn write_dfd(f: &mut fmt::Formatter<'_>, dfd: &BasicDescriptor) -> fmt::Result {
+    let [width, height, depth, fourth] = dfd.texel_block_dimensions;
+    let [p0, p1, p2, p3, p4, p5, p6, p7] = dfd.bytes_planes;
+    writeln!(
+        f,
+        "BDB descriptor type 0x{:04x} vendor id = 0x{:05x}",
+        dfd.descriptor_type, dfd.vendor_id
+    )?;
+    writeln!(
+        f,
+        "Descriptor block size {} ({} samples) versionNumber = 0x{:04x}",
+        24 + 16 * dfd.samples.len(),
+        dfd.samples.len(),
+        dfd.version_number
+    )?;
+    writeln!(
+        f,
+        "Flags 0x{:02x} Xfer {:02} Primaries {:02} Model {:03}",
+        dfd.flags, dfd.transfer_function, dfd.color_primaries, dfd.color_model
+    )?;
+    writeln!(f, "Dimensions: {width},{height},{depth},{fourth}")?;
+    writeln!(f, "Plane bytes: {p0},{p1},{p2},{p3},{p4},{p5},{p6},{p7}")?;
+    for (i, sample) in dfd.samples.iter().enumerate() {
+        let [x, y, z, w] = sample.sample_positions;
+        writeln!(f, "    Sample {i}")?;
+        writeln!(
+            f,
+            "Qualifiers {:x} Channel 0x{:x} ({}) Length {} bits Offset {}",
+            sample.qualifiers >> 4,
+            sample.channel,
+            channel_name(dfd.color_model, sample.channel),
+            sample.bit_length,
+            sample.bit_offset
+        )?;
+        writeln!(f, "Position: {x},{y},{z},{w}")?;
+        writeln!(
+            f,
+            "Lower 0x{:08x}\nUpper 0x{:08x}",
+            sample.lower, sample.upper
+        )?;
+    }
+    Ok(())
+}
+
+fn channel_name(model: u32, channel: u8) -> &'static str {
+    match (model, u32::from(channel)) {
+        (_khr_df_model_e_KHR_DF_MODEL_UASTC, _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RGB) => {
+            "RGB"
+        }
+        (
+            _khr_df_model_e_KHR_DF_MODEL_UASTC,
+            _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RGBA,
+        ) => "RGBA",
+        (_khr_df_model_e_KHR_DF_MODEL_UASTC, _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RRR) => {
+            "RRR"
+        }
+        (
+            _khr_df_model_e_KHR_DF_MODEL_UASTC,
+            _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RRRG,
+        ) => "RRRG",
+        (_khr_df_model_e_KHR_DF_MODEL_UASTC, _khr_df_model_channels_e_KHR_DF_CHANNEL_UASTC_RG) => {
+            "RG"
+        }
+        (_khr_df_model_e_KHR_DF_MODEL_ETC1S, _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_RGB) => {
+            "RGB"
+        }
+        (_khr_df_model_e_KHR_DF_MODEL_ETC1S, _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_RRR) => {
+            "RRR"
+        }
+        (_khr_df_model_e_KHR_DF_MODEL_ETC1S, _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_GGG) => {
+            "GGG"
+        }
+        (_khr_df_model_e_KHR_DF_MODEL_ETC1S, _khr_df_model_channels_e_KHR_DF_CHANNEL_ETC1S_AAA) => {
+            "AAA"
+        }
+        (_, channel) => [
+            "R", "G", "B", "3", "4", "5", "6", "7", "8", "9", "a", "b", "c", "D", "S", "A",
+        ][channel as usize & 0xf],
+    }
+}
+
+fn vk_format_name(vk_format: u32) -> Option<&'static str> {
+    match vk_format {
+        0 => Some("VK_FORMAT_UNDEFINED"),
+        _ => crate::FormatInfo::of(vk_format).map(|info| info.name),
+    }
+}
+
+fn scheme_name(scheme: ktxSupercmpScheme) -> &'static str {
+    // ktxSupercompressionSchemeString returns pointers to static ASCII strings.
+    unsafe { CStr::from_ptr(ktxSupercompressionSchemeString(scheme)) }
+        .to_str()
+        .unwrap_or("")
+}
+
+fn owned_key_values(key_values: KeyValues<'_>) -> Vec<(String, Vec<u8>)> {
+    key_values
+        .map(|(key, value)| (key.to_string_lossy().into_owned(), value.to_vec()))
+        .collect()
+}
+
+// Values stored as a single NUL-terminated UTF-8 string, as most keys are.
+// The key only names the error, which is dropped.
+fn string_text(value: &[u8]) -> Option<&str> {
+    string_value(b"\0", value).ok()
+}
+
+/// Formats like the `%#x` conversion of C: `0` or `0x` followed by the digits.
+struct Hex(u64);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            0 => f.write_str("0"),
+            value => write!(f, "{value:#x}"),
+        }
+    }
+}
+
+impl BasisGlobalData {
+    // Image descriptors follow the header up to the endpoint data.
+    fn parse(data: &[u8]) -> Option<Self> {
+        let header_size = BASIS_LZ_HEADER_SIZE as usize;
+        let header = data.get(..header_size)?;
+        let u16_at = |offset: usize| u16::from_le_bytes([header[offset], header[offset + 1]]);
+        let sgd = Self {
+            endpoint_count: u16_at(0),
+            selector_count: u16_at(2),
+            endpoints_byte_length: read_u32(header, 4),
+            selectors_byte_length: read_u32(header, 8),
+            tables_byte_length: read_u32(header, 12),
+            extended_byte_length: read_u32(header, 16),
+            images: Vec::new(),
+        };
+        let payload = [
+            sgd.endpoints_byte_length,
+            sgd.selectors_byte_length,
+            sgd.tables_byte_length,
+            sgd.extended_byte_length,
+        ]
+        .iter()
+        .try_fold(0usize, |sum, &length| sum.checked_add(length as usize))?;
+        let descs = data[header_size..].len().checked_sub(payload)?;
+        let images = data[header_size..header_size + descs]
+            .chunks_exact(BASIS_LZ_IMAGE_DESC_SIZE as usize)
+            .map(|desc| BasisImageDesc {
+                image_flags: read_u32(desc, 0),
+                rgb_slice_byte_offset: read_u32(desc, 4),
+                rgb_slice_byte_length: read_u32(desc, 8),
+                alpha_slice_byte_offset: read_u32(desc, 12),
+                alpha_slice_byte_length: read_u32(desc, 16),
+            })
+            .collect();
+        Some(Self { images, ..sgd })
+    }
+}
+
+/// Minimal JSON document model, rendered with two-space indentation.
+enum Json {
+    Null,
+    Number(u64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+macro_rules! json_number {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for Json {
+            fn from(value: $ty) -> Self {
+                Json::Number(value.into())
+            }
+        })*
+    };
+}
+
+json_number!(u8, u16, u32, u64);
+
+impl<T: Into<Json>, const N: usize> From<[T; N]> for Json {
+    fn from(values: [T; N]) -> Self {
+        Json::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_owned())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl Json {
+    fn write(&self, out: &mut dyn Write, indent: usize) -> fmt::Result {
+        let pad = |out: &mut dyn Write, indent: usize| write!(out, "{:1$}", "", indent * 2);
+        match self {
+            Json::Null => out.write_str("null"),
+            Json::Number(value) => write!(out, "{value}"),
+            Json::String(value) => write_json_string(out, value),
+            Json::Array(values) if values.iter().all(|value| matches!(value, Json::Number(_))) => {
+                out.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.write_str(", ")?;
+                    }
+                    value.write(out, indent)?;
+                }
+                out.write_char(']')
+            }
+            Json::Array(values) => {
+                out.write_str("[\n")?;
+                for (i, value) in values.iter().enumerate() {
+                    pad(out, indent + 1)?;
+                    value.write(out, indent + 1)?;
+                    out.write_str(if i + 1 < values.len() { ",\n" } else { "\n" })?;
+                }
+                pad(out, indent)?;
+                out.write_char(']')
+            }
+            Json::Object(fields) => {
+                out.write_str("{\n")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    pad(out, indent + 1)?;
+                    write_json_string(out, key)?;
+                    out.write_str(": ")?;
+                    value.write(out, indent + 1)?;
+                    out.write_str(if i + 1 < fields.len() { ",\n" } else { "\n" })?;
+                }
+                pad(out, indent)?;
+                out.write_char('}')
+            }
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+fn write_json_string(out: &mut dyn Write, value: &str) -> fmt::Result {
+    out.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{rgba8_gradient, undefined_r8, write_ktx2},
+        BaseTexture, EncodeBasis, TextureBuilder,
+    };
+
+    #[test]
+    fn describes_written_file() {
+        let (texture, bytes) = write_ktx2(&undefined_r8(), "inspect \"test\"");
+
+        let report = inspect(&bytes).unwrap();
+        assert_eq!(report.levels.len(), 4);
+        assert_eq!(report.dfd.as_ref().ok(), texture.dfd().as_ref().ok());
+        // libktx appends its own version to the writer, e.g. " / libktx v4.3.2".
+        let [(key, value)] = report.key_values.as_slice() else {
+            panic!("{:?}", report.key_values);
+        };
+        assert_eq!(key, "KTXwriter");
+        assert!(value.starts_with(b"inspect \"test\""), "{value:?}");
+        assert!(value.ends_with(b"\0"), "{value:?}");
+
+        let text = report.to_text();
+        assert!(text.starts_with("Header\n\n"), "{text}");
+        for line in [
+            "vkFormat: VK_FORMAT_UNDEFINED\n",
+            "supercompressionScheme: KTX_SS_NONE\n",
+            "supercompressionGlobalData.byteOffset: 0\n",
+            "Level3.byteLength: 1\n",
+            "Plane bytes: 1,0,0,0,0,0,0,0\n",
+            "\nKTXwriter: inspect \"test\"",
+        ] {
+            assert!(text.contains(line), "{line:?} not in {text}");
+        }
+
+        let json = report.to_json();
+        for field in [
+            "\"pixelWidth\": 8,",
+            "\"bytesPlane\": [1, 0, 0, 0, 0, 0, 0, 0],",
+            "\"value\": \"inspect \\\"test\\\"",
+            "\"supercompressionGlobalData\": null\n}",
+        ] {
+            assert!(json.contains(field), "{field:?} not in {json}");
+        }
+    }
+
+    #[test]
+    fn describes_rejected_file_partially() {
+        let (_, bytes) = write_ktx2(&undefined_r8(), "inspect test");
+        let complete = inspect(&bytes).unwrap();
+        assert_eq!(complete.error, None);
+
+        // Level 0 is stored last, so it now extends past the end.
+        let report = inspect(&bytes[..bytes.len() - 1]).unwrap();
+        assert_eq!(report.error, Some(ReadError::Truncated));
+        assert_eq!(
+            Report {
+                error: None,
+                ..report.clone()
+            },
+            complete
+        );
+        assert!(report.to_text().ends_with("\ntruncated KTX2 file\n"));
+        assert!(report
+            .to_json()
+            .ends_with("\"error\": \"truncated KTX2 file\"\n}"));
+
+        // Only the header is needed.
+        let report = inspect(&bytes[..HEADER_SIZE]).unwrap();
+        assert_eq!(report.header, complete.header);
+        assert_eq!(report.levels, []);
+        assert!(report.dfd.is_err());
+        assert_eq!(
+            inspect(&bytes[..HEADER_SIZE - 1]).err(),
+            Some(ReadError::Truncated)
+        );
+        assert_eq!(inspect(b"KTX 11").err(), Some(ReadError::NotKtx2));
+    }
+
+    /// What `ktxPrintInfoForStdioStream` prints for `bytes`. It reads the file
+    /// from a stream and prints to stdout, so both go through temporary files,
+    /// with file descriptor 1 pointed at the second while libktx runs.
+    #[cfg(target_os = "linux")]
+    fn libktx_info(bytes: &[u8]) -> String {
+        use std::os::raw::{c_int, c_void};
+
+        extern "C" {
+            static mut stdout: *mut FILE;
+            fn tmpfile() -> *mut FILE;
+            fn fwrite(ptr: *const c_void, size: usize, count: usize, stream: *mut FILE) -> usize;
+            fn fread(ptr: *mut c_void, size: usize, count: usize, stream: *mut FILE) -> usize;
+            fn rewind(stream: *mut FILE);
+            fn fflush(stream: *mut FILE) -> c_int;
+            fn fclose(stream: *mut FILE) -> c_int;
+            fn fileno(stream: *mut FILE) -> c_int;
+            fn dup(fd: c_int) -> c_int;
+            fn dup2(fd: c_int, to: c_int) -> c_int;
+            fn close(fd: c_int) -> c_int;
+        }
+
+        unsafe {
+            let input = tmpfile();
+            let output = tmpfile();
+            assert!(!input.is_null() && !output.is_null());
+            assert_eq!(
+                fwrite(bytes.as_ptr().cast(), 1, bytes.len(), input),
+                bytes.len()
+            );
+            rewind(input);
+
+            fflush(stdout);
+            let saved = dup(1);
+            assert!(saved >= 0 && dup2(fileno(output), 1) >= 0);
+            let result = ktxPrintInfoForStdioStream(input);
+            fflush(stdout);
+            dup2(saved, 1);
+            close(saved);
+            crate::check(result).unwrap();
+
+            rewind(output);
+            let mut text = Vec::new();
+            let mut buffer = [0u8; 4096];
+            loop {
+                let read = fread(buffer.as_mut_ptr().cast(), 1, buffer.len(), output);
+                if read == 0 {
+                    break;
+                }
+                text.extend_from_slice(&buffer[..read]);
+            }
+            fclose(input);
+            fclose(output);
+            String::from_utf8(text).unwrap()
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn text_matches_libktx() {
+        let rgba8 = TextureBuilder::new()
+            .vk_format(VkFormat_VK_FORMAT_R8G8B8A8_SRGB)
+            .extent_2d(16, 8)
+            .levels(3)
+            .layers(2)
+            .array(true);
+        let mut files = vec![
+            write_ktx2(&undefined_r8(), "inspect test").1,
+            write_ktx2(&rgba8, "inspect test").1,
+        ];
+        let mut etc1s = rgba8_gradient(16, 16);
+        etc1s.compress_basis(&EncodeBasis::fast()).unwrap();
+        etc1s.metadata_mut().set_writer("inspect test").unwrap();
+        let mut bytes = Vec::new();
+        etc1s.write_to(&mut bytes).unwrap();
+        files.push(bytes);
+
+        for bytes in files {
+            let text = inspect(&bytes).unwrap().to_text();
+            let printed = libktx_info(&bytes);
+            // The test harness may report other tests on stdout while it is redirected.
+            assert!(
+                printed.contains(&text),
+                "libktx printed\n{printed}\ninstead of\n{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn json_layout() {
+        let json = Json::Object(vec![
+            ("text", "a\tb\u{1}".into()),
+            ("numbers", [1u8, 2].into()),
+            (
+                "objects",
+                Json::Array(vec![Json::Object(vec![("n", 3u32.into())])]),
+            ),
+            ("empty", Json::Array(Vec::new())),
+            ("none", Json::Null),
+        ]);
+        assert_eq!(
+            json.to_string(),
+            "{\n  \"text\": \"a\\tb\\u0001\",\n  \"numbers\": [1, 2],\n  \"objects\": [\n    {\n      \"n\": 3\n    }\n  ],\n  \"empty\": [],\n  \"none\": null\n}"
+        );
+    }
+}
@@ -1,9 +1,83 @@
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
 #![allow(non_snake_case)]
-#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+#![cfg_attr(not(feature = "native"), no_std)]
+// The README examples use the libktx wrappers
+#![cfg_attr(
+    feature = "native",
+    doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))
+)]
 
+// The tests use `std` whether or not the crate is `no_std`
+#[cfg(test)]
+extern crate std;
+
+pub mod reader;
+
+pub use reader::{DescriptorView, Ktx2Reader, ReadError, SampleView};
+
+// Everything else wraps libktx and needs the `native` feature
+
+#[cfg(feature = "native")]
 #[doc(hidden)]
 mod ffi;
 
+#[cfg(feature = "native")]
 pub use ffi::*;
+
+#[cfg(feature = "native")]
+pub mod builder;
+#[cfg(feature = "native")]
+pub mod dfd;
+#[cfg(feature = "native")]
+pub mod encode;
+#[cfg(feature = "native")]
+pub mod error;
+#[cfg(feature = "native")]
+pub mod format;
+#[cfg(feature = "native")]
+pub mod image;
+#[cfg(feature = "native")]
+pub mod inspect;
+#[cfg(feature = "native")]
+pub mod mapping;
+#[cfg(feature = "native")]
+pub mod metadata;
+#[cfg(feature = "native")]
+mod stream;
+#[cfg(all(test, feature = "native"))]
+mod testing;
+#[cfg(feature = "native")]
+pub mod texture;
+#[cfg(feature = "native")]
+pub mod transcode;
+#[cfg(feature = "native")]
+pub mod validate;
+
+#[cfg(feature = "native")]
+pub use builder::{CreateError, TextureBuilder};
+#[cfg(feature = "native")]
+pub use dfd::{BasicDescriptor, DfdBuilder, DfdError, SampleDescriptor};
+#[cfg(feature = "native")]
+pub use encode::{
+    AstcBlock, AstcMode, AstcParams, AstcQuality, BasisCodec, EncodeBasis, EncodeError, Etc1s, Rdo,
+    Uastc, UastcLevel,
+};
+#[cfg(feature = "native")]
+pub use error::{check, KtxError};
+#[cfg(feature = "native")]
+pub use format::{BlockCompression, FormatInfo};
+#[cfg(feature = "native")]
+pub use image::{ImageMut, ImageRef};
+#[cfg(feature = "native")]
+pub use inspect::{inspect, Report};
+#[cfg(feature = "native")]
+pub use mapping::GlFormat;
+#[cfg(feature = "native")]
+pub use metadata::{AnimData, Metadata, MetadataBuf, MetadataError, Orientation, Swizzle};
+#[cfg(feature = "native")]
+pub use texture::{BaseTexture, Texture, Texture1, Texture2};
+#[cfg(feature = "native")]
+pub use transcode::{choose_transcode_format, DeviceCaps, TranscodeFormat};
+#[cfg(feature = "native")]
+pub use validate::{validate, Diagnostic, Severity};
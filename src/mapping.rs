@@ -0,0 +1,587 @@
+//! Mapping between `VkFormat`, OpenGL formats and `DXGI_FORMAT`.
+//!
+//! Lookups from `VkFormat` are exact. Where several Vulkan formats share an
+//! OpenGL or DXGI format, e.g. the UNORM and SFLOAT ASTC formats, or BC1 with
+//! and without alpha, the reverse lookup returns the first of them.
+
+use crate::{
+    ffi::*,
+    format::{VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR, VK_FORMAT_A8_UNORM_KHR},
+};
+
+/// An OpenGL format as stored in KTX1 headers. Compressed formats have a
+/// `format` and `type_` of 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GlFormat {
+    pub internalformat: u32,
+    pub format: u32,
+    pub type_: u32,
+}
+
+impl GlFormat {
+    pub const fn new(internalformat: u32, format: u32, type_: u32) -> Self {
+        Self {
+            internalformat,
+            format,
+            type_,
+        }
+    }
+
+    /// The OpenGL format of `vk_format`, if OpenGL has one.
+    pub fn from_vk(vk_format: VkFormat) -> Option<Self> {
+        GL.iter()
+            .find(|&&(format, _)| format == vk_format)
+            .map(|&(_, gl)| gl)
+    }
+
+    /// The `VkFormat` matching all of `self`, falling back to the first one with
+    /// the same `internalformat`.
+    pub fn to_vk(&self) -> Option<VkFormat> {
+        GL.iter()
+            .find(|&(_, gl)| gl == self)
+            .or_else(|| {
+                GL.iter()
+                    .find(|&(_, gl)| gl.internalformat == self.internalformat)
+            })
+            .map(|&(vk_format, _)| vk_format)
+    }
+}
+
+/// The `DXGI_FORMAT` of `vk_format`, if Direct3D has one.
+pub fn vk_to_dxgi(vk_format: VkFormat) -> Option<u32> {
+    DXGI.iter()
+        .find(|&&(format, _)| format == vk_format)
+        .map(|&(_, dxgi)| dxgi)
+}
+
+pub fn dxgi_to_vk(dxgi_format: u32) -> Option<VkFormat> {
+    DXGI.iter()
+        .find(|&&(_, dxgi)| dxgi == dxgi_format)
+        .map(|&(vk_format, _)| vk_format)
+}
+
+mod gl {
+    pub const RED: u32 = 0x1903;
+    pub const RG: u32 = 0x8227;
+    pub const RGB: u32 = 0x1907;
+    pub const RGBA: u32 = 0x1908;
+    pub const BGR: u32 = 0x80E0;
+    pub const BGRA: u32 = 0x80E1;
+    pub const ALPHA: u32 = 0x1906;
+    pub const RED_INTEGER: u32 = 0x8D94;
+    pub const RG_INTEGER: u32 = 0x8228;
+    pub const RGB_INTEGER: u32 = 0x8D98;
+    pub const RGBA_INTEGER: u32 = 0x8D99;
+    pub const BGR_INTEGER: u32 = 0x8D9A;
+    pub const BGRA_INTEGER: u32 = 0x8D9B;
+    pub const DEPTH_COMPONENT: u32 = 0x1902;
+    pub const STENCIL_INDEX: u32 = 0x1901;
+    pub const DEPTH_STENCIL: u32 = 0x84F9;
+    pub const BYTE: u32 = 0x1400;
+    pub const UNSIGNED_BYTE: u32 = 0x1401;
+    pub const SHORT: u32 = 0x1402;
+    pub const UNSIGNED_SHORT: u32 = 0x1403;
+    pub const INT: u32 = 0x1404;
+    pub const UNSIGNED_INT: u32 = 0x1405;
+    pub const FLOAT: u32 = 0x1406;
+    pub const HALF_FLOAT: u32 = 0x140B;
+    pub const UNSIGNED_SHORT_4_4_4_4: u32 = 0x8033;
+    pub const UNSIGNED_SHORT_5_5_5_1: u32 = 0x8034;
+    pub const UNSIGNED_SHORT_5_6_5: u32 = 0x8363;
+    pub const UNSIGNED_SHORT_4_4_4_4_REV: u32 = 0x8365;
+    pub const UNSIGNED_SHORT_1_5_5_5_REV: u32 = 0x8366;
+    pub const UNSIGNED_INT_2_10_10_10_REV: u32 = 0x8368;
+    pub const UNSIGNED_INT_10F_11F_11F_REV: u32 = 0x8C3B;
+    pub const UNSIGNED_INT_5_9_9_9_REV: u32 = 0x8C3E;
+    pub const UNSIGNED_INT_24_8: u32 = 0x84FA;
+    pub const FLOAT_32_UNSIGNED_INT_24_8_REV: u32 = 0x8DAD;
+    pub const R8: u32 = 0x8229;
+    pub const R8_SNORM: u32 = 0x8F94;
+    pub const R8UI: u32 = 0x8232;
+    pub const R8I: u32 = 0x8231;
+    pub const SR8_EXT: u32 = 0x8FBD;
+    pub const RG8: u32 = 0x822B;
+    pub const RG8_SNORM: u32 = 0x8F95;
+    pub const RG8UI: u32 = 0x8238;
+    pub const RG8I: u32 = 0x8237;
+    pub const SRG8_EXT: u32 = 0x8FBE;
+    pub const RGB8: u32 = 0x8051;
+    pub const RGB8_SNORM: u32 = 0x8F96;
+    pub const RGB8UI: u32 = 0x8D7D;
+    pub const RGB8I: u32 = 0x8D8F;
+    pub const SRGB8: u32 = 0x8C41;
+    pub const RGBA8: u32 = 0x8058;
+    pub const RGBA8_SNORM: u32 = 0x8F97;
+    pub const RGBA8UI: u32 = 0x8D7C;
+    pub const RGBA8I: u32 = 0x8D8E;
+    pub const SRGB8_ALPHA8: u32 = 0x8C43;
+    pub const R16: u32 = 0x822A;
+    pub const R16_SNORM: u32 = 0x8F98;
+    pub const R16UI: u32 = 0x8234;
+    pub const R16I: u32 = 0x8233;
+    pub const R16F: u32 = 0x822D;
+    pub const RG16: u32 = 0x822C;
+    pub const RG16_SNORM: u32 = 0x8F99;
+    pub const RG16UI: u32 = 0x823A;
+    pub const RG16I: u32 = 0x8239;
+    pub const RG16F: u32 = 0x822F;
+    pub const RGB16: u32 = 0x8054;
+    pub const RGB16_SNORM: u32 = 0x8F9A;
+    pub const RGB16UI: u32 = 0x8D77;
+    pub const RGB16I: u32 = 0x8D89;
+    pub const RGB16F: u32 = 0x881B;
+    pub const RGBA16: u32 = 0x805B;
+    pub const RGBA16_SNORM: u32 = 0x8F9B;
+    pub const RGBA16UI: u32 = 0x8D76;
+    pub const RGBA16I: u32 = 0x8D88;
+    pub const RGBA16F: u32 = 0x881A;
+    pub const R32UI: u32 = 0x8236;
+    pub const R32I: u32 = 0x8235;
+    pub const R32F: u32 = 0x822E;
+    pub const RG32UI: u32 = 0x823C;
+    pub const RG32I: u32 = 0x823B;
+    pub const RG32F: u32 = 0x8230;
+    pub const RGB32UI: u32 = 0x8D71;
+    pub const RGB32I: u32 = 0x8D83;
+    pub const RGB32F: u32 = 0x8815;
+    pub const RGBA32UI: u32 = 0x8D70;
+    pub const RGBA32I: u32 = 0x8D82;
+    pub const RGBA32F: u32 = 0x8814;
+    pub const RGBA4: u32 = 0x8056;
+    pub const RGB5_A1: u32 = 0x8057;
+    pub const RGB565: u32 = 0x8D62;
+    pub const RGB10_A2: u32 = 0x8059;
+    pub const RGB10_A2UI: u32 = 0x906F;
+    pub const R11F_G11F_B10F: u32 = 0x8C3A;
+    pub const RGB9_E5: u32 = 0x8C3D;
+    pub const ALPHA8_EXT: u32 = 0x803C;
+    pub const DEPTH_COMPONENT16: u32 = 0x81A5;
+    pub const DEPTH_COMPONENT24: u32 = 0x81A6;
+    pub const DEPTH_COMPONENT32F: u32 = 0x8CAC;
+    pub const STENCIL_INDEX8: u32 = 0x8D48;
+    pub const DEPTH24_STENCIL8: u32 = 0x88F0;
+    pub const DEPTH32F_STENCIL8: u32 = 0x8CAD;
+    pub const COMPRESSED_RGB_S3TC_DXT1_EXT: u32 = 0x83F0;
+    pub const COMPRESSED_RGBA_S3TC_DXT1_EXT: u32 = 0x83F1;
+    pub const COMPRESSED_RGBA_S3TC_DXT3_EXT: u32 = 0x83F2;
+    pub const COMPRESSED_RGBA_S3TC_DXT5_EXT: u32 = 0x83F3;
+    pub const COMPRESSED_SRGB_S3TC_DXT1_EXT: u32 = 0x8C4C;
+    pub const COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT: u32 = 0x8C4D;
+    pub const COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT: u32 = 0x8C4E;
+    pub const COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT: u32 = 0x8C4F;
+    pub const COMPRESSED_RED_RGTC1: u32 = 0x8DBB;
+    pub const COMPRESSED_SIGNED_RED_RGTC1: u32 = 0x8DBC;
+    pub const COMPRESSED_RG_RGTC2: u32 = 0x8DBD;
+    pub const COMPRESSED_SIGNED_RG_RGTC2: u32 = 0x8DBE;
+    pub const COMPRESSED_RGBA_BPTC_UNORM: u32 = 0x8E8C;
+    pub const COMPRESSED_SRGB_ALPHA_BPTC_UNORM: u32 = 0x8E8D;
+    pub const COMPRESSED_RGB_BPTC_SIGNED_FLOAT: u32 = 0x8E8E;
+    pub const COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT: u32 = 0x8E8F;
+    pub const COMPRESSED_RGB8_ETC2: u32 = 0x9274;
+    pub const COMPRESSED_SRGB8_ETC2: u32 = 0x9275;
+    pub const COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2: u32 = 0x9276;
+    pub const COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2: u32 = 0x9277;
+    pub const COMPRESSED_RGBA8_ETC2_EAC: u32 = 0x9278;
+    pub const COMPRESSED_SRGB8_ALPHA8_ETC2_EAC: u32 = 0x9279;
+    pub const COMPRESSED_R11_EAC: u32 = 0x9270;
+    pub const COMPRESSED_SIGNED_R11_EAC: u32 = 0x9271;
+    pub const COMPRESSED_RG11_EAC: u32 = 0x9272;
+    pub const COMPRESSED_SIGNED_RG11_EAC: u32 = 0x9273;
+    pub const COMPRESSED_RGBA_PVRTC_2BPPV1_IMG: u32 = 0x8C03;
+    pub const COMPRESSED_RGBA_PVRTC_4BPPV1_IMG: u32 = 0x8C02;
+    pub const COMPRESSED_RGBA_PVRTC_2BPPV2_IMG: u32 = 0x9137;
+    pub const COMPRESSED_RGBA_PVRTC_4BPPV2_IMG: u32 = 0x9138;
+    pub const COMPRESSED_SRGB_ALPHA_PVRTC_2BPPV1_EXT: u32 = 0x8A56;
+    pub const COMPRESSED_SRGB_ALPHA_PVRTC_4BPPV1_EXT: u32 = 0x8A57;
+    pub const COMPRESSED_SRGB_ALPHA_PVRTC_2BPPV2_IMG: u32 = 0x93F0;
+    pub const COMPRESSED_SRGB_ALPHA_PVRTC_4BPPV2_IMG: u32 = 0x93F1;
+    pub const COMPRESSED_RGBA_ASTC_4x4_KHR: u32 = 0x93B0;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR: u32 = 0x93D0;
+    pub const COMPRESSED_RGBA_ASTC_5x4_KHR: u32 = 0x93B1;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR: u32 = 0x93D1;
+    pub const COMPRESSED_RGBA_ASTC_5x5_KHR: u32 = 0x93B2;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR: u32 = 0x93D2;
+    pub const COMPRESSED_RGBA_ASTC_6x5_KHR: u32 = 0x93B3;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR: u32 = 0x93D3;
+    pub const COMPRESSED_RGBA_ASTC_6x6_KHR: u32 = 0x93B4;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR: u32 = 0x93D4;
+    pub const COMPRESSED_RGBA_ASTC_8x5_KHR: u32 = 0x93B5;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR: u32 = 0x93D5;
+    pub const COMPRESSED_RGBA_ASTC_8x6_KHR: u32 = 0x93B6;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR: u32 = 0x93D6;
+    pub const COMPRESSED_RGBA_ASTC_8x8_KHR: u32 = 0x93B7;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR: u32 = 0x93D7;
+    pub const COMPRESSED_RGBA_ASTC_10x5_KHR: u32 = 0x93B8;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR: u32 = 0x93D8;
+    pub const COMPRESSED_RGBA_ASTC_10x6_KHR: u32 = 0x93B9;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR: u32 = 0x93D9;
+    pub const COMPRESSED_RGBA_ASTC_10x8_KHR: u32 = 0x93BA;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR: u32 = 0x93DA;
+    pub const COMPRESSED_RGBA_ASTC_10x10_KHR: u32 = 0x93BB;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR: u32 = 0x93DB;
+    pub const COMPRESSED_RGBA_ASTC_12x10_KHR: u32 = 0x93BC;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR: u32 = 0x93DC;
+    pub const COMPRESSED_RGBA_ASTC_12x12_KHR: u32 = 0x93BD;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR: u32 = 0x93DD;
+}
+
+/// `DXGI_FORMAT` values, without the `DXGI_FORMAT_` prefix.
+pub mod dxgi {
+    pub const R32G32B32A32_FLOAT: u32 = 2;
+    pub const R32G32B32A32_UINT: u32 = 3;
+    pub const R32G32B32A32_SINT: u32 = 4;
+    pub const R32G32B32_FLOAT: u32 = 6;
+    pub const R32G32B32_UINT: u32 = 7;
+    pub const R32G32B32_SINT: u32 = 8;
+    pub const R16G16B16A16_FLOAT: u32 = 10;
+    pub const R16G16B16A16_UNORM: u32 = 11;
+    pub const R16G16B16A16_UINT: u32 = 12;
+    pub const R16G16B16A16_SNORM: u32 = 13;
+    pub const R16G16B16A16_SINT: u32 = 14;
+    pub const R32G32_FLOAT: u32 = 16;
+    pub const R32G32_UINT: u32 = 17;
+    pub const R32G32_SINT: u32 = 18;
+    pub const D32_FLOAT_S8X24_UINT: u32 = 20;
+    pub const R10G10B10A2_UNORM: u32 = 24;
+    pub const R10G10B10A2_UINT: u32 = 25;
+    pub const R11G11B10_FLOAT: u32 = 26;
+    pub const R8G8B8A8_UNORM: u32 = 28;
+    pub const R8G8B8A8_UNORM_SRGB: u32 = 29;
+    pub const R8G8B8A8_UINT: u32 = 30;
+    pub const R8G8B8A8_SNORM: u32 = 31;
+    pub const R8G8B8A8_SINT: u32 = 32;
+    pub const R16G16_FLOAT: u32 = 34;
+    pub const R16G16_UNORM: u32 = 35;
+    pub const R16G16_UINT: u32 = 36;
+    pub const R16G16_SNORM: u32 = 37;
+    pub const R16G16_SINT: u32 = 38;
+    pub const D32_FLOAT: u32 = 40;
+    pub const R32_FLOAT: u32 = 41;
+    pub const R32_UINT: u32 = 42;
+    pub const R32_SINT: u32 = 43;
+    pub const D24_UNORM_S8_UINT: u32 = 45;
+    pub const R8G8_UNORM: u32 = 49;
+    pub const R8G8_UINT: u32 = 50;
+    pub const R8G8_SNORM: u32 = 51;
+    pub const R8G8_SINT: u32 = 52;
+    pub const R16_FLOAT: u32 = 54;
+    pub const D16_UNORM: u32 = 55;
+    pub const R16_UNORM: u32 = 56;
+    pub const R16_UINT: u32 = 57;
+    pub const R16_SNORM: u32 = 58;
+    pub const R16_SINT: u32 = 59;
+    pub const R8_UNORM: u32 = 61;
+    pub const R8_UINT: u32 = 62;
+    pub const R8_SNORM: u32 = 63;
+    pub const R8_SINT: u32 = 64;
+    pub const A8_UNORM: u32 = 65;
+    pub const R9G9B9E5_SHAREDEXP: u32 = 67;
+    pub const BC1_UNORM: u32 = 71;
+    pub const BC1_UNORM_SRGB: u32 = 72;
+    pub const BC2_UNORM: u32 = 74;
+    pub const BC2_UNORM_SRGB: u32 = 75;
+    pub const BC3_UNORM: u32 = 77;
+    pub const BC3_UNORM_SRGB: u32 = 78;
+    pub const BC4_UNORM: u32 = 80;
+    pub const BC4_SNORM: u32 = 81;
+    pub const BC5_UNORM: u32 = 83;
+    pub const BC5_SNORM: u32 = 84;
+    pub const B5G6R5_UNORM: u32 = 85;
+    pub const B5G5R5A1_UNORM: u32 = 86;
+    pub const B8G8R8A8_UNORM: u32 = 87;
+    pub const B8G8R8A8_UNORM_SRGB: u32 = 91;
+    pub const BC6H_UF16: u32 = 95;
+    pub const BC6H_SF16: u32 = 96;
+    pub const BC7_UNORM: u32 = 98;
+    pub const BC7_UNORM_SRGB: u32 = 99;
+    pub const YUY2: u32 = 107;
+    pub const Y210: u32 = 108;
+    pub const Y216: u32 = 109;
+    pub const B4G4R4A4_UNORM: u32 = 115;
+}
+
+#[rustfmt::skip]
+static GL: &[(VkFormat, GlFormat)] = &[
+    (VkFormat_VK_FORMAT_R8_UNORM, GlFormat::new(gl::R8, gl::RED, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8_SNORM, GlFormat::new(gl::R8_SNORM, gl::RED, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8_UINT, GlFormat::new(gl::R8UI, gl::RED_INTEGER, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8_SINT, GlFormat::new(gl::R8I, gl::RED_INTEGER, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8_SRGB, GlFormat::new(gl::SR8_EXT, gl::RED, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8_UNORM, GlFormat::new(gl::RG8, gl::RG, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8_SNORM, GlFormat::new(gl::RG8_SNORM, gl::RG, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8G8_UINT, GlFormat::new(gl::RG8UI, gl::RG_INTEGER, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8_SINT, GlFormat::new(gl::RG8I, gl::RG_INTEGER, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8G8_SRGB, GlFormat::new(gl::SRG8_EXT, gl::RG, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8_UNORM, GlFormat::new(gl::RGB8, gl::RGB, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8_SNORM, GlFormat::new(gl::RGB8_SNORM, gl::RGB, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8_UINT, GlFormat::new(gl::RGB8UI, gl::RGB_INTEGER, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8_SINT, GlFormat::new(gl::RGB8I, gl::RGB_INTEGER, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8_SRGB, GlFormat::new(gl::SRGB8, gl::RGB, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8_UNORM, GlFormat::new(gl::RGB8, gl::BGR, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8_SNORM, GlFormat::new(gl::RGB8_SNORM, gl::BGR, gl::BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8_UINT, GlFormat::new(gl::RGB8UI, gl::BGR_INTEGER, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8_SINT, GlFormat::new(gl::RGB8I, gl::BGR_INTEGER, gl::BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8_SRGB, GlFormat::new(gl::SRGB8, gl::BGR, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8A8_UNORM, GlFormat::new(gl::RGBA8, gl::RGBA, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8A8_SNORM, GlFormat::new(gl::RGBA8_SNORM, gl::RGBA, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8A8_UINT, GlFormat::new(gl::RGBA8UI, gl::RGBA_INTEGER, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8A8_SINT, GlFormat::new(gl::RGBA8I, gl::RGBA_INTEGER, gl::BYTE)),
+    (VkFormat_VK_FORMAT_R8G8B8A8_SRGB, GlFormat::new(gl::SRGB8_ALPHA8, gl::RGBA, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8A8_UNORM, GlFormat::new(gl::RGBA8, gl::BGRA, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8A8_SNORM, GlFormat::new(gl::RGBA8_SNORM, gl::BGRA, gl::BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8A8_UINT, GlFormat::new(gl::RGBA8UI, gl::BGRA_INTEGER, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8A8_SINT, GlFormat::new(gl::RGBA8I, gl::BGRA_INTEGER, gl::BYTE)),
+    (VkFormat_VK_FORMAT_B8G8R8A8_SRGB, GlFormat::new(gl::SRGB8_ALPHA8, gl::BGRA, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_R16_UNORM, GlFormat::new(gl::R16, gl::RED, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16_SNORM, GlFormat::new(gl::R16_SNORM, gl::RED, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16_UINT, GlFormat::new(gl::R16UI, gl::RED_INTEGER, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16_SINT, GlFormat::new(gl::R16I, gl::RED_INTEGER, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16_SFLOAT, GlFormat::new(gl::R16F, gl::RED, gl::HALF_FLOAT)),
+    (VkFormat_VK_FORMAT_R16G16_UNORM, GlFormat::new(gl::RG16, gl::RG, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16G16_SNORM, GlFormat::new(gl::RG16_SNORM, gl::RG, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16G16_UINT, GlFormat::new(gl::RG16UI, gl::RG_INTEGER, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16G16_SINT, GlFormat::new(gl::RG16I, gl::RG_INTEGER, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16G16_SFLOAT, GlFormat::new(gl::RG16F, gl::RG, gl::HALF_FLOAT)),
+    (VkFormat_VK_FORMAT_R16G16B16_UNORM, GlFormat::new(gl::RGB16, gl::RGB, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16_SNORM, GlFormat::new(gl::RGB16_SNORM, gl::RGB, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16_UINT, GlFormat::new(gl::RGB16UI, gl::RGB_INTEGER, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16_SINT, GlFormat::new(gl::RGB16I, gl::RGB_INTEGER, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16_SFLOAT, GlFormat::new(gl::RGB16F, gl::RGB, gl::HALF_FLOAT)),
+    (VkFormat_VK_FORMAT_R16G16B16A16_UNORM, GlFormat::new(gl::RGBA16, gl::RGBA, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16A16_SNORM, GlFormat::new(gl::RGBA16_SNORM, gl::RGBA, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16A16_UINT, GlFormat::new(gl::RGBA16UI, gl::RGBA_INTEGER, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16A16_SINT, GlFormat::new(gl::RGBA16I, gl::RGBA_INTEGER, gl::SHORT)),
+    (VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT, GlFormat::new(gl::RGBA16F, gl::RGBA, gl::HALF_FLOAT)),
+    (VkFormat_VK_FORMAT_R32_UINT, GlFormat::new(gl::R32UI, gl::RED_INTEGER, gl::UNSIGNED_INT)),
+    (VkFormat_VK_FORMAT_R32_SINT, GlFormat::new(gl::R32I, gl::RED_INTEGER, gl::INT)),
+    (VkFormat_VK_FORMAT_R32_SFLOAT, GlFormat::new(gl::R32F, gl::RED, gl::FLOAT)),
+    (VkFormat_VK_FORMAT_R32G32_UINT, GlFormat::new(gl::RG32UI, gl::RG_INTEGER, gl::UNSIGNED_INT)),
+    (VkFormat_VK_FORMAT_R32G32_SINT, GlFormat::new(gl::RG32I, gl::RG_INTEGER, gl::INT)),
+    (VkFormat_VK_FORMAT_R32G32_SFLOAT, GlFormat::new(gl::RG32F, gl::RG, gl::FLOAT)),
+    (VkFormat_VK_FORMAT_R32G32B32_UINT, GlFormat::new(gl::RGB32UI, gl::RGB_INTEGER, gl::UNSIGNED_INT)),
+    (VkFormat_VK_FORMAT_R32G32B32_SINT, GlFormat::new(gl::RGB32I, gl::RGB_INTEGER, gl::INT)),
+    (VkFormat_VK_FORMAT_R32G32B32_SFLOAT, GlFormat::new(gl::RGB32F, gl::RGB, gl::FLOAT)),
+    (VkFormat_VK_FORMAT_R32G32B32A32_UINT, GlFormat::new(gl::RGBA32UI, gl::RGBA_INTEGER, gl::UNSIGNED_INT)),
+    (VkFormat_VK_FORMAT_R32G32B32A32_SINT, GlFormat::new(gl::RGBA32I, gl::RGBA_INTEGER, gl::INT)),
+    (VkFormat_VK_FORMAT_R32G32B32A32_SFLOAT, GlFormat::new(gl::RGBA32F, gl::RGBA, gl::FLOAT)),
+    (VkFormat_VK_FORMAT_R4G4B4A4_UNORM_PACK16, GlFormat::new(gl::RGBA4, gl::RGBA, gl::UNSIGNED_SHORT_4_4_4_4)),
+    (VkFormat_VK_FORMAT_B4G4R4A4_UNORM_PACK16, GlFormat::new(gl::RGBA4, gl::BGRA, gl::UNSIGNED_SHORT_4_4_4_4)),
+    (VkFormat_VK_FORMAT_A4B4G4R4_UNORM_PACK16, GlFormat::new(gl::RGBA4, gl::RGBA, gl::UNSIGNED_SHORT_4_4_4_4_REV)),
+    (VkFormat_VK_FORMAT_A4R4G4B4_UNORM_PACK16, GlFormat::new(gl::RGBA4, gl::BGRA, gl::UNSIGNED_SHORT_4_4_4_4_REV)),
+    (VkFormat_VK_FORMAT_R5G6B5_UNORM_PACK16, GlFormat::new(gl::RGB565, gl::RGB, gl::UNSIGNED_SHORT_5_6_5)),
+    (VkFormat_VK_FORMAT_B5G6R5_UNORM_PACK16, GlFormat::new(gl::RGB565, gl::BGR, gl::UNSIGNED_SHORT_5_6_5)),
+    (VkFormat_VK_FORMAT_R5G5B5A1_UNORM_PACK16, GlFormat::new(gl::RGB5_A1, gl::RGBA, gl::UNSIGNED_SHORT_5_5_5_1)),
+    (VkFormat_VK_FORMAT_B5G5R5A1_UNORM_PACK16, GlFormat::new(gl::RGB5_A1, gl::BGRA, gl::UNSIGNED_SHORT_5_5_5_1)),
+    (VK_FORMAT_A1B5G5R5_UNORM_PACK16_KHR, GlFormat::new(gl::RGB5_A1, gl::RGBA, gl::UNSIGNED_SHORT_1_5_5_5_REV)),
+    (VkFormat_VK_FORMAT_A1R5G5B5_UNORM_PACK16, GlFormat::new(gl::RGB5_A1, gl::BGRA, gl::UNSIGNED_SHORT_1_5_5_5_REV)),
+    (VkFormat_VK_FORMAT_A2B10G10R10_UNORM_PACK32, GlFormat::new(gl::RGB10_A2, gl::RGBA, gl::UNSIGNED_INT_2_10_10_10_REV)),
+    (VkFormat_VK_FORMAT_A2R10G10B10_UNORM_PACK32, GlFormat::new(gl::RGB10_A2, gl::BGRA, gl::UNSIGNED_INT_2_10_10_10_REV)),
+    (VkFormat_VK_FORMAT_A2B10G10R10_UINT_PACK32, GlFormat::new(gl::RGB10_A2UI, gl::RGBA_INTEGER, gl::UNSIGNED_INT_2_10_10_10_REV)),
+    (VkFormat_VK_FORMAT_A2R10G10B10_UINT_PACK32, GlFormat::new(gl::RGB10_A2UI, gl::BGRA_INTEGER, gl::UNSIGNED_INT_2_10_10_10_REV)),
+    (VkFormat_VK_FORMAT_B10G11R11_UFLOAT_PACK32, GlFormat::new(gl::R11F_G11F_B10F, gl::RGB, gl::UNSIGNED_INT_10F_11F_11F_REV)),
+    (VkFormat_VK_FORMAT_E5B9G9R9_UFLOAT_PACK32, GlFormat::new(gl::RGB9_E5, gl::RGB, gl::UNSIGNED_INT_5_9_9_9_REV)),
+    (VK_FORMAT_A8_UNORM_KHR, GlFormat::new(gl::ALPHA8_EXT, gl::ALPHA, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_D16_UNORM, GlFormat::new(gl::DEPTH_COMPONENT16, gl::DEPTH_COMPONENT, gl::UNSIGNED_SHORT)),
+    (VkFormat_VK_FORMAT_X8_D24_UNORM_PACK32, GlFormat::new(gl::DEPTH_COMPONENT24, gl::DEPTH_COMPONENT, gl::UNSIGNED_INT)),
+    (VkFormat_VK_FORMAT_D32_SFLOAT, GlFormat::new(gl::DEPTH_COMPONENT32F, gl::DEPTH_COMPONENT, gl::FLOAT)),
+    (VkFormat_VK_FORMAT_S8_UINT, GlFormat::new(gl::STENCIL_INDEX8, gl::STENCIL_INDEX, gl::UNSIGNED_BYTE)),
+    (VkFormat_VK_FORMAT_D24_UNORM_S8_UINT, GlFormat::new(gl::DEPTH24_STENCIL8, gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8)),
+    (VkFormat_VK_FORMAT_D32_SFLOAT_S8_UINT, GlFormat::new(gl::DEPTH32F_STENCIL8, gl::DEPTH_STENCIL, gl::FLOAT_32_UNSIGNED_INT_24_8_REV)),
+    (VkFormat_VK_FORMAT_BC1_RGB_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGB_S3TC_DXT1_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC1_RGB_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB_S3TC_DXT1_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC1_RGBA_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_S3TC_DXT1_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC1_RGBA_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC2_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_S3TC_DXT3_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC2_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC3_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_S3TC_DXT5_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC3_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC4_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RED_RGTC1, 0, 0)),
+    (VkFormat_VK_FORMAT_BC4_SNORM_BLOCK, GlFormat::new(gl::COMPRESSED_SIGNED_RED_RGTC1, 0, 0)),
+    (VkFormat_VK_FORMAT_BC5_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RG_RGTC2, 0, 0)),
+    (VkFormat_VK_FORMAT_BC5_SNORM_BLOCK, GlFormat::new(gl::COMPRESSED_SIGNED_RG_RGTC2, 0, 0)),
+    (VkFormat_VK_FORMAT_BC6H_UFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC6H_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGB_BPTC_SIGNED_FLOAT, 0, 0)),
+    (VkFormat_VK_FORMAT_BC7_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_BPTC_UNORM, 0, 0)),
+    (VkFormat_VK_FORMAT_BC7_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM, 0, 0)),
+    (VkFormat_VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGB8_ETC2, 0, 0)),
+    (VkFormat_VK_FORMAT_ETC2_R8G8B8_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ETC2, 0, 0)),
+    (VkFormat_VK_FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2, 0, 0)),
+    (VkFormat_VK_FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2, 0, 0)),
+    (VkFormat_VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA8_ETC2_EAC, 0, 0)),
+    (VkFormat_VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC, 0, 0)),
+    (VkFormat_VK_FORMAT_EAC_R11_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_R11_EAC, 0, 0)),
+    (VkFormat_VK_FORMAT_EAC_R11_SNORM_BLOCK, GlFormat::new(gl::COMPRESSED_SIGNED_R11_EAC, 0, 0)),
+    (VkFormat_VK_FORMAT_EAC_R11G11_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RG11_EAC, 0, 0)),
+    (VkFormat_VK_FORMAT_EAC_R11G11_SNORM_BLOCK, GlFormat::new(gl::COMPRESSED_SIGNED_RG11_EAC, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_4x4_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_4x4_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_4x4_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_5x4_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_5x4_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_5x4_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_5x5_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_5x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_5x5_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_6x5_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_6x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_6x5_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_6x6_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_6x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_6x6_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x5_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_8x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x5_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x6_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_8x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x6_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x8_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_8x8_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x8_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x5_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x5_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x6_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x6_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x8_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x8_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x8_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x10_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x10_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x10_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_12x10_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_12x10_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_12x10_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_12x12_UNORM_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_12x12_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_12x12_SRGB_BLOCK, GlFormat::new(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_4x4_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_5x4_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_5x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_6x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_6x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_8x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_8x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_8x8_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x5_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x6_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x8_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_10x10_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_12x10_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK, GlFormat::new(gl::COMPRESSED_RGBA_ASTC_12x12_KHR, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC1_2BPP_UNORM_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_RGBA_PVRTC_2BPPV1_IMG, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC1_4BPP_UNORM_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_RGBA_PVRTC_4BPPV1_IMG, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC2_2BPP_UNORM_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_RGBA_PVRTC_2BPPV2_IMG, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC2_4BPP_UNORM_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_RGBA_PVRTC_4BPPV2_IMG, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC1_2BPP_SRGB_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_PVRTC_2BPPV1_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC1_4BPP_SRGB_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_PVRTC_4BPPV1_EXT, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC2_2BPP_SRGB_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_PVRTC_2BPPV2_IMG, 0, 0)),
+    (VkFormat_VK_FORMAT_PVRTC2_4BPP_SRGB_BLOCK_IMG, GlFormat::new(gl::COMPRESSED_SRGB_ALPHA_PVRTC_4BPPV2_IMG, 0, 0)),
+];
+
+#[rustfmt::skip]
+static DXGI: &[(VkFormat, u32)] = &[
+    (VkFormat_VK_FORMAT_R32G32B32A32_SFLOAT, dxgi::R32G32B32A32_FLOAT),
+    (VkFormat_VK_FORMAT_R32G32B32A32_UINT, dxgi::R32G32B32A32_UINT),
+    (VkFormat_VK_FORMAT_R32G32B32A32_SINT, dxgi::R32G32B32A32_SINT),
+    (VkFormat_VK_FORMAT_R32G32B32_SFLOAT, dxgi::R32G32B32_FLOAT),
+    (VkFormat_VK_FORMAT_R32G32B32_UINT, dxgi::R32G32B32_UINT),
+    (VkFormat_VK_FORMAT_R32G32B32_SINT, dxgi::R32G32B32_SINT),
+    (VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT, dxgi::R16G16B16A16_FLOAT),
+    (VkFormat_VK_FORMAT_R16G16B16A16_UNORM, dxgi::R16G16B16A16_UNORM),
+    (VkFormat_VK_FORMAT_R16G16B16A16_UINT, dxgi::R16G16B16A16_UINT),
+    (VkFormat_VK_FORMAT_R16G16B16A16_SNORM, dxgi::R16G16B16A16_SNORM),
+    (VkFormat_VK_FORMAT_R16G16B16A16_SINT, dxgi::R16G16B16A16_SINT),
+    (VkFormat_VK_FORMAT_R32G32_SFLOAT, dxgi::R32G32_FLOAT),
+    (VkFormat_VK_FORMAT_R32G32_UINT, dxgi::R32G32_UINT),
+    (VkFormat_VK_FORMAT_R32G32_SINT, dxgi::R32G32_SINT),
+    (VkFormat_VK_FORMAT_D32_SFLOAT_S8_UINT, dxgi::D32_FLOAT_S8X24_UINT),
+    (VkFormat_VK_FORMAT_A2B10G10R10_UNORM_PACK32, dxgi::R10G10B10A2_UNORM),
+    (VkFormat_VK_FORMAT_A2B10G10R10_UINT_PACK32, dxgi::R10G10B10A2_UINT),
+    (VkFormat_VK_FORMAT_B10G11R11_UFLOAT_PACK32, dxgi::R11G11B10_FLOAT),
+    (VkFormat_VK_FORMAT_R8G8B8A8_UNORM, dxgi::R8G8B8A8_UNORM),
+    (VkFormat_VK_FORMAT_R8G8B8A8_SRGB, dxgi::R8G8B8A8_UNORM_SRGB),
+    (VkFormat_VK_FORMAT_R8G8B8A8_UINT, dxgi::R8G8B8A8_UINT),
+    (VkFormat_VK_FORMAT_R8G8B8A8_SNORM, dxgi::R8G8B8A8_SNORM),
+    (VkFormat_VK_FORMAT_R8G8B8A8_SINT, dxgi::R8G8B8A8_SINT),
+    (VkFormat_VK_FORMAT_R16G16_SFLOAT, dxgi::R16G16_FLOAT),
+    (VkFormat_VK_FORMAT_R16G16_UNORM, dxgi::R16G16_UNORM),
+    (VkFormat_VK_FORMAT_R16G16_UINT, dxgi::R16G16_UINT),
+    (VkFormat_VK_FORMAT_R16G16_SNORM, dxgi::R16G16_SNORM),
+    (VkFormat_VK_FORMAT_R16G16_SINT, dxgi::R16G16_SINT),
+    (VkFormat_VK_FORMAT_D32_SFLOAT, dxgi::D32_FLOAT),
+    (VkFormat_VK_FORMAT_R32_SFLOAT, dxgi::R32_FLOAT),
+    (VkFormat_VK_FORMAT_R32_UINT, dxgi::R32_UINT),
+    (VkFormat_VK_FORMAT_R32_SINT, dxgi::R32_SINT),
+    (VkFormat_VK_FORMAT_D24_UNORM_S8_UINT, dxgi::D24_UNORM_S8_UINT),
+    (VkFormat_VK_FORMAT_R8G8_UNORM, dxgi::R8G8_UNORM),
+    (VkFormat_VK_FORMAT_R8G8_UINT, dxgi::R8G8_UINT),
+    (VkFormat_VK_FORMAT_R8G8_SNORM, dxgi::R8G8_SNORM),
+    (VkFormat_VK_FORMAT_R8G8_SINT, dxgi::R8G8_SINT),
+    (VkFormat_VK_FORMAT_R16_SFLOAT, dxgi::R16_FLOAT),
+    (VkFormat_VK_FORMAT_D16_UNORM, dxgi::D16_UNORM),
+    (VkFormat_VK_FORMAT_R16_UNORM, dxgi::R16_UNORM),
+    (VkFormat_VK_FORMAT_R16_UINT, dxgi::R16_UINT),
+    (VkFormat_VK_FORMAT_R16_SNORM, dxgi::R16_SNORM),
+    (VkFormat_VK_FORMAT_R16_SINT, dxgi::R16_SINT),
+    (VkFormat_VK_FORMAT_R8_UNORM, dxgi::R8_UNORM),
+    (VkFormat_VK_FORMAT_R8_UINT, dxgi::R8_UINT),
+    (VkFormat_VK_FORMAT_R8_SNORM, dxgi::R8_SNORM),
+    (VkFormat_VK_FORMAT_R8_SINT, dxgi::R8_SINT),
+    (VK_FORMAT_A8_UNORM_KHR, dxgi::A8_UNORM),
+    (VkFormat_VK_FORMAT_E5B9G9R9_UFLOAT_PACK32, dxgi::R9G9B9E5_SHAREDEXP),
+    (VkFormat_VK_FORMAT_BC1_RGBA_UNORM_BLOCK, dxgi::BC1_UNORM),
+    (VkFormat_VK_FORMAT_BC1_RGBA_SRGB_BLOCK, dxgi::BC1_UNORM_SRGB),
+    (VkFormat_VK_FORMAT_BC1_RGB_UNORM_BLOCK, dxgi::BC1_UNORM),
+    (VkFormat_VK_FORMAT_BC1_RGB_SRGB_BLOCK, dxgi::BC1_UNORM_SRGB),
+    (VkFormat_VK_FORMAT_BC2_UNORM_BLOCK, dxgi::BC2_UNORM),
+    (VkFormat_VK_FORMAT_BC2_SRGB_BLOCK, dxgi::BC2_UNORM_SRGB),
+    (VkFormat_VK_FORMAT_BC3_UNORM_BLOCK, dxgi::BC3_UNORM),
+    (VkFormat_VK_FORMAT_BC3_SRGB_BLOCK, dxgi::BC3_UNORM_SRGB),
+    (VkFormat_VK_FORMAT_BC4_UNORM_BLOCK, dxgi::BC4_UNORM),
+    (VkFormat_VK_FORMAT_BC4_SNORM_BLOCK, dxgi::BC4_SNORM),
+    (VkFormat_VK_FORMAT_BC5_UNORM_BLOCK, dxgi::BC5_UNORM),
+    (VkFormat_VK_FORMAT_BC5_SNORM_BLOCK, dxgi::BC5_SNORM),
+    (VkFormat_VK_FORMAT_R5G6B5_UNORM_PACK16, dxgi::B5G6R5_UNORM),
+    (VkFormat_VK_FORMAT_A1R5G5B5_UNORM_PACK16, dxgi::B5G5R5A1_UNORM),
+    (VkFormat_VK_FORMAT_B8G8R8A8_UNORM, dxgi::B8G8R8A8_UNORM),
+    (VkFormat_VK_FORMAT_B8G8R8A8_SRGB, dxgi::B8G8R8A8_UNORM_SRGB),
+    (VkFormat_VK_FORMAT_BC6H_UFLOAT_BLOCK, dxgi::BC6H_UF16),
+    (VkFormat_VK_FORMAT_BC6H_SFLOAT_BLOCK, dxgi::BC6H_SF16),
+    (VkFormat_VK_FORMAT_BC7_UNORM_BLOCK, dxgi::BC7_UNORM),
+    (VkFormat_VK_FORMAT_BC7_SRGB_BLOCK, dxgi::BC7_UNORM_SRGB),
+    (VkFormat_VK_FORMAT_G8B8G8R8_422_UNORM, dxgi::YUY2),
+    (VkFormat_VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16, dxgi::Y210),
+    (VkFormat_VK_FORMAT_G16B16G16R16_422_UNORM, dxgi::Y216),
+    (VkFormat_VK_FORMAT_A4R4G4B4_UNORM_PACK16, dxgi::B4G4R4A4_UNORM),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{format::known_to_libktx, TextureBuilder};
+
+    #[test]
+    fn reverse_lookups_agree() {
+        for &(vk_format, gl) in GL {
+            let reverse = gl.to_vk().and_then(GlFormat::from_vk);
+            assert_eq!(reverse.map(|gl| gl.internalformat), Some(gl.internalformat));
+            assert_eq!(GlFormat::from_vk(vk_format), Some(gl));
+        }
+        for &(vk_format, dxgi) in DXGI {
+            assert_eq!(dxgi_to_vk(dxgi).and_then(vk_to_dxgi), Some(dxgi));
+            assert_eq!(vk_to_dxgi(vk_format), Some(dxgi));
+        }
+    }
+
+    #[test]
+    fn gl_matches_libktx() {
+        for &(vk_format, gl) in GL
+            .iter()
+            .filter(|&&(vk_format, _)| known_to_libktx(vk_format))
+        {
+            let texture = TextureBuilder::new()
+                .vk_format(vk_format)
+                .extent_2d(8, 8)
+                .build_ktx1()
+                .unwrap_or_else(|error| panic!("VkFormat {vk_format}: {error}"));
+            assert_eq!(texture.gl_internalformat(), gl.internalformat);
+            // libktx maps back by glInternalformat alone, so formats sharing one,
+            // such as B8G8R8 and R8G8B8, all come back as the first in the table.
+            #[cfg(feature = "vulkan")]
+            {
+                let first = GL
+                    .iter()
+                    .find(|(_, other)| other.internalformat == gl.internalformat)
+                    .map(|&(vk_format, _)| vk_format);
+                assert_eq!(Some(texture.vk_format()), first, "VkFormat {vk_format}");
+            }
+        }
+    }
+}
@@ -0,0 +1,755 @@
+//! Key/value metadata stored in a `ktxHashList`.
+
+use std::{
+    borrow::Borrow,
+    ffi::{c_void, CStr, CString},
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    os::raw::{c_char, c_uchar, c_uint},
+    ptr, slice,
+    str::FromStr,
+};
+
+use crate::{error::check, ffi::*, KtxError};
+
+extern "C" {
+    // Releases the buffer allocated by `ktxHashList_Serialize`.
+    fn free(ptr: *mut c_void);
+}
+
+/// A view of a `ktxHashList`, such as the `kvDataHead` of a texture.
+///
+/// Entries are kept sorted by key, the order libktx expects when writing.
+#[repr(transparent)]
+pub struct Metadata {
+    head: ktxHashList,
+}
+
+impl Metadata {
+    /// Views the list pointed at by `head`.
+    ///
+    /// # Safety
+    /// `head` must point to a valid list that outlives `'a` and is not accessed
+    /// through other pointers in the meantime.
+    pub unsafe fn from_raw<'a>(head: *mut ktxHashList) -> &'a mut Self {
+        &mut *head.cast()
+    }
+
+    pub fn as_raw(&self) -> *mut ktxHashList {
+        &self.head as *const ktxHashList as *mut ktxHashList
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_null()
+    }
+
+    pub fn iter(&self) -> MetadataIter<'_> {
+        MetadataIter {
+            entry: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Value stored under `key`, if any.
+    pub fn get(&self, key: &str) -> Option<&[u8]> {
+        let key = CString::new(key).ok()?;
+        let mut len: c_uint = 0;
+        let mut value = ptr::null_mut();
+        let code =
+            unsafe { ktxHashList_FindValue(self.as_raw(), key.as_ptr(), &mut len, &mut value) };
+        check(code).ok()?;
+        Some(unsafe { bytes(value.cast(), len) })
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Stores `value` under `key`, replacing any previous value.
+    pub fn insert(&mut self, key: &str, value: &[u8]) -> Result<(), KtxError> {
+        let key = CString::new(key).map_err(|_| KtxError::InvalidValue)?;
+        let len = c_uint::try_from(value.len()).map_err(|_| KtxError::InvalidValue)?;
+        self.remove_c(&key)?;
+        check(unsafe {
+            ktxHashList_AddKVPair(self.as_raw(), key.as_ptr(), len, value.as_ptr().cast())
+        })?;
+        check(unsafe { ktxHashList_Sort(self.as_raw()) })
+    }
+
+    /// Removes the entry under `key`, returning whether there was one.
+    pub fn remove(&mut self, key: &str) -> Result<bool, KtxError> {
+        let key = CString::new(key).map_err(|_| KtxError::InvalidValue)?;
+        self.remove_c(&key)
+    }
+
+    fn remove_c(&mut self, key: &CStr) -> Result<bool, KtxError> {
+        let mut len: c_uint = 0;
+        let mut value = ptr::null_mut();
+        let code =
+            unsafe { ktxHashList_FindValue(self.as_raw(), key.as_ptr(), &mut len, &mut value) };
+        if code == ktx_error_code_e_KTX_NOT_FOUND {
+            return Ok(false);
+        }
+        check(code)?;
+        check(unsafe { ktxHashList_DeleteKVPair(self.as_raw(), key.as_ptr()) })?;
+        Ok(true)
+    }
+
+    /// Sorts the entries by key. [`Metadata::insert`] already does this.
+    pub fn sort(&mut self) -> Result<(), KtxError> {
+        check(unsafe { ktxHashList_Sort(self.as_raw()) })
+    }
+
+    /// Serializes the entries into the key/value data block of a KTX file.
+    pub fn serialize(&self) -> Result<Vec<u8>, KtxError> {
+        let mut len: c_uint = 0;
+        let mut data: *mut c_uchar = ptr::null_mut();
+        check(unsafe { ktxHashList_Serialize(self.as_raw(), &mut len, &mut data) })?;
+        let serialized = unsafe { bytes(data, len) }.to_vec();
+        unsafe { free(data.cast()) };
+        Ok(serialized)
+    }
+}
+
+/// A standalone `ktxHashList`, destructed on drop.
+pub struct MetadataBuf {
+    metadata: Metadata,
+}
+
+impl MetadataBuf {
+    pub fn new() -> Self {
+        Self {
+            metadata: Metadata {
+                head: ptr::null_mut(),
+            },
+        }
+    }
+
+    /// Parses the key/value data block of a KTX file.
+    pub fn deserialize(data: &[u8]) -> Result<Self, KtxError> {
+        let len = c_uint::try_from(data.len()).map_err(|_| KtxError::InvalidValue)?;
+        let mut metadata = Self::new();
+        // libktx only reads from `kvd` despite taking it mutably.
+        check(unsafe {
+            ktxHashList_Deserialize(
+                &mut metadata.metadata.head,
+                len,
+                data.as_ptr() as *mut c_void,
+            )
+        })?;
+        metadata.sort()?;
+        Ok(metadata)
+    }
+}
+
+impl ToOwned for Metadata {
+    type Owned = MetadataBuf;
+
+    fn to_owned(&self) -> MetadataBuf {
+        let mut copy = MetadataBuf::new();
+        unsafe { ktxHashList_ConstructCopy(&mut copy.metadata.head, self.head) };
+        copy
+    }
+}
+
+impl Borrow<Metadata> for MetadataBuf {
+    fn borrow(&self) -> &Metadata {
+        self
+    }
+}
+
+impl Clone for MetadataBuf {
+    fn clone(&self) -> Self {
+        self.metadata.to_owned()
+    }
+}
+
+impl Default for MetadataBuf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for MetadataBuf {
+    type Target = Metadata;
+
+    fn deref(&self) -> &Metadata {
+        &self.metadata
+    }
+}
+
+impl DerefMut for MetadataBuf {
+    fn deref_mut(&mut self) -> &mut Metadata {
+        &mut self.metadata
+    }
+}
+
+impl Drop for MetadataBuf {
+    fn drop(&mut self) {
+        unsafe { ktxHashList_Destruct(&mut self.metadata.head) }
+    }
+}
+
+// The list is only ever reached through `&`/`&mut` borrows of its owner.
+unsafe impl Send for MetadataBuf {}
+
+/// Iterator over the `(key, value)` pairs of a [`Metadata`].
+pub struct MetadataIter<'a> {
+    entry: *mut ktxHashListEntry,
+    _marker: PhantomData<&'a Metadata>,
+}
+
+impl<'a> Iterator for MetadataIter<'a> {
+    type Item = (&'a CStr, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.entry.is_null() {
+            return None;
+        }
+        let entry = self.entry;
+        let mut key_len: c_uint = 0;
+        let mut key: *mut c_char = ptr::null_mut();
+        let mut value_len: c_uint = 0;
+        let mut value = ptr::null_mut();
+        unsafe {
+            ktxHashListEntry_GetKey(entry, &mut key_len, &mut key);
+            ktxHashListEntry_GetValue(entry, &mut value_len, &mut value);
+            self.entry = ktxHashList_Next(entry);
+            Some((CStr::from_ptr(key), bytes(value.cast(), value_len)))
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Metadata {
+    type Item = (&'a CStr, &'a [u8]);
+    type IntoIter = MetadataIter<'a>;
+
+    fn into_iter(self) -> MetadataIter<'a> {
+        self.iter()
+    }
+}
+
+// libktx hands out null pointers for empty values.
+unsafe fn bytes<'a>(data: *const u8, len: c_uint) -> &'a [u8] {
+    if data.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len as usize)
+    }
+}
+
+/// Typed access to the metadata keys defined by the KTX specification.
+impl Metadata {
+    /// Parses `KTXorientation`, in either the KTX2 (`rd`) or KTX1 (`S=r,T=d`) form.
+    pub fn orientation(&self) -> Result<Option<Orientation>, MetadataError> {
+        self.typed(KTX_ORIENTATION_KEY, |value| {
+            string_value(KTX_ORIENTATION_KEY, value)?.parse()
+        })
+    }
+
+    /// Stores `KTXorientation` in the KTX2 form.
+    pub fn set_orientation(&mut self, orientation: Orientation) -> Result<(), MetadataError> {
+        self.set_string(KTX_ORIENTATION_KEY, &orientation.to_string())
+    }
+
+    pub fn swizzle(&self) -> Result<Option<Swizzle>, MetadataError> {
+        self.typed(KTX_SWIZZLE_KEY, |value| {
+            string_value(KTX_SWIZZLE_KEY, value)?.parse()
+        })
+    }
+
+    pub fn set_swizzle(&mut self, swizzle: Swizzle) -> Result<(), MetadataError> {
+        self.set_string(KTX_SWIZZLE_KEY, &swizzle.to_string())
+    }
+
+    pub fn anim_data(&self) -> Result<Option<AnimData>, MetadataError> {
+        self.typed(KTX_ANIMDATA_KEY, |value| {
+            let words: [u8; 12] = value
+                .try_into()
+                .map_err(|_| MetadataError::malformed(KTX_ANIMDATA_KEY, "expected 12 bytes"))?;
+            let word = |i: usize| u32::from_le_bytes(words[i * 4..i * 4 + 4].try_into().unwrap());
+            Ok(AnimData {
+                duration: word(0),
+                timescale: word(1),
+                loop_count: word(2),
+            })
+        })
+    }
+
+    pub fn set_anim_data(&mut self, anim_data: AnimData) -> Result<(), MetadataError> {
+        let mut value = Vec::with_capacity(12);
+        value.extend(anim_data.duration.to_le_bytes());
+        value.extend(anim_data.timescale.to_le_bytes());
+        value.extend(anim_data.loop_count.to_le_bytes());
+        Ok(self.insert(key_str(KTX_ANIMDATA_KEY), &value)?)
+    }
+
+    /// The name and version of the tool that wrote the file.
+    pub fn writer(&self) -> Result<Option<&str>, MetadataError> {
+        self.typed(KTX_WRITER_KEY, |value| string_value(KTX_WRITER_KEY, value))
+    }
+
+    pub fn set_writer(&mut self, writer: &str) -> Result<(), MetadataError> {
+        self.set_string(KTX_WRITER_KEY, writer)
+    }
+
+    /// The supercompression options the writer was invoked with.
+    pub fn writer_sc_params(&self) -> Result<Option<&str>, MetadataError> {
+        self.typed(KTX_WRITER_SCPARAMS_KEY, |value| {
+            string_value(KTX_WRITER_SCPARAMS_KEY, value)
+        })
+    }
+
+    pub fn set_writer_sc_params(&mut self, params: &str) -> Result<(), MetadataError> {
+        self.set_string(KTX_WRITER_SCPARAMS_KEY, params)
+    }
+
+    fn typed<'a, T>(
+        &'a self,
+        key: &'static [u8],
+        parse: impl FnOnce(&'a [u8]) -> Result<T, MetadataError>,
+    ) -> Result<Option<T>, MetadataError> {
+        self.get(key_str(key)).map(parse).transpose()
+    }
+
+    // String values are stored NUL-terminated.
+    fn set_string(&mut self, key: &'static [u8], value: &str) -> Result<(), MetadataError> {
+        if value.contains('\0') {
+            return Err(MetadataError::malformed(key, "contains a NUL character"));
+        }
+        let mut bytes = Vec::with_capacity(value.len() + 1);
+        bytes.extend_from_slice(value.as_bytes());
+        bytes.push(0);
+        Ok(self.insert(key_str(key), &bytes)?)
+    }
+}
+
+/// The logical orientation of a texture's axes, as stored in `KTXorientation`.
+///
+/// `y` and `z` are only present for textures with that many dimensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub x: ktxOrientationX,
+    pub y: Option<ktxOrientationY>,
+    pub z: Option<ktxOrientationZ>,
+}
+
+impl Orientation {
+    /// Formats the orientation the way KTX1 files store it, e.g. `S=r,T=d`.
+    pub fn to_ktx1_string(&self) -> String {
+        let mut out = format!("S={}", char::from(self.x as u8));
+        if let Some(y) = self.y {
+            out.push_str(&format!(",T={}", char::from(y as u8)));
+        }
+        if let Some(z) = self.z {
+            out.push_str(&format!(",R={}", char::from(z as u8)));
+        }
+        out
+    }
+}
+
+impl From<Orientation> for ktxOrientation {
+    /// Missing axes take the defaults libktx uses, down and out.
+    fn from(orientation: Orientation) -> Self {
+        ktxOrientation {
+            x: orientation.x,
+            y: orientation.y.unwrap_or(ktxOrientationY_KTX_ORIENT_Y_DOWN),
+            z: orientation.z.unwrap_or(ktxOrientationZ_KTX_ORIENT_Z_OUT),
+        }
+    }
+}
+
+impl FromStr for Orientation {
+    type Err = MetadataError;
+
+    fn from_str(s: &str) -> Result<Self, MetadataError> {
+        let malformed = || {
+            MetadataError::malformed(
+                KTX_ORIENTATION_KEY,
+                "expected 1 to 3 of [rl], [du], [oi] in order, optionally as S=_,T=_,R=_",
+            )
+        };
+        let axes: Vec<u8> = if s.starts_with("S=") {
+            s.split(',')
+                .zip(["S=", "T=", "R="])
+                .map(
+                    |(axis, prefix)| match axis.strip_prefix(prefix).map(str::as_bytes) {
+                        Some(&[c]) => Ok(c),
+                        _ => Err(malformed()),
+                    },
+                )
+                .collect::<Result<_, _>>()?
+        } else {
+            s.bytes().collect()
+        };
+        if s.split(',').count() > 3 {
+            return Err(malformed());
+        }
+        let axis = |i: usize, options: [u32; 2]| match axes.get(i) {
+            None => Ok(None),
+            Some(&c) if options.contains(&u32::from(c)) => Ok(Some(u32::from(c))),
+            Some(_) => Err(malformed()),
+        };
+        match axes.len() {
+            1..=3 => Ok(Orientation {
+                x: axis(
+                    0,
+                    [
+                        ktxOrientationX_KTX_ORIENT_X_RIGHT,
+                        ktxOrientationX_KTX_ORIENT_X_LEFT,
+                    ],
+                )?
+                .unwrap(),
+                y: axis(
+                    1,
+                    [
+                        ktxOrientationY_KTX_ORIENT_Y_DOWN,
+                        ktxOrientationY_KTX_ORIENT_Y_UP,
+                    ],
+                )?,
+                z: axis(
+                    2,
+                    [
+                        ktxOrientationZ_KTX_ORIENT_Z_OUT,
+                        ktxOrientationZ_KTX_ORIENT_Z_IN,
+                    ],
+                )?,
+            }),
+            _ => Err(malformed()),
+        }
+    }
+}
+
+/// The KTX2 form, e.g. `rd`.
+impl fmt::Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for axis in [Some(self.x), self.y, self.z].into_iter().flatten() {
+            write!(f, "{}", char::from(axis as u8))?;
+        }
+        Ok(())
+    }
+}
+
+/// The source of one output component in `KTXswizzle`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwizzleComponent {
+    R,
+    G,
+    B,
+    A,
+    Zero,
+    One,
+}
+
+impl SwizzleComponent {
+    fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            'r' => Self::R,
+            'g' => Self::G,
+            'b' => Self::B,
+            'a' => Self::A,
+            '0' => Self::Zero,
+            '1' => Self::One,
+            _ => return None,
+        })
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Self::R => 'r',
+            Self::G => 'g',
+            Self::B => 'b',
+            Self::A => 'a',
+            Self::Zero => '0',
+            Self::One => '1',
+        }
+    }
+}
+
+/// Component mapping for the R, G, B and A outputs, as stored in `KTXswizzle`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Swizzle(pub [SwizzleComponent; 4]);
+
+impl FromStr for Swizzle {
+    type Err = MetadataError;
+
+    fn from_str(s: &str) -> Result<Self, MetadataError> {
+        let malformed =
+            || MetadataError::malformed(KTX_SWIZZLE_KEY, "expected 4 characters from [rgba01]");
+        let components: Vec<SwizzleComponent> = s
+            .chars()
+            .map(|c| SwizzleComponent::from_char(c).ok_or_else(malformed))
+            .collect::<Result<_, _>>()?;
+        Ok(Swizzle(components.try_into().map_err(|_| malformed())?))
+    }
+}
+
+impl fmt::Display for Swizzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|c| write!(f, "{}", c.to_char()))
+    }
+}
+
+/// Playback parameters of an animated texture, as stored in `KTXanimData`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnimData {
+    /// Duration of each frame, in units of `timescale`.
+    pub duration: u32,
+    /// Number of time units per second.
+    pub timescale: u32,
+    /// Number of times to play the animation, 0 for infinitely.
+    pub loop_count: u32,
+}
+
+/// Failure to read or write a typed metadata value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MetadataError {
+    /// The value doesn't follow the format the KTX specification gives `key`.
+    Malformed {
+        key: &'static str,
+        reason: &'static str,
+    },
+    Ktx(KtxError),
+}
+
+impl MetadataError {
+    fn malformed(key: &'static [u8], reason: &'static str) -> Self {
+        MetadataError::Malformed {
+            key: key_str(key),
+            reason,
+        }
+    }
+}
+
+impl From<KtxError> for MetadataError {
+    fn from(error: KtxError) -> Self {
+        MetadataError::Ktx(error)
+    }
+}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetadataError::Malformed { key, reason } => {
+                write!(f, "malformed {key} value: {reason}")
+            }
+            MetadataError::Ktx(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for MetadataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MetadataError::Malformed { .. } => None,
+            MetadataError::Ktx(error) => Some(error),
+        }
+    }
+}
+
+// The `KTX_*_KEY` constants are NUL-terminated ASCII.
+fn key_str(key: &'static [u8]) -> &'static str {
+    std::str::from_utf8(&key[..key.len() - 1]).unwrap()
+}
+
+// Strips the NUL terminator the specification requires on string values.
+pub(crate) fn string_value<'a>(
+    key: &'static [u8],
+    value: &'a [u8],
+) -> Result<&'a str, MetadataError> {
+    let value = value
+        .strip_suffix(b"\0")
+        .ok_or_else(|| MetadataError::malformed(key, "missing NUL terminator"))?;
+    if value.contains(&0) {
+        return Err(MetadataError::malformed(key, "contains a NUL character"));
+    }
+    std::str::from_utf8(value).map_err(|_| MetadataError::malformed(key, "not valid UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BaseTexture;
+
+    fn is_malformed<T>(result: Result<T, MetadataError>, key: &[u8]) -> bool {
+        matches!(result, Err(MetadataError::Malformed { key: k, .. }) if k.as_bytes() == &key[..key.len() - 1])
+    }
+
+    fn keys(metadata: &Metadata) -> Vec<&str> {
+        metadata
+            .iter()
+            .map(|(key, _)| key.to_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn insert_replaces_and_keeps_keys_sorted() {
+        let mut metadata = MetadataBuf::new();
+        assert!(metadata.is_empty());
+        metadata.insert("b", b"1").unwrap();
+        metadata.insert("a", b"2").unwrap();
+        metadata.insert("c", b"").unwrap();
+        metadata.insert("b", b"3").unwrap();
+        assert_eq!(keys(&metadata), ["a", "b", "c"]);
+        assert_eq!(metadata.get("b"), Some(&b"3"[..]));
+        assert_eq!(metadata.get("c"), Some(&b""[..]));
+        assert_eq!(metadata.get("d"), None);
+        assert!(metadata.contains_key("a") && !metadata.contains_key("A"));
+        let pairs: Vec<_> = (&*metadata).into_iter().collect();
+        assert_eq!(pairs[0], (c"a", &b"2"[..]));
+        assert_eq!(metadata.insert("a\0b", b""), Err(KtxError::InvalidValue));
+    }
+
+    #[test]
+    fn remove_reports_whether_the_key_existed() {
+        let mut metadata = MetadataBuf::new();
+        assert_eq!(metadata.remove("a"), Ok(false));
+        metadata.insert("a", b"1").unwrap();
+        metadata.insert("b", b"2").unwrap();
+        assert_eq!(metadata.remove("a"), Ok(true));
+        assert_eq!(metadata.remove("a"), Ok(false));
+        assert_eq!(keys(&metadata), ["b"]);
+        assert_eq!(metadata.remove("b"), Ok(true));
+        assert!(metadata.is_empty());
+    }
+
+    #[test]
+    fn sort_orders_entries_added_out_of_order() {
+        let mut metadata = MetadataBuf::new();
+        for key in [c"zeta", c"KTXwriter", c"alpha"] {
+            let code = unsafe {
+                ktxHashList_AddKVPair(metadata.as_raw(), key.as_ptr(), 1, b"x".as_ptr().cast())
+            };
+            check(code).unwrap();
+        }
+        assert_eq!(keys(&metadata), ["zeta", "KTXwriter", "alpha"]);
+        metadata.sort().unwrap();
+        assert_eq!(keys(&metadata), ["KTXwriter", "alpha", "zeta"]);
+    }
+
+    #[test]
+    fn serialize_round_trips() {
+        assert_eq!(MetadataBuf::new().serialize(), Ok(Vec::new()));
+
+        let mut metadata = MetadataBuf::new();
+        metadata.insert("KTXwriter", b"metadata test\0").unwrap();
+        metadata.insert("odd", b"12345").unwrap();
+        let data = metadata.serialize().unwrap();
+        // Each entry is a 4 byte length, key, NUL and value, padded to 4 bytes.
+        assert_eq!(data.len(), (4 + 24) + (4 + 9 + 3));
+        let parsed = MetadataBuf::deserialize(&data).unwrap();
+        assert_eq!(
+            parsed.iter().collect::<Vec<_>>(),
+            metadata.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn copies_are_independent() {
+        let mut original = MetadataBuf::new();
+        original.insert("a", b"1").unwrap();
+        let mut copy = original.to_owned();
+        let mut clone = copy.clone();
+        copy.insert("a", b"2").unwrap();
+        clone.remove("a").unwrap();
+        assert_eq!(original.get("a"), Some(&b"1"[..]));
+        assert_eq!(copy.get("a"), Some(&b"2"[..]));
+        assert!(clone.is_empty());
+    }
+
+    #[test]
+    fn texture_metadata_is_written_sorted() {
+        let mut texture = crate::testing::undefined_r8().build_ktx2().unwrap();
+        let metadata = texture.metadata_mut();
+        metadata.insert("zeta", b"1\0").unwrap();
+        metadata.insert("alpha", b"2\0").unwrap();
+        metadata.set_writer("metadata test").unwrap();
+        metadata.insert("mid", b"3\0").unwrap();
+        let mut bytes = Vec::new();
+        texture.write_to(&mut bytes).unwrap();
+        let reader = crate::Ktx2Reader::new(&bytes).unwrap();
+        let keys: Vec<_> = reader
+            .key_values()
+            .map(|(key, _)| key.to_str().unwrap())
+            .collect();
+        assert_eq!(keys, ["KTXwriter", "alpha", "mid", "zeta"]);
+    }
+
+    #[test]
+    fn orientation_round_trips() {
+        for s in ["r", "ld", "rdi", "luo"] {
+            let orientation: Orientation = s.parse().unwrap();
+            assert_eq!(orientation.to_string(), s);
+            assert_eq!(orientation.to_ktx1_string().parse(), Ok(orientation));
+        }
+        let orientation: Orientation = "S=l,T=u,R=i".parse().unwrap();
+        assert_eq!(orientation.to_string(), "lui");
+        assert_eq!(orientation.to_ktx1_string(), "S=l,T=u,R=i");
+    }
+
+    #[test]
+    fn orientation_rejects_malformed() {
+        for s in [
+            "",
+            "d",
+            "rr",
+            "RD",
+            "rdox",
+            "rdo,",
+            "S=rd",
+            "S=r,T=",
+            "S=r,R=o",
+            "S=r,T=d,R=o,Q=x",
+        ] {
+            assert!(
+                is_malformed(s.parse::<Orientation>(), KTX_ORIENTATION_KEY),
+                "{s:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn swizzle_round_trips() {
+        for s in ["rgba", "bgr1", "0001", "rrrg"] {
+            let swizzle: Swizzle = s.parse().unwrap();
+            assert_eq!(swizzle.to_string(), s);
+        }
+        assert_eq!(
+            "rg01".parse(),
+            Ok(Swizzle([
+                SwizzleComponent::R,
+                SwizzleComponent::G,
+                SwizzleComponent::Zero,
+                SwizzleComponent::One,
+            ]))
+        );
+        for s in ["", "rgb", "rgbaa", "rgbx", "RGBA"] {
+            assert!(is_malformed(s.parse::<Swizzle>(), KTX_SWIZZLE_KEY), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn typed_values_round_trip() {
+        let mut metadata = MetadataBuf::new();
+        let anim_data = AnimData {
+            duration: 1,
+            timescale: 30,
+            loop_count: 0,
+        };
+        metadata.set_anim_data(anim_data).unwrap();
+        metadata.set_swizzle("rgb1".parse().unwrap()).unwrap();
+        metadata.set_orientation("rd".parse().unwrap()).unwrap();
+        assert_eq!(metadata.anim_data(), Ok(Some(anim_data)));
+        assert_eq!(metadata.swizzle().unwrap().unwrap().to_string(), "rgb1");
+        assert_eq!(metadata.get("KTXorientation"), Some(&b"rd\0"[..]));
+
+        metadata.insert("KTXanimData", &[0; 11]).unwrap();
+        assert!(is_malformed(metadata.anim_data(), KTX_ANIMDATA_KEY));
+        metadata.insert("KTXswizzle", b"rgba").unwrap();
+        assert!(is_malformed(metadata.swizzle(), KTX_SWIZZLE_KEY));
+        metadata.insert("KTXorientation", b"r\0d\0").unwrap();
+        assert!(is_malformed(metadata.orientation(), KTX_ORIENTATION_KEY));
+        assert!(is_malformed(metadata.set_writer("a\0b"), KTX_WRITER_KEY));
+    }
+}
@@ -0,0 +1,747 @@
+//! Reading the structure of KTX2 files in pure Rust, without libktx.
+//!
+//! [`Ktx2Reader`] borrows every section from the input, only depending on
+//! `core`, so listing or indexing files doesn't need the C library. This module
+//! is all that is built without the `native` feature. Image data is handed out
+//! as stored, still supercompressed if it was written that way.
+
+use core::{ffi::CStr, fmt, ops::Range};
+
+/// The 12 byte identifier every KTX2 file starts with.
+pub const KTX2_IDENTIFIER: [u8; 12] = [
+    0xab, b'K', b'T', b'X', b' ', b'2', b'0', 0xbb, b'\r', b'\n', 0x1a, b'\n',
+];
+
+pub(crate) const HEADER_SIZE: usize = 80;
+pub(crate) const LEVEL_INDEX_ENTRY_SIZE: usize = 24;
+
+/// The header of a KTX2 file, as stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Header {
+    pub vk_format: u32,
+    pub type_size: u32,
+    pub pixel_width: u32,
+    pub pixel_height: u32,
+    pub pixel_depth: u32,
+    pub layer_count: u32,
+    pub face_count: u32,
+    pub level_count: u32,
+    /// A `ktxSupercmpScheme`, e.g. `KTX_SS_ZSTD`.
+    pub supercompression_scheme: u32,
+}
+
+/// Location of the sections following the header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Index {
+    pub dfd_byte_offset: u32,
+    pub dfd_byte_length: u32,
+    pub kvd_byte_offset: u32,
+    pub kvd_byte_length: u32,
+    pub sgd_byte_offset: u64,
+    pub sgd_byte_length: u64,
+}
+
+/// Location of the images of one mip level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LevelIndex {
+    pub byte_offset: u64,
+    pub byte_length: u64,
+    pub uncompressed_byte_length: u64,
+}
+
+/// A KTX2 file parsed from memory.
+///
+/// Every offset and length is checked against the input by [`Ktx2Reader::new`],
+/// so the accessors don't fail. Derived properties follow the `ktxTexture2`
+/// fields of the same name.
+#[derive(Clone, Copy, Debug)]
+pub struct Ktx2Reader<'a> {
+    data: &'a [u8],
+    header: Header,
+    index: Index,
+}
+
+impl<'a> Ktx2Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, ReadError> {
+        if !data.starts_with(&KTX2_IDENTIFIER) {
+            return Err(if KTX2_IDENTIFIER.starts_with(data) {
+                ReadError::Truncated
+            } else {
+                ReadError::NotKtx2
+            });
+        }
+        let (header, index) = read_header(data)?;
+        let reader = Self {
+            data,
+            header,
+            index,
+        };
+        reader.validate()?;
+        Ok(reader)
+    }
+
+    fn validate(&self) -> Result<(), ReadError> {
+        let malformed = |reason| Err(ReadError::Malformed(reason));
+        let header = &self.header;
+        if header.pixel_width == 0 {
+            return malformed("pixelWidth must be non-zero");
+        }
+        if header.pixel_depth > 0 && header.pixel_height == 0 {
+            return malformed("3D textures need a pixelHeight");
+        }
+        if header.face_count != 1 && header.face_count != 6 {
+            return malformed("faceCount must be 1 or 6");
+        }
+        if header.face_count == 6
+            && (header.pixel_depth > 0 || header.pixel_width != header.pixel_height)
+        {
+            return malformed("cubemaps must be 2D and square");
+        }
+        let max_levels = 32
+            - header
+                .pixel_width
+                .max(header.pixel_height)
+                .max(header.pixel_depth)
+                .leading_zeros();
+        if header.level_count > max_levels {
+            return malformed("levelCount exceeds the number of levels down to 1x1");
+        }
+        let level_index_end = self.num_levels() as usize * LEVEL_INDEX_ENTRY_SIZE + HEADER_SIZE;
+        if level_index_end > self.data.len() {
+            return Err(ReadError::Truncated);
+        }
+        for level in self.levels() {
+            self.range(level.byte_offset, level.byte_length)?;
+        }
+        let dfd = self.range(
+            self.index.dfd_byte_offset.into(),
+            self.index.dfd_byte_length.into(),
+        )?;
+        if dfd.len() < 4 || read_u32(self.data, dfd.start) as usize != dfd.len() {
+            return malformed("dfdTotalSize must equal dfdByteLength");
+        }
+        self.range(
+            self.index.kvd_byte_offset.into(),
+            self.index.kvd_byte_length.into(),
+        )?;
+        self.range(self.index.sgd_byte_offset, self.index.sgd_byte_length)?;
+        Ok(())
+    }
+
+    fn range(&self, offset: u64, length: u64) -> Result<Range<usize>, ReadError> {
+        let end = offset.checked_add(length).ok_or(ReadError::Truncated)?;
+        if end > self.data.len() as u64 {
+            return Err(ReadError::Truncated);
+        }
+        Ok(offset as usize..end as usize)
+    }
+
+    // Only called with ranges checked by `validate`.
+    fn section(&self, offset: u64, length: u64) -> &'a [u8] {
+        &self.data[offset as usize..(offset + length) as usize]
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    pub fn vk_format(&self) -> u32 {
+        self.header.vk_format
+    }
+
+    pub fn supercompression_scheme(&self) -> u32 {
+        self.header.supercompression_scheme
+    }
+
+    pub fn base_width(&self) -> u32 {
+        self.header.pixel_width
+    }
+
+    pub fn base_height(&self) -> u32 {
+        self.header.pixel_height.max(1)
+    }
+
+    pub fn base_depth(&self) -> u32 {
+        self.header.pixel_depth.max(1)
+    }
+
+    pub fn num_dimensions(&self) -> u32 {
+        if self.header.pixel_depth > 0 {
+            3
+        } else if self.header.pixel_height > 0 {
+            2
+        } else {
+            1
+        }
+    }
+
+    pub fn num_levels(&self) -> u32 {
+        self.header.level_count.max(1)
+    }
+
+    pub fn num_layers(&self) -> u32 {
+        self.header.layer_count.max(1)
+    }
+
+    pub fn num_faces(&self) -> u32 {
+        self.header.face_count
+    }
+
+    pub fn is_array(&self) -> bool {
+        self.header.layer_count > 0
+    }
+
+    pub fn is_cubemap(&self) -> bool {
+        self.header.face_count == 6
+    }
+
+    /// A level count of 0 asks the loader to generate mipmaps.
+    pub fn generate_mipmaps(&self) -> bool {
+        self.header.level_count == 0
+    }
+
+    /// The level index, from level 0, the largest, down.
+    pub fn levels(&self) -> impl ExactSizeIterator<Item = LevelIndex> + 'a {
+        let data = self.data;
+        (0..self.num_levels() as usize).map(move |level| read_level_index(data, level))
+    }
+
+    pub fn level_index(&self, level: u32) -> Option<LevelIndex> {
+        self.levels().nth(level as usize)
+    }
+
+    /// The images of `level`, for every layer, face and depth slice.
+    pub fn level_data(&self, level: u32) -> Option<&'a [u8]> {
+        let level = self.level_index(level)?;
+        Some(self.section(level.byte_offset, level.byte_length))
+    }
+
+    /// The data format descriptor, starting with its `dfdTotalSize`, little-endian.
+    pub fn dfd(&self) -> &'a [u8] {
+        self.section(
+            self.index.dfd_byte_offset.into(),
+            self.index.dfd_byte_length.into(),
+        )
+    }
+
+    /// The basic descriptor block of [`Ktx2Reader::dfd`], read in place.
+    pub fn basic_descriptor(&self) -> Result<DescriptorView<'a>, ReadError> {
+        DescriptorView::new(&self.dfd()[4..])
+    }
+
+    /// The key/value data in file order, which the KTX2 specification requires
+    /// to be sorted by key.
+    pub fn key_values(&self) -> KeyValues<'a> {
+        KeyValues::new(self.section(
+            self.index.kvd_byte_offset.into(),
+            self.index.kvd_byte_length.into(),
+        ))
+    }
+
+    /// The supercompression global data, e.g. the BasisLZ codebooks.
+    pub fn supercompression_global_data(&self) -> &'a [u8] {
+        self.section(self.index.sgd_byte_offset, self.index.sgd_byte_length)
+    }
+}
+
+// Sizes of the basic descriptor block parts, in bytes.
+const DESCRIPTOR_HEADER_SIZE: usize = 24;
+const SAMPLE_SIZE: usize = 16;
+
+/// The basic descriptor block of a data format descriptor, borrowed from a
+/// [`Ktx2Reader`].
+///
+/// Accessors decode the fields of `dfd::BasicDescriptor` of the same name on
+/// each call. Values stored minus one in the block, such as texel block
+/// dimensions and sample bit lengths, are returned as the actual values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DescriptorView<'a> {
+    // The block up to its descriptorBlockSize, a whole number of samples long.
+    block: &'a [u8],
+}
+
+impl<'a> DescriptorView<'a> {
+    // `dfd` follows the dfdTotalSize word.
+    pub(crate) fn new(dfd: &'a [u8]) -> Result<Self, ReadError> {
+        let malformed = |reason| Err(ReadError::Malformed(reason));
+        if dfd.len() < DESCRIPTOR_HEADER_SIZE {
+            return malformed("the DFD is shorter than a basic descriptor block");
+        }
+        let view = Self { block: dfd };
+        // KHR_DF_VENDORID_KHRONOS and KHR_DF_KHR_DESCRIPTORTYPE_BASICFORMAT
+        if view.vendor_id() != 0 || view.descriptor_type() != 0 {
+            return malformed("the first descriptor block is not a basic descriptor block");
+        }
+        let block_size = (view.word(1) >> 16) as usize;
+        if block_size < DESCRIPTOR_HEADER_SIZE
+            || (block_size - DESCRIPTOR_HEADER_SIZE) % SAMPLE_SIZE != 0
+        {
+            return malformed("descriptorBlockSize does not hold a whole number of samples");
+        }
+        if block_size > dfd.len() {
+            return malformed("descriptorBlockSize exceeds dfdTotalSize");
+        }
+        Ok(Self {
+            block: &dfd[..block_size],
+        })
+    }
+
+    fn word(&self, index: usize) -> u32 {
+        read_u32(self.block, 4 * index)
+    }
+
+    pub fn vendor_id(&self) -> u32 {
+        self.word(0) & 0x1ffff
+    }
+
+    pub fn descriptor_type(&self) -> u32 {
+        self.word(0) >> 17
+    }
+
+    pub fn version_number(&self) -> u32 {
+        self.word(1) & 0xffff
+    }
+
+    pub fn color_model(&self) -> u32 {
+        self.block[8].into()
+    }
+
+    pub fn color_primaries(&self) -> u32 {
+        self.block[9].into()
+    }
+
+    pub fn transfer_function(&self) -> u32 {
+        self.block[10].into()
+    }
+
+    /// `KHR_DF_FLAG_*` bits.
+    pub fn flags(&self) -> u32 {
+        self.block[11].into()
+    }
+
+    /// Width, height, depth and fourth dimension of a texel block, 1 when unused.
+    pub fn texel_block_dimensions(&self) -> [u32; 4] {
+        self.word(3)
+            .to_le_bytes()
+            .map(|dimension| u32::from(dimension) + 1)
+    }
+
+    /// Bytes per plane of a texel block, 0 for unused planes and supercompressed data.
+    pub fn bytes_planes(&self) -> [u8; 8] {
+        self.block[16..24].try_into().unwrap()
+    }
+
+    pub fn samples(&self) -> impl ExactSizeIterator<Item = SampleView<'a>> + 'a {
+        self.block[DESCRIPTOR_HEADER_SIZE..]
+            .chunks_exact(SAMPLE_SIZE)
+            .map(|sample| SampleView { sample })
+    }
+}
+
+/// One sample of a [`DescriptorView`], decoded like `dfd::SampleDescriptor`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SampleView<'a> {
+    sample: &'a [u8],
+}
+
+impl SampleView<'_> {
+    pub fn bit_offset(&self) -> u16 {
+        u16::from_le_bytes([self.sample[0], self.sample[1]])
+    }
+
+    pub fn bit_length(&self) -> u16 {
+        u16::from(self.sample[2]) + 1
+    }
+
+    /// The channel within the color model, e.g. `KHR_DF_CHANNEL_RGBSDA_RED`.
+    pub fn channel(&self) -> u8 {
+        self.sample[3] & 0x0f
+    }
+
+    /// `KHR_DF_SAMPLE_DATATYPE_*` bits.
+    pub fn qualifiers(&self) -> u8 {
+        self.sample[3] & 0xf0
+    }
+
+    pub fn sample_positions(&self) -> [u8; 4] {
+        self.sample[4..8].try_into().unwrap()
+    }
+
+    pub fn lower(&self) -> u32 {
+        read_u32(self.sample, 8)
+    }
+
+    pub fn upper(&self) -> u32 {
+        read_u32(self.sample, 12)
+    }
+}
+
+/// Iterator over the entries of the key/value data of a [`Ktx2Reader`].
+///
+/// Iteration stops at the first malformed entry; [`KeyValues::remainder`] is
+/// then non-empty.
+#[derive(Clone, Debug)]
+pub struct KeyValues<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> KeyValues<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// The bytes not yet consumed.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl<'a> Iterator for KeyValues<'a> {
+    type Item = (&'a CStr, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let length = read_u32(self.data.get(..4)?, 0) as usize;
+        let entry = self.data.get(4..)?.get(..length)?;
+        let key = CStr::from_bytes_until_nul(entry).ok()?;
+        let value = &entry[key.to_bytes_with_nul().len()..];
+        // Entries are padded to 4 bytes, except possibly the last one.
+        let padded = (4 + length).next_multiple_of(4).min(self.data.len());
+        self.data = &self.data[padded..];
+        Some((key, value))
+    }
+}
+
+/// Failure to read a KTX2 file with [`Ktx2Reader`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReadError {
+    /// The data doesn't start with [`KTX2_IDENTIFIER`].
+    NotKtx2,
+    /// A section extends past the end of the data.
+    Truncated,
+    Malformed(&'static str),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::NotKtx2 => f.write_str("not a KTX2 file"),
+            ReadError::Truncated => f.write_str("truncated KTX2 file"),
+            ReadError::Malformed(reason) => write!(f, "malformed KTX2 file: {reason}"),
+        }
+    }
+}
+
+impl core::error::Error for ReadError {}
+
+// Reads the header and index of data starting with the identifier.
+pub(crate) fn read_header(data: &[u8]) -> Result<(Header, Index), ReadError> {
+    if data.len() < HEADER_SIZE {
+        return Err(ReadError::Truncated);
+    }
+    let u32_at = |offset| read_u32(data, offset);
+    let u64_at = |offset| read_u64(data, offset);
+    let header = Header {
+        vk_format: u32_at(12),
+        type_size: u32_at(16),
+        pixel_width: u32_at(20),
+        pixel_height: u32_at(24),
+        pixel_depth: u32_at(28),
+        layer_count: u32_at(32),
+        face_count: u32_at(36),
+        level_count: u32_at(40),
+        supercompression_scheme: u32_at(44),
+    };
+    let index = Index {
+        dfd_byte_offset: u32_at(48),
+        dfd_byte_length: u32_at(52),
+        kvd_byte_offset: u32_at(56),
+        kvd_byte_length: u32_at(60),
+        sgd_byte_offset: u64_at(64),
+        sgd_byte_length: u64_at(72),
+    };
+    Ok((header, index))
+}
+
+pub(crate) fn read_level_index(data: &[u8], level: usize) -> LevelIndex {
+    let offset = HEADER_SIZE + level * LEVEL_INDEX_ENTRY_SIZE;
+    LevelIndex {
+        byte_offset: read_u64(data, offset),
+        byte_length: read_u64(data, offset + 8),
+        uncompressed_byte_length: read_u64(data, offset + 16),
+    }
+}
+
+pub(crate) fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+pub(crate) fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    // A basic descriptor block of one 8-bit sRGB red sample, after dfdTotalSize.
+    fn r8_block() -> Vec<u8> {
+        [
+            0,
+            2 | 40 << 16,
+            1 | 1 << 8 | 2 << 16,
+            0,
+            1,
+            0,
+            7 << 16,
+            0,
+            0,
+            255,
+        ]
+        .iter()
+        .flat_map(|word: &u32| word.to_le_bytes())
+        .collect()
+    }
+
+    #[test]
+    fn descriptor_view_reads_in_place() {
+        let block = r8_block();
+        let view = DescriptorView::new(&block).unwrap();
+        assert_eq!((view.vendor_id(), view.descriptor_type()), (0, 0));
+        assert_eq!(view.version_number(), 2);
+        assert_eq!(
+            (
+                view.color_model(),
+                view.color_primaries(),
+                view.transfer_function()
+            ),
+            (1, 1, 2)
+        );
+        assert_eq!(view.flags(), 0);
+        assert_eq!(view.texel_block_dimensions(), [1; 4]);
+        assert_eq!(view.bytes_planes(), [1, 0, 0, 0, 0, 0, 0, 0]);
+        let samples: Vec<_> = view.samples().collect();
+        assert_eq!(samples.len(), 1);
+        let sample = samples[0];
+        assert_eq!((sample.bit_offset(), sample.bit_length()), (0, 8));
+        assert_eq!((sample.channel(), sample.qualifiers()), (0, 0));
+        assert_eq!(sample.sample_positions(), [0; 4]);
+        assert_eq!((sample.lower(), sample.upper()), (0, 255));
+
+        // Data after descriptorBlockSize, such as further blocks, is not part of the view.
+        let mut longer = block.clone();
+        longer.extend([0; 8]);
+        assert_eq!(DescriptorView::new(&longer), Ok(view));
+    }
+
+    #[test]
+    fn descriptor_view_rejects_malformed() {
+        let malformed = |block: &[u8]| match DescriptorView::new(block) {
+            Err(ReadError::Malformed(reason)) => reason,
+            other => panic!("{other:?}"),
+        };
+        let block = r8_block();
+        assert!(malformed(&block[..20]).contains("shorter"));
+        let mut vendor = block.clone();
+        vendor[0] = 1;
+        assert!(malformed(&vendor).contains("not a basic descriptor block"));
+        let mut partial_sample = block.clone();
+        partial_sample[6] = 36;
+        assert!(malformed(&partial_sample).contains("whole number of samples"));
+        assert!(malformed(&block[..24]).contains("exceeds dfdTotalSize"));
+    }
+
+    #[cfg(feature = "native")]
+    mod libktx {
+        use super::super::*;
+        use crate::{
+            check,
+            ffi::*,
+            testing::{rgba8_gradient, undefined_r8, write_ktx2},
+            BaseTexture, BasicDescriptor, BasisCodec, EncodeBasis, Texture2, TextureBuilder, Uastc,
+        };
+
+        fn assert_matches_libktx(bytes: &[u8], name: &str) {
+            let flags = ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_LOAD_IMAGE_DATA_BIT;
+            let Ok(texture) = Texture2::from_memory(bytes, flags) else {
+                assert!(
+                    Ktx2Reader::new(bytes).is_err(),
+                    "{name} is rejected by libktx"
+                );
+                return;
+            };
+            let reader = Ktx2Reader::new(bytes).unwrap_or_else(|err| panic!("{name}: {err}"));
+            assert_eq!(reader.vk_format(), texture.vk_format(), "{name}");
+            assert_eq!(
+                reader.supercompression_scheme(),
+                texture.supercompression_scheme(),
+                "{name}"
+            );
+            assert_eq!(reader.base_width(), texture.base_width(), "{name}");
+            assert_eq!(reader.base_height(), texture.base_height(), "{name}");
+            assert_eq!(reader.base_depth(), texture.base_depth(), "{name}");
+            assert_eq!(reader.num_dimensions(), texture.num_dimensions(), "{name}");
+            assert_eq!(reader.num_levels(), texture.num_levels(), "{name}");
+            assert_eq!(reader.num_layers(), texture.num_layers(), "{name}");
+            assert_eq!(reader.num_faces(), texture.num_faces(), "{name}");
+            assert_eq!(reader.is_array(), texture.is_array(), "{name}");
+            assert_eq!(reader.is_cubemap(), texture.is_cubemap(), "{name}");
+            assert_eq!(
+                reader.generate_mipmaps(),
+                texture.generate_mipmaps(),
+                "{name}"
+            );
+            assert_eq!(
+                reader.basic_descriptor().ok().map(BasicDescriptor::from),
+                texture.dfd().ok(),
+                "{name}"
+            );
+
+            let mut expected: Vec<_> = texture.metadata().iter().collect();
+            let mut key_values = reader.key_values();
+            let mut actual: Vec<_> = key_values.by_ref().collect();
+            assert!(key_values.remainder().is_empty(), "{name}");
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected, "{name}");
+
+            if reader.supercompression_scheme() == 0 {
+                let level_sizes: Vec<u64> =
+                    reader.levels().map(|level| level.byte_length).collect();
+                let mut expected = vec![0; level_sizes.len()];
+                for image in texture.images().unwrap() {
+                    expected[image.level as usize] += image.data.len() as u64;
+                }
+                assert_eq!(level_sizes, expected, "{name}");
+            }
+        }
+
+        #[test]
+        fn rejects_truncated() {
+            let (_, mut bytes) = write_ktx2(&undefined_r8(), "reader test");
+            assert_matches_libktx(&bytes, "builder");
+            for len in [
+                0,
+                5,
+                12,
+                79,
+                HEADER_SIZE + LEVEL_INDEX_ENTRY_SIZE,
+                bytes.len() - 1,
+            ] {
+                assert_eq!(
+                    Ktx2Reader::new(&bytes[..len]).err(),
+                    Some(ReadError::Truncated),
+                    "length {len}"
+                );
+            }
+            bytes[1] = b'k';
+            assert_eq!(Ktx2Reader::new(&bytes).err(), Some(ReadError::NotKtx2));
+        }
+
+        /// Writes `texture` with a `KTXwriter`, supercompressed with ZSTD if `zstd`.
+        fn write(mut texture: Texture2, zstd: bool) -> Vec<u8> {
+            if zstd {
+                check(unsafe { ktxTexture2_DeflateZstd(texture.as_raw().cast(), 5) }).unwrap();
+            }
+            texture.metadata_mut().set_writer("reader test").unwrap();
+            let mut bytes = Vec::new();
+            texture.write_to(&mut bytes).unwrap();
+            bytes
+        }
+
+        #[test]
+        fn generated_corpus_matches_libktx() {
+            let builder = |vk_format| TextureBuilder::new().vk_format(vk_format);
+            let builders = [
+                ("undefined", undefined_r8()),
+                (
+                    "rgba8 mipmapped",
+                    builder(VkFormat_VK_FORMAT_R8G8B8A8_UNORM)
+                        .extent_2d(16, 16)
+                        .levels(5),
+                ),
+                (
+                    "bc1",
+                    builder(VkFormat_VK_FORMAT_BC1_RGB_SRGB_BLOCK)
+                        .extent_2d(16, 8)
+                        .levels(3),
+                ),
+                (
+                    "astc partial blocks",
+                    builder(VkFormat_VK_FORMAT_ASTC_6x6_UNORM_BLOCK).extent_2d(13, 11),
+                ),
+                (
+                    "1d array",
+                    builder(VkFormat_VK_FORMAT_R32_SFLOAT)
+                        .extent_1d(32)
+                        .layers(3)
+                        .array(true),
+                ),
+                (
+                    "3d",
+                    builder(VkFormat_VK_FORMAT_R16G16B16A16_SFLOAT)
+                        .extent_3d(8, 8, 4)
+                        .levels(4),
+                ),
+                (
+                    "cubemap",
+                    builder(VkFormat_VK_FORMAT_R8G8B8A8_SRGB)
+                        .extent_2d(8, 8)
+                        .levels(4)
+                        .cubemap(true),
+                ),
+                (
+                    "cubemap array",
+                    builder(VkFormat_VK_FORMAT_E5B9G9R9_UFLOAT_PACK32)
+                        .extent_2d(4, 4)
+                        .layers(2)
+                        .cubemap(true)
+                        .array(true),
+                ),
+                (
+                    "depth array",
+                    builder(VkFormat_VK_FORMAT_D32_SFLOAT)
+                        .extent_2d(8, 4)
+                        .layers(4)
+                        .array(true),
+                ),
+                (
+                    "generated mipmaps",
+                    builder(VkFormat_VK_FORMAT_R8_UNORM)
+                        .extent_2d(8, 8)
+                        .generate_mipmaps(true),
+                ),
+            ];
+            for (name, builder) in builders {
+                for zstd in [false, true] {
+                    let mut texture = builder.build_ktx2().unwrap();
+                    for image in texture.images_mut().unwrap() {
+                        for (i, byte) in image.data.iter_mut().enumerate() {
+                            *byte = (i % 7) as u8;
+                        }
+                    }
+                    let bytes = write(texture, zstd);
+                    assert_matches_libktx(&bytes, &format!("{name}, zstd {zstd}"));
+                }
+            }
+
+            // ETC1S is BasisLZ supercompressed, UASTC can additionally use ZSTD.
+            let uastc = EncodeBasis::new(BasisCodec::Uastc(Uastc::default()));
+            let encodings = [
+                ("etc1s", EncodeBasis::fast(), false),
+                ("uastc", uastc.clone(), false),
+                ("uastc", uastc, true),
+            ];
+            for (name, params, zstd) in encodings {
+                let mut texture = rgba8_gradient(16, 16);
+                texture.compress_basis(&params).unwrap();
+                let bytes = write(texture, zstd);
+                assert_matches_libktx(&bytes, &format!("{name}, zstd {zstd}"));
+            }
+        }
+    }
+}
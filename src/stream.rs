@@ -0,0 +1,490 @@
+//! Custom `ktxStream`s backed by Rust readers and writers.
+//!
+//! libktx copies the `ktxStream` struct into the texture it creates and keeps
+//! calling it to load image data later, so the Rust object behind a reader stream
+//! is owned by the texture wrapper through a [`StreamState`]. Writer streams only
+//! live for the duration of a single write call.
+
+use std::{
+    ffi::c_void,
+    io::{self, Read, Seek, SeekFrom, Write},
+    panic::{self, AssertUnwindSafe},
+    ptr::{self, NonNull},
+};
+
+use crate::ffi::*;
+
+/// Owns the Rust object a custom `ktxStream` points at and frees it on drop.
+pub(crate) struct StreamState {
+    address: NonNull<c_void>,
+    free: unsafe fn(*mut c_void),
+}
+
+// Only ever constructed from `Send` objects.
+unsafe impl Send for StreamState {}
+
+impl StreamState {
+    fn new<T: Send + 'static>(value: T) -> Self {
+        let address = NonNull::from(Box::leak(Box::new(value))).cast();
+        Self {
+            address,
+            free: free::<T>,
+        }
+    }
+}
+
+impl Drop for StreamState {
+    fn drop(&mut self) {
+        unsafe { (self.free)(self.address.as_ptr()) }
+    }
+}
+
+unsafe fn free<T>(address: *mut c_void) {
+    drop(Box::from_raw(address.cast::<T>()));
+}
+
+/// Builds a read-only `ktxStream` over `reader`.
+///
+/// The returned state must outlive every texture created from the stream.
+pub(crate) fn reader_stream<R: Read + Seek + Send + 'static>(
+    reader: R,
+) -> (ktxStream, StreamState) {
+    let state = StreamState::new(reader);
+    let stream = ktxStream {
+        read: Some(read::<R>),
+        skip: Some(skip::<R>),
+        write: None,
+        getpos: Some(getpos::<R>),
+        setpos: Some(setpos::<R>),
+        getsize: Some(getsize::<R>),
+        destruct: Some(destruct),
+        type_: streamType_eStreamTypeCustom,
+        data: ktxStream__bindgen_ty_1 {
+            custom_ptr: ktxStream__bindgen_ty_1__bindgen_ty_1 {
+                address: state.address.as_ptr(),
+                allocatorAddress: ptr::null_mut(),
+                size: 0,
+            },
+        },
+        readpos: 0,
+        closeOnDestruct: false,
+    };
+    (stream, state)
+}
+
+/// A writer exposed to libktx, tracking the position for `getpos`.
+pub(crate) struct WriterState<W> {
+    writer: W,
+    position: u64,
+}
+
+impl<W: Write> WriterState<W> {
+    pub(crate) fn new(writer: W) -> Self {
+        Self {
+            writer,
+            position: 0,
+        }
+    }
+
+    /// Builds a write-only `ktxStream` over `self`, valid while `self` is not moved.
+    pub(crate) fn stream(&mut self) -> ktxStream {
+        ktxStream {
+            read: None,
+            skip: None,
+            write: Some(write::<W>),
+            getpos: Some(writer_getpos::<W>),
+            setpos: Some(writer_setpos::<W>),
+            getsize: Some(writer_getsize::<W>),
+            destruct: Some(destruct),
+            type_: streamType_eStreamTypeCustom,
+            data: ktxStream__bindgen_ty_1 {
+                custom_ptr: ktxStream__bindgen_ty_1__bindgen_ty_1 {
+                    address: (self as *mut Self).cast(),
+                    allocatorAddress: ptr::null_mut(),
+                    size: 0,
+                },
+            },
+            readpos: 0,
+            closeOnDestruct: false,
+        }
+    }
+}
+
+// Runs `f` on the object behind `stream`, turning I/O errors and panics into `error`.
+unsafe fn with_inner<T>(
+    stream: *mut ktxStream,
+    error: ktx_error_code_e,
+    f: impl FnOnce(&mut T) -> io::Result<()>,
+) -> ktx_error_code_e {
+    let inner = &mut *(*stream).data.custom_ptr.address.cast::<T>();
+    match panic::catch_unwind(AssertUnwindSafe(|| f(inner))) {
+        Ok(Ok(())) => ktx_error_code_e_KTX_SUCCESS,
+        Ok(Err(err)) if err.kind() == io::ErrorKind::UnexpectedEof => {
+            ktx_error_code_e_KTX_FILE_UNEXPECTED_EOF
+        }
+        Ok(Err(_)) | Err(_) => error,
+    }
+}
+
+unsafe extern "C" fn read<R: Read>(
+    stream: *mut ktxStream,
+    dst: *mut c_void,
+    count: ktx_size_t,
+) -> ktx_error_code_e {
+    if count == 0 {
+        return ktx_error_code_e_KTX_SUCCESS;
+    }
+    let dst = std::slice::from_raw_parts_mut(dst.cast::<u8>(), count);
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_READ_ERROR,
+        |reader: &mut R| reader.read_exact(dst),
+    )
+}
+
+unsafe extern "C" fn skip<R: Seek>(stream: *mut ktxStream, count: ktx_size_t) -> ktx_error_code_e {
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+        |reader: &mut R| {
+            let count = i64::try_from(count).map_err(invalid_offset)?;
+            reader.seek(SeekFrom::Current(count)).map(drop)
+        },
+    )
+}
+
+unsafe extern "C" fn getpos<R: Seek>(
+    stream: *mut ktxStream,
+    offset: *mut ktx_off_t,
+) -> ktx_error_code_e {
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+        |reader: &mut R| {
+            let position = reader.stream_position()?;
+            *offset = ktx_off_t::try_from(position).map_err(invalid_offset)?;
+            Ok(())
+        },
+    )
+}
+
+unsafe extern "C" fn setpos<R: Seek>(
+    stream: *mut ktxStream,
+    offset: ktx_off_t,
+) -> ktx_error_code_e {
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+        |reader: &mut R| {
+            let offset = u64::try_from(offset).map_err(invalid_offset)?;
+            reader.seek(SeekFrom::Start(offset)).map(drop)
+        },
+    )
+}
+
+unsafe extern "C" fn getsize<R: Seek>(
+    stream: *mut ktxStream,
+    size: *mut ktx_size_t,
+) -> ktx_error_code_e {
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+        |reader: &mut R| {
+            let position = reader.stream_position()?;
+            let end = reader.seek(SeekFrom::End(0))?;
+            reader.seek(SeekFrom::Start(position))?;
+            *size = ktx_size_t::try_from(end).map_err(invalid_offset)?;
+            Ok(())
+        },
+    )
+}
+
+unsafe extern "C" fn write<W: Write>(
+    stream: *mut ktxStream,
+    src: *const c_void,
+    size: ktx_size_t,
+    count: ktx_size_t,
+) -> ktx_error_code_e {
+    let Some(len) = size.checked_mul(count) else {
+        return ktx_error_code_e_KTX_FILE_OVERFLOW;
+    };
+    if len == 0 {
+        return ktx_error_code_e_KTX_SUCCESS;
+    }
+    let src = std::slice::from_raw_parts(src.cast::<u8>(), len);
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_WRITE_ERROR,
+        |state: &mut WriterState<W>| {
+            state.writer.write_all(src)?;
+            state.position += len as u64;
+            Ok(())
+        },
+    )
+}
+
+unsafe extern "C" fn writer_getpos<W>(
+    stream: *mut ktxStream,
+    offset: *mut ktx_off_t,
+) -> ktx_error_code_e {
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+        |state: &mut WriterState<W>| {
+            *offset = ktx_off_t::try_from(state.position).map_err(invalid_offset)?;
+            Ok(())
+        },
+    )
+}
+
+// Writers can't seek, so only "moving" to the current position succeeds.
+unsafe extern "C" fn writer_setpos<W>(
+    stream: *mut ktxStream,
+    offset: ktx_off_t,
+) -> ktx_error_code_e {
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+        |state: &mut WriterState<W>| match u64::try_from(offset) {
+            Ok(offset) if offset == state.position => Ok(()),
+            _ => Err(io::ErrorKind::Unsupported.into()),
+        },
+    )
+}
+
+unsafe extern "C" fn writer_getsize<W>(
+    stream: *mut ktxStream,
+    size: *mut ktx_size_t,
+) -> ktx_error_code_e {
+    with_inner(
+        stream,
+        ktx_error_code_e_KTX_FILE_SEEK_ERROR,
+        |state: &mut WriterState<W>| {
+            *size = ktx_size_t::try_from(state.position).map_err(invalid_offset)?;
+            Ok(())
+        },
+    )
+}
+
+// The Rust object is released by its owner on the Rust side, not by libktx.
+unsafe extern "C" fn destruct(_stream: *mut ktxStream) {}
+
+fn invalid_offset<E>(_: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "stream offset out of range")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::Arc};
+
+    use super::*;
+    use crate::{BaseTexture, KtxError, Texture2, TextureBuilder};
+
+    // A reader that is alive for as long as `alive` has a second owner.
+    struct Tracked {
+        reader: Cursor<Vec<u8>>,
+        _alive: Arc<()>,
+    }
+
+    impl Read for Tracked {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reader.read(buf)
+        }
+    }
+
+    impl Seek for Tracked {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.reader.seek(pos)
+        }
+    }
+
+    struct Panicking;
+
+    impl Read for Panicking {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            panic!("read called")
+        }
+    }
+
+    impl Seek for Panicking {
+        fn seek(&mut self, _pos: SeekFrom) -> io::Result<u64> {
+            panic!("seek called")
+        }
+    }
+
+    #[test]
+    fn reader_callbacks() {
+        let (mut stream, _state) = reader_stream(Cursor::new((0..16).collect::<Vec<u8>>()));
+        let s = ptr::addr_of_mut!(stream);
+        let mut buf = [0u8; 4];
+        let (mut position, mut size) = (0, 0);
+        unsafe {
+            let read = (*s).read.unwrap();
+            assert_eq!(
+                read(s, buf.as_mut_ptr().cast(), 4),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(buf, [0, 1, 2, 3]);
+            assert_eq!((*s).skip.unwrap()(s, 8), ktx_error_code_e_KTX_SUCCESS);
+            assert_eq!(
+                (*s).getpos.unwrap()(s, &mut position),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(position, 12);
+            assert_eq!(
+                (*s).getsize.unwrap()(s, &mut size),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(size, 16);
+            assert_eq!(
+                read(s, buf.as_mut_ptr().cast(), 8),
+                ktx_error_code_e_KTX_FILE_UNEXPECTED_EOF
+            );
+            assert_eq!((*s).setpos.unwrap()(s, 2), ktx_error_code_e_KTX_SUCCESS);
+            assert_eq!(
+                read(s, buf.as_mut_ptr().cast(), 2),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(buf[..2], [2, 3]);
+            assert_eq!(
+                (*s).setpos.unwrap()(s, -1),
+                ktx_error_code_e_KTX_FILE_SEEK_ERROR
+            );
+        }
+    }
+
+    #[test]
+    fn callbacks_catch_panics() {
+        let (mut stream, _state) = reader_stream(Panicking);
+        let s = ptr::addr_of_mut!(stream);
+        let mut buf = [0u8; 4];
+        let mut position = 0;
+        unsafe {
+            assert_eq!(
+                (*s).read.unwrap()(s, buf.as_mut_ptr().cast(), 4),
+                ktx_error_code_e_KTX_FILE_READ_ERROR
+            );
+            assert_eq!(
+                (*s).getpos.unwrap()(s, &mut position),
+                ktx_error_code_e_KTX_FILE_SEEK_ERROR
+            );
+        }
+    }
+
+    // A writer with no space left.
+    struct Full;
+
+    impl Write for Full {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::WriteZero.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writer_callbacks() {
+        let mut out = Vec::new();
+        let mut state = WriterState::new(&mut out);
+        let mut stream = state.stream();
+        let s = ptr::addr_of_mut!(stream);
+        let data = b"abcdef";
+        let (mut position, mut size) = (0, 0);
+        unsafe {
+            let write = (*s).write.unwrap();
+            assert_eq!(
+                write(s, data.as_ptr().cast(), 2, 3),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(
+                write(s, data.as_ptr().cast(), 0, 3),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(
+                write(s, data.as_ptr().cast(), usize::MAX, 2),
+                ktx_error_code_e_KTX_FILE_OVERFLOW
+            );
+            assert_eq!(
+                (*s).getpos.unwrap()(s, &mut position),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(position, 6);
+            assert_eq!(
+                (*s).getsize.unwrap()(s, &mut size),
+                ktx_error_code_e_KTX_SUCCESS
+            );
+            assert_eq!(size, 6);
+            assert_eq!((*s).setpos.unwrap()(s, 6), ktx_error_code_e_KTX_SUCCESS);
+            assert_eq!(
+                (*s).setpos.unwrap()(s, 0),
+                ktx_error_code_e_KTX_FILE_SEEK_ERROR
+            );
+        }
+        assert_eq!(out, data);
+
+        let mut state = WriterState::new(Full);
+        let mut stream = state.stream();
+        let s = ptr::addr_of_mut!(stream);
+        unsafe {
+            assert_eq!(
+                (*s).write.unwrap()(s, data.as_ptr().cast(), 1, 1),
+                ktx_error_code_e_KTX_FILE_WRITE_ERROR
+            );
+        }
+    }
+
+    #[test]
+    fn write_to_round_trips() {
+        let builder = TextureBuilder::new()
+            .vk_format(VkFormat_VK_FORMAT_R8G8B8A8_UNORM)
+            .extent_2d(4, 4);
+        let mut ktx1 = builder.build_ktx1().unwrap();
+        for image in ktx1.images_mut().unwrap() {
+            image.data.fill(0x5a);
+        }
+        let mut bytes = Vec::new();
+        ktx1.write_ktx2_to(&mut bytes).unwrap();
+        let ktx2 = Texture2::from_memory(
+            &bytes,
+            ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_LOAD_IMAGE_DATA_BIT,
+        )
+        .unwrap();
+        assert_eq!(ktx2.vk_format(), VkFormat_VK_FORMAT_R8G8B8A8_UNORM);
+        assert!(ktx2.images().unwrap().all(|image| image.data == [0x5a; 64]));
+
+        assert_eq!(ktx2.write_to(&mut Full), Err(KtxError::FileWriteError));
+    }
+
+    #[test]
+    fn texture_owns_its_reader() {
+        let mut bytes = Vec::new();
+        let mut texture = TextureBuilder::new()
+            .vk_format(VkFormat_VK_FORMAT_R8_UNORM)
+            .extent_2d(4, 4)
+            .levels(3)
+            .build_ktx2()
+            .unwrap();
+        for (i, image) in texture.images_mut().unwrap().enumerate() {
+            image.data.fill(i as u8);
+        }
+        texture.write_to(&mut bytes).unwrap();
+
+        let alive = Arc::new(());
+        let read = Texture2::from_reader(
+            Tracked {
+                reader: Cursor::new(bytes),
+                _alive: alive.clone(),
+            },
+            ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_LOAD_IMAGE_DATA_BIT,
+        )
+        .unwrap();
+        assert_eq!(Arc::strong_count(&alive), 2);
+        let images: Vec<_> = read.images().unwrap().map(|image| image.data).collect();
+        let expected: Vec<_> = texture.images().unwrap().map(|image| image.data).collect();
+        assert_eq!(images, expected);
+        drop(read);
+        assert_eq!(Arc::strong_count(&alive), 1);
+    }
+}
@@ -0,0 +1,47 @@
+//! Fixtures shared by the unit tests.
+
+use crate::{ffi::*, BaseTexture, DfdBuilder, SampleDescriptor, Texture2, TextureBuilder};
+
+/// An 8x8 texture with 4 levels of 8-bit single channel texels, described only by
+/// its DFD, so with `VK_FORMAT_UNDEFINED`.
+pub(crate) fn undefined_r8() -> TextureBuilder {
+    let dfd = DfdBuilder::new(_khr_df_model_e_KHR_DF_MODEL_RGBSDA)
+        .texel_block_size(1)
+        .sample(SampleDescriptor::new(0, 0, 8))
+        .build()
+        .unwrap();
+    TextureBuilder::new().extent_2d(8, 8).levels(4).dfd(dfd)
+}
+
+/// Builds a KTX2 texture with `writer` as its `KTXwriter` and writes it to memory.
+pub(crate) fn write_ktx2(builder: &TextureBuilder, writer: &str) -> (Texture2, Vec<u8>) {
+    let mut texture = builder.build_ktx2().unwrap();
+    texture.metadata_mut().set_writer(writer).unwrap();
+    let mut bytes = Vec::new();
+    texture.write_to(&mut bytes).unwrap();
+    (texture, bytes)
+}
+
+/// A `width` x `height` `R8G8B8A8_SRGB` texture holding a gradient with varying
+/// alpha, so encoders keep the alpha channel.
+pub(crate) fn rgba8_gradient(width: u32, height: u32) -> Texture2 {
+    let mut texture = TextureBuilder::new()
+        .vk_format(VkFormat_VK_FORMAT_R8G8B8A8_SRGB)
+        .extent_2d(width, height)
+        .build_ktx2()
+        .unwrap();
+    for image in texture.images_mut().unwrap() {
+        for (i, texel) in (0..).zip(image.data.chunks_exact_mut(4)) {
+            let (x, y) = (i % image.width, i / image.width);
+            // 0 at the first texel to 255 at the last of `count`
+            let ramp = |value: u32, count: u32| (value * 255 / (count - 1).max(1)) as u8;
+            texel.copy_from_slice(&[
+                ramp(x, image.width),
+                ramp(y, image.height),
+                128,
+                ramp(x + y, image.width + image.height - 1),
+            ]);
+        }
+    }
+    texture
+}
@@ -0,0 +1,527 @@
+//! Safe, owning wrappers over `ktxTexture`, `ktxTexture1` and `ktxTexture2`.
+
+use std::{
+    ffi::CString,
+    io::{Read, Seek, Write},
+    path::Path,
+    ptr::{self, NonNull},
+};
+
+use crate::{
+    error::check,
+    ffi::*,
+    image::{Images, ImagesMut},
+    metadata::Metadata,
+    stream::{reader_stream, StreamState, WriterState},
+    KtxError,
+};
+
+/// Properties shared by every kind of KTX texture.
+///
+/// This mirrors the `ktxTexture` base class of libktx, which `ktxTexture1` and
+/// `ktxTexture2` extend. It is implemented by [`Texture1`], [`Texture2`] and [`Texture`].
+pub trait BaseTexture {
+    /// Pointer to the underlying texture, valid for as long as `self` is.
+    fn as_raw(&self) -> *mut ktxTexture;
+
+    /// The `ktxTexture` fields common to both texture classes.
+    fn base(&self) -> &ktxTexture {
+        unsafe { &*self.as_raw() }
+    }
+
+    fn base_width(&self) -> u32 {
+        self.base().baseWidth
+    }
+
+    fn base_height(&self) -> u32 {
+        self.base().baseHeight
+    }
+
+    fn base_depth(&self) -> u32 {
+        self.base().baseDepth
+    }
+
+    /// 1, 2 or 3.
+    fn num_dimensions(&self) -> u32 {
+        self.base().numDimensions
+    }
+
+    fn num_levels(&self) -> u32 {
+        self.base().numLevels
+    }
+
+    fn num_layers(&self) -> u32 {
+        self.base().numLayers
+    }
+
+    /// 6 for cubemaps, 1 otherwise.
+    fn num_faces(&self) -> u32 {
+        self.base().numFaces
+    }
+
+    fn is_array(&self) -> bool {
+        self.base().isArray
+    }
+
+    fn is_cubemap(&self) -> bool {
+        self.base().isCubemap
+    }
+
+    fn is_compressed(&self) -> bool {
+        self.base().isCompressed
+    }
+
+    fn generate_mipmaps(&self) -> bool {
+        self.base().generateMipmaps
+    }
+
+    /// Size in bytes of the image data, whether or not it has been loaded.
+    fn data_size(&self) -> usize {
+        self.base().dataSize
+    }
+
+    /// Iterates over every image, failing if the image data has not been loaded
+    /// or is BasisLZ supercompressed.
+    fn images(&self) -> Result<Images<'_>, KtxError> {
+        unsafe { Images::new(self.as_raw()) }
+    }
+
+    fn images_mut(&mut self) -> Result<ImagesMut<'_>, KtxError> {
+        unsafe { ImagesMut::new(self.as_raw()) }
+    }
+
+    /// Copies `data` into one image of a texture with allocated storage.
+    ///
+    /// `data` must be exactly as long as an image of `level`.
+    fn set_image(
+        &mut self,
+        level: u32,
+        layer: u32,
+        face_or_slice: u32,
+        data: &[u8],
+    ) -> Result<(), KtxError> {
+        let raw = self.as_raw();
+        let base = self.base();
+        let faces_or_slices = if base.numDimensions == 3 {
+            (base.baseDepth >> level).max(1)
+        } else {
+            base.numFaces
+        };
+        if level >= base.numLevels || layer >= base.numLayers || face_or_slice >= faces_or_slices {
+            return Err(KtxError::InvalidValue);
+        }
+        if base.pData.is_null() {
+            return Err(KtxError::InvalidOperation);
+        }
+        unsafe {
+            let vtbl = &*base.vtbl;
+            if data.len() != vtbl.GetImageSize.unwrap()(raw, level) {
+                return Err(KtxError::InvalidValue);
+            }
+            check(vtbl.SetImageFromMemory.unwrap()(
+                raw,
+                level,
+                layer,
+                face_or_slice,
+                data.as_ptr(),
+                data.len(),
+            ))
+        }
+    }
+
+    /// Key/value metadata, empty if created with `KTX_TEXTURE_CREATE_RAW_KVDATA_BIT`.
+    fn metadata(&self) -> &Metadata {
+        unsafe { &*ptr::addr_of!((*self.as_raw()).kvDataHead).cast() }
+    }
+
+    fn metadata_mut(&mut self) -> &mut Metadata {
+        unsafe { Metadata::from_raw(&mut (*self.as_raw()).kvDataHead) }
+    }
+
+    /// Writes the texture in its own container format to `writer`.
+    ///
+    /// The image data must have been loaded, e.g. with `KTX_TEXTURE_CREATE_LOAD_IMAGE_DATA_BIT`.
+    fn write_to(&self, writer: &mut impl Write) -> Result<(), KtxError>
+    where
+        Self: Sized,
+    {
+        let raw = self.as_raw();
+        let mut state = WriterState::new(writer);
+        let mut stream = state.stream();
+        unsafe {
+            let write_to_stream = (*(*raw).vtbl).WriteToStream.unwrap();
+            check(write_to_stream(raw, &mut stream))
+        }
+    }
+}
+
+/// A KTX texture of either class, as returned by the class-agnostic constructors.
+pub enum Texture {
+    Ktx1(Texture1),
+    Ktx2(Texture2),
+}
+
+/// An owned `ktxTexture1`, destroyed on drop.
+pub struct Texture1 {
+    raw: NonNull<ktxTexture1>,
+    // Keeps the reader of a stream-backed texture alive until it is destroyed.
+    _stream: Option<StreamState>,
+}
+
+/// An owned `ktxTexture2`, destroyed on drop.
+pub struct Texture2 {
+    raw: NonNull<ktxTexture2>,
+    // Keeps the reader of a stream-backed texture alive until it is destroyed.
+    _stream: Option<StreamState>,
+}
+
+// libktx textures hold no thread-local state, only shared access needs care.
+unsafe impl Send for Texture1 {}
+unsafe impl Send for Texture2 {}
+
+impl Texture {
+    /// Opens a KTX or KTX2 file, dispatching on the class libktx detects.
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        flags: ktxTextureCreateFlags,
+    ) -> Result<Self, KtxError> {
+        let path = path_to_cstring(path.as_ref())?;
+        let raw =
+            create(|out| unsafe { ktxTexture_CreateFromNamedFile(path.as_ptr(), flags, out) })?;
+        Ok(unsafe { Self::from_raw(raw.as_ptr()) })
+    }
+
+    pub fn from_memory(bytes: &[u8], flags: ktxTextureCreateFlags) -> Result<Self, KtxError> {
+        let raw = create(|out| unsafe {
+            ktxTexture_CreateFromMemory(bytes.as_ptr(), bytes.len(), flags, out)
+        })?;
+        Ok(unsafe { Self::from_raw(raw.as_ptr()) })
+    }
+
+    /// Reads a texture of either class from `reader`, see [`Texture2::from_reader`].
+    pub fn from_reader(
+        reader: impl Read + Seek + Send + 'static,
+        flags: ktxTextureCreateFlags,
+    ) -> Result<Self, KtxError> {
+        let (mut stream, state) = reader_stream(reader);
+        let raw = create(|out| unsafe { ktxTexture_CreateFromStream(&mut stream, flags, out) })?;
+        let mut texture = unsafe { Self::from_raw(raw.as_ptr()) };
+        match &mut texture {
+            Texture::Ktx1(texture) => texture._stream = Some(state),
+            Texture::Ktx2(texture) => texture._stream = Some(state),
+        }
+        Ok(texture)
+    }
+
+    /// Takes ownership of a texture created by libktx, using `classId` to pick the variant.
+    ///
+    /// # Safety
+    /// `raw` must be a valid, non-null texture that is not owned elsewhere.
+    pub unsafe fn from_raw(raw: *mut ktxTexture) -> Self {
+        match (*raw).classId {
+            class_id_ktxTexture1_c => Texture::Ktx1(Texture1::from_raw(raw.cast())),
+            _ => Texture::Ktx2(Texture2::from_raw(raw.cast())),
+        }
+    }
+
+    /// Releases ownership without destroying the texture.
+    pub fn into_raw(self) -> *mut ktxTexture {
+        match self {
+            Texture::Ktx1(texture) => texture.into_raw().cast(),
+            Texture::Ktx2(texture) => texture.into_raw().cast(),
+        }
+    }
+}
+
+impl Texture1 {
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        flags: ktxTextureCreateFlags,
+    ) -> Result<Self, KtxError> {
+        let path = path_to_cstring(path.as_ref())?;
+        let raw =
+            create(|out| unsafe { ktxTexture1_CreateFromNamedFile(path.as_ptr(), flags, out) })?;
+        Ok(Self { raw, _stream: None })
+    }
+
+    pub fn from_memory(bytes: &[u8], flags: ktxTextureCreateFlags) -> Result<Self, KtxError> {
+        let raw = create(|out| unsafe {
+            ktxTexture1_CreateFromMemory(bytes.as_ptr(), bytes.len(), flags, out)
+        })?;
+        Ok(Self { raw, _stream: None })
+    }
+
+    /// Reads a texture from `reader`, which is kept until the texture is dropped
+    /// so that image data can be loaded from it on demand.
+    pub fn from_reader(
+        reader: impl Read + Seek + Send + 'static,
+        flags: ktxTextureCreateFlags,
+    ) -> Result<Self, KtxError> {
+        let (mut stream, state) = reader_stream(reader);
+        let raw = create(|out| unsafe { ktxTexture1_CreateFromStream(&mut stream, flags, out) })?;
+        Ok(Self {
+            raw,
+            _stream: Some(state),
+        })
+    }
+
+    /// Takes ownership of a texture created by libktx.
+    ///
+    /// # Safety
+    /// `raw` must be a valid, non-null `ktxTexture1` that is not owned elsewhere.
+    pub unsafe fn from_raw(raw: *mut ktxTexture1) -> Self {
+        Self {
+            raw: NonNull::new_unchecked(raw),
+            _stream: None,
+        }
+    }
+
+    /// Releases ownership without destroying the texture.
+    ///
+    /// The reader of a texture created with [`Texture1::from_reader`] is leaked.
+    pub fn into_raw(self) -> *mut ktxTexture1 {
+        let raw = self.raw.as_ptr();
+        std::mem::forget(self);
+        raw
+    }
+
+    pub fn raw(&self) -> &ktxTexture1 {
+        unsafe { self.raw.as_ref() }
+    }
+
+    pub fn gl_format(&self) -> u32 {
+        self.raw().glFormat
+    }
+
+    pub fn gl_internalformat(&self) -> u32 {
+        self.raw().glInternalformat
+    }
+
+    pub fn gl_base_internalformat(&self) -> u32 {
+        self.raw().glBaseInternalformat
+    }
+
+    pub fn gl_type(&self) -> u32 {
+        self.raw().glType
+    }
+
+    pub fn needs_transcoding(&self) -> bool {
+        unsafe { ktxTexture1_NeedsTranscoding(self.raw.as_ptr()) }
+    }
+
+    /// The `VkFormat` libktx converts `glInternalformat` to, see also
+    /// [`GlFormat`](crate::GlFormat).
+    #[cfg(feature = "vulkan")]
+    pub fn vk_format(&self) -> VkFormat {
+        unsafe { ktxTexture1_GetVkFormat(self.raw.as_ptr()) }
+    }
+
+    /// Writes the texture to `writer` converted to the KTX2 container format.
+    pub fn write_ktx2_to(&self, writer: &mut impl Write) -> Result<(), KtxError> {
+        let mut state = WriterState::new(writer);
+        let mut stream = state.stream();
+        check(unsafe { ktxTexture1_WriteKTX2ToStream(self.raw.as_ptr(), &mut stream) })
+    }
+}
+
+impl Texture2 {
+    pub fn from_file(
+        path: impl AsRef<Path>,
+        flags: ktxTextureCreateFlags,
+    ) -> Result<Self, KtxError> {
+        let path = path_to_cstring(path.as_ref())?;
+        let raw =
+            create(|out| unsafe { ktxTexture2_CreateFromNamedFile(path.as_ptr(), flags, out) })?;
+        Ok(Self { raw, _stream: None })
+    }
+
+    pub fn from_memory(bytes: &[u8], flags: ktxTextureCreateFlags) -> Result<Self, KtxError> {
+        let raw = create(|out| unsafe {
+            ktxTexture2_CreateFromMemory(bytes.as_ptr(), bytes.len(), flags, out)
+        })?;
+        Ok(Self { raw, _stream: None })
+    }
+
+    /// Reads a texture from `reader`, which is kept until the texture is dropped
+    /// so that image data can be loaded from it on demand.
+    pub fn from_reader(
+        reader: impl Read + Seek + Send + 'static,
+        flags: ktxTextureCreateFlags,
+    ) -> Result<Self, KtxError> {
+        let (mut stream, state) = reader_stream(reader);
+        let raw = create(|out| unsafe { ktxTexture2_CreateFromStream(&mut stream, flags, out) })?;
+        Ok(Self {
+            raw,
+            _stream: Some(state),
+        })
+    }
+
+    /// Takes ownership of a texture created by libktx.
+    ///
+    /// # Safety
+    /// `raw` must be a valid, non-null `ktxTexture2` that is not owned elsewhere.
+    pub unsafe fn from_raw(raw: *mut ktxTexture2) -> Self {
+        Self {
+            raw: NonNull::new_unchecked(raw),
+            _stream: None,
+        }
+    }
+
+    /// Releases ownership without destroying the texture.
+    ///
+    /// The reader of a texture created with [`Texture2::from_reader`] is leaked.
+    pub fn into_raw(self) -> *mut ktxTexture2 {
+        let raw = self.raw.as_ptr();
+        std::mem::forget(self);
+        raw
+    }
+
+    pub fn raw(&self) -> &ktxTexture2 {
+        unsafe { self.raw.as_ref() }
+    }
+
+    pub fn vk_format(&self) -> u32 {
+        self.raw().vkFormat
+    }
+
+    pub fn supercompression_scheme(&self) -> ktxSupercmpScheme {
+        self.raw().supercompressionScheme
+    }
+
+    pub fn needs_transcoding(&self) -> bool {
+        unsafe { ktxTexture2_NeedsTranscoding(self.raw.as_ptr()) }
+    }
+}
+
+impl BaseTexture for Texture {
+    fn as_raw(&self) -> *mut ktxTexture {
+        match self {
+            Texture::Ktx1(texture) => texture.as_raw(),
+            Texture::Ktx2(texture) => texture.as_raw(),
+        }
+    }
+}
+
+impl BaseTexture for Texture1 {
+    fn as_raw(&self) -> *mut ktxTexture {
+        self.raw.as_ptr().cast()
+    }
+}
+
+impl BaseTexture for Texture2 {
+    fn as_raw(&self) -> *mut ktxTexture {
+        self.raw.as_ptr().cast()
+    }
+}
+
+impl Drop for Texture1 {
+    fn drop(&mut self) {
+        unsafe { destroy(self.as_raw()) }
+    }
+}
+
+impl Drop for Texture2 {
+    fn drop(&mut self) {
+        unsafe { destroy(self.as_raw()) }
+    }
+}
+
+// Equivalent of the `ktxTexture_Destroy` macro from ktx.h
+unsafe fn destroy(texture: *mut ktxTexture) {
+    if let Some(destroy) = (*(*texture).vtbl).Destroy {
+        destroy(texture);
+    }
+}
+
+// Runs a libktx constructor, turning its out-parameter into an owned pointer
+pub(crate) fn create<T>(
+    f: impl FnOnce(*mut *mut T) -> ktx_error_code_e,
+) -> Result<NonNull<T>, KtxError> {
+    let mut raw = ptr::null_mut();
+    check(f(&mut raw))?;
+    NonNull::new(raw).ok_or(KtxError::OutOfMemory)
+}
+
+fn path_to_cstring(path: &Path) -> Result<CString, KtxError> {
+    #[cfg(unix)]
+    let bytes = {
+        use std::os::unix::ffi::OsStrExt;
+        path.as_os_str().as_bytes()
+    };
+    #[cfg(not(unix))]
+    let bytes = path.to_str().ok_or(KtxError::InvalidValue)?.as_bytes();
+    CString::new(bytes).map_err(|_| KtxError::InvalidValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+    use crate::TextureBuilder;
+
+    const LOAD: ktxTextureCreateFlags =
+        ktxTextureCreateFlagBits_KTX_TEXTURE_CREATE_LOAD_IMAGE_DATA_BIT;
+
+    static DESTROYED: AtomicU32 = AtomicU32::new(0);
+
+    unsafe extern "C" fn count_destroy(_texture: *mut ktxTexture) {
+        DESTROYED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn drop_destroys_once() {
+        // A stand-in texture whose only method counts calls to Destroy.
+        let mut vtbl: ktxTexture_vtbl = unsafe { std::mem::zeroed() };
+        vtbl.Destroy = Some(count_destroy);
+        let mut raw: ktxTexture2 = unsafe { std::mem::zeroed() };
+        raw.classId = class_id_ktxTexture2_c;
+        raw.vtbl = &mut vtbl;
+
+        let texture = unsafe { Texture::from_raw(ptr::addr_of_mut!(raw).cast()) };
+        let raw = texture.into_raw();
+        assert_eq!(DESTROYED.load(Ordering::SeqCst), 0);
+        let Texture::Ktx2(texture) = (unsafe { Texture::from_raw(raw) }) else {
+            panic!("classId of a ktxTexture2 must give Texture::Ktx2");
+        };
+        drop(texture);
+        assert_eq!(DESTROYED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn from_memory_dispatches_on_class() {
+        let builder = TextureBuilder::new()
+            .vk_format(VkFormat_VK_FORMAT_R8G8B8A8_UNORM)
+            .extent_2d(4, 2)
+            .levels(2);
+        let (mut ktx1, mut ktx2) = (Vec::new(), Vec::new());
+        builder.build_ktx1().unwrap().write_to(&mut ktx1).unwrap();
+        builder.build_ktx2().unwrap().write_to(&mut ktx2).unwrap();
+
+        let Ok(Texture::Ktx1(texture)) = Texture::from_memory(&ktx1, LOAD) else {
+            panic!("KTX1 file not loaded as Texture::Ktx1");
+        };
+        assert_eq!(texture.gl_internalformat(), 0x8058); // GL_RGBA8
+        let Ok(Texture::Ktx2(texture)) = Texture::from_memory(&ktx2, LOAD) else {
+            panic!("KTX2 file not loaded as Texture::Ktx2");
+        };
+        assert_eq!(texture.vk_format(), VkFormat_VK_FORMAT_R8G8B8A8_UNORM);
+        assert_eq!(
+            (
+                texture.base_width(),
+                texture.base_height(),
+                texture.num_levels()
+            ),
+            (4, 2, 2)
+        );
+
+        assert_eq!(
+            Texture2::from_memory(&ktx1, LOAD).err(),
+            Some(KtxError::UnknownFileFormat)
+        );
+        assert_eq!(
+            Texture1::from_memory(&ktx2, LOAD).err(),
+            Some(KtxError::UnknownFileFormat)
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Transcoding Basis Universal textures and picking a target format for a device.
+
+use crate::{error::check, ffi::*, BaseTexture, KtxError, Texture2};
+
+/// Target formats of `ktxTexture2_TranscodeBasis`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum TranscodeFormat {
+    Etc1Rgb = ktx_transcode_fmt_e_KTX_TTF_ETC1_RGB,
+    Etc2Rgba = ktx_transcode_fmt_e_KTX_TTF_ETC2_RGBA,
+    Bc1Rgb = ktx_transcode_fmt_e_KTX_TTF_BC1_RGB,
+    Bc3Rgba = ktx_transcode_fmt_e_KTX_TTF_BC3_RGBA,
+    Bc4R = ktx_transcode_fmt_e_KTX_TTF_BC4_R,
+    Bc5Rg = ktx_transcode_fmt_e_KTX_TTF_BC5_RG,
+    Bc7Rgba = ktx_transcode_fmt_e_KTX_TTF_BC7_RGBA,
+    Pvrtc1_4Rgb = ktx_transcode_fmt_e_KTX_TTF_PVRTC1_4_RGB,
+    Pvrtc1_4Rgba = ktx_transcode_fmt_e_KTX_TTF_PVRTC1_4_RGBA,
+    Astc4x4Rgba = ktx_transcode_fmt_e_KTX_TTF_ASTC_4x4_RGBA,
+    Pvrtc2_4Rgb = ktx_transcode_fmt_e_KTX_TTF_PVRTC2_4_RGB,
+    Pvrtc2_4Rgba = ktx_transcode_fmt_e_KTX_TTF_PVRTC2_4_RGBA,
+    Etc2EacR11 = ktx_transcode_fmt_e_KTX_TTF_ETC2_EAC_R11,
+    Etc2EacRg11 = ktx_transcode_fmt_e_KTX_TTF_ETC2_EAC_RG11,
+    Rgba32 = ktx_transcode_fmt_e_KTX_TTF_RGBA32,
+    Rgb565 = ktx_transcode_fmt_e_KTX_TTF_RGB565,
+    Bgr565 = ktx_transcode_fmt_e_KTX_TTF_BGR565,
+    Rgba4444 = ktx_transcode_fmt_e_KTX_TTF_RGBA4444,
+    /// ETC1 RGB for opaque textures, ETC2 RGBA otherwise. Chosen by libktx.
+    Etc = ktx_transcode_fmt_e_KTX_TTF_ETC,
+    /// BC1 RGB for opaque textures, BC3 RGBA otherwise. Chosen by libktx.
+    Bc1Or3 = ktx_transcode_fmt_e_KTX_TTF_BC1_OR_3,
+}
+
+/// The families of compressed formats a GPU can sample from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DeviceCaps {
+    /// BC1 to BC7, e.g. `textureCompressionBC` in Vulkan.
+    pub bc: bool,
+    /// ASTC LDR, e.g. `textureCompressionASTC_LDR` in Vulkan.
+    pub astc: bool,
+    /// ETC2 and EAC, e.g. `textureCompressionETC2` in Vulkan.
+    pub etc2: bool,
+    /// PVRTC1, which additionally requires power-of-two dimensions.
+    pub pvrtc: bool,
+}
+
+impl Texture2 {
+    /// Transcodes Basis Universal data in place, see [`Texture2::transcode_with_flags`].
+    pub fn transcode(&mut self, target: TranscodeFormat) -> Result<(), KtxError> {
+        self.transcode_with_flags(target, 0)
+    }
+
+    /// Transcodes Basis Universal data in place, with `ktx_transcode_flag_bits_e` flags.
+    pub fn transcode_with_flags(
+        &mut self,
+        target: TranscodeFormat,
+        flags: ktx_transcode_flags,
+    ) -> Result<(), KtxError> {
+        check(unsafe { ktxTexture2_TranscodeBasis(self.as_raw().cast(), target as u32, flags) })
+    }
+
+    /// Number of components of the image data, as reported by the DFD.
+    pub fn num_components(&self) -> u32 {
+        unsafe { ktxTexture2_GetNumComponents(self.as_raw().cast()) }
+    }
+
+    /// Whether the transfer function is sRGB.
+    pub fn is_srgb(&self) -> bool {
+        unsafe {
+            ktxTexture2_GetOETF_e(self.as_raw().cast()) == _khr_df_transfer_e_KHR_DF_TRANSFER_SRGB
+        }
+    }
+}
+
+/// Picks the best format `texture` can be transcoded to on a device with `caps`,
+/// or `None` if it is not Basis Universal encoded.
+///
+/// Block formats are preferred in the order that loses the least quality for the
+/// source codec: ETC1S maps almost losslessly to ETC, UASTC to ASTC and BC7.
+/// Single and dual component linear textures use BC4/BC5 or EAC R11/RG11.
+/// Uncompressed RGBA32 is the fallback.
+pub fn choose_transcode_format(caps: &DeviceCaps, texture: &Texture2) -> Option<TranscodeFormat> {
+    if !texture.needs_transcoding() {
+        return None;
+    }
+    Some(choose_for(
+        caps,
+        Source {
+            etc1s: texture.supercompression_scheme() == ktxSupercmpScheme_KTX_SS_BASIS_LZ,
+            components: texture.num_components(),
+            srgb: texture.is_srgb(),
+            power_of_two: texture.base_width().is_power_of_two()
+                && texture.base_height().is_power_of_two(),
+        },
+    ))
+}
+
+/// What [`choose_transcode_format`] looks at in a Basis Universal texture.
+#[derive(Clone, Copy, Debug)]
+struct Source {
+    etc1s: bool,
+    components: u32,
+    srgb: bool,
+    power_of_two: bool,
+}
+
+fn choose_for(caps: &DeviceCaps, source: Source) -> TranscodeFormat {
+    use TranscodeFormat::*;
+
+    let components = source.components;
+    // Two component data keeps its second component in the alpha channel.
+    let has_alpha = components == 2 || components == 4;
+    // Neither OpenGL nor Vulkan have sRGB variants of the R and RG formats.
+    if !source.srgb && (components == 1 || components == 2) {
+        let two = components == 2;
+        if caps.bc {
+            return if two { Bc5Rg } else { Bc4R };
+        }
+        if caps.etc2 {
+            return if two { Etc2EacRg11 } else { Etc2EacR11 };
+        }
+    }
+    let pvrtc = caps.pvrtc && source.power_of_two;
+    let candidates = if source.etc1s {
+        [
+            (caps.etc2, Etc),
+            (caps.bc, Bc1Or3),
+            (caps.astc, Astc4x4Rgba),
+            (pvrtc, if has_alpha { Pvrtc1_4Rgba } else { Pvrtc1_4Rgb }),
+        ]
+    } else {
+        [
+            (caps.astc, Astc4x4Rgba),
+            (caps.bc, Bc7Rgba),
+            (caps.etc2, Etc),
+            (pvrtc, if has_alpha { Pvrtc1_4Rgba } else { Pvrtc1_4Rgb }),
+        ]
+    };
+    candidates
+        .into_iter()
+        .find_map(|(supported, format)| supported.then_some(format))
+        .unwrap_or(Rgba32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TranscodeFormat::*, *};
+    use crate::{testing::rgba8_gradient, BasisCodec, EncodeBasis, Uastc, UastcLevel};
+
+    #[test]
+    fn choice_follows_codec_alpha_and_caps() {
+        const NONE: DeviceCaps = DeviceCaps {
+            bc: false,
+            astc: false,
+            etc2: false,
+            pvrtc: false,
+        };
+        const BC: DeviceCaps = DeviceCaps { bc: true, ..NONE };
+        const ASTC: DeviceCaps = DeviceCaps { astc: true, ..NONE };
+        const ETC2: DeviceCaps = DeviceCaps { etc2: true, ..NONE };
+        const PVRTC: DeviceCaps = DeviceCaps {
+            pvrtc: true,
+            ..NONE
+        };
+        const DESKTOP: DeviceCaps = DeviceCaps {
+            bc: true,
+            astc: true,
+            ..NONE
+        };
+        const MOBILE: DeviceCaps = DeviceCaps {
+            astc: true,
+            etc2: true,
+            pvrtc: true,
+            ..NONE
+        };
+        const ALL: DeviceCaps = DeviceCaps {
+            bc: true,
+            astc: true,
+            etc2: true,
+            pvrtc: true,
+        };
+
+        // (caps, etc1s, components, srgb, power_of_two, expected)
+        let cases = [
+            // ETC1S prefers ETC, then BC, ASTC and PVRTC.
+            (ALL, true, 4, true, true, Etc),
+            (DESKTOP, true, 4, true, true, Bc1Or3),
+            (ASTC, true, 3, true, true, Astc4x4Rgba),
+            (PVRTC, true, 3, true, true, Pvrtc1_4Rgb),
+            (PVRTC, true, 4, true, true, Pvrtc1_4Rgba),
+            (PVRTC, true, 4, true, false, Rgba32),
+            (NONE, true, 4, true, true, Rgba32),
+            // UASTC prefers ASTC, then BC7, ETC and PVRTC.
+            (ALL, false, 4, true, true, Astc4x4Rgba),
+            (MOBILE, false, 3, false, true, Astc4x4Rgba),
+            (BC, false, 3, true, true, Bc7Rgba),
+            (ETC2, false, 4, true, true, Etc),
+            (PVRTC, false, 2, true, true, Pvrtc1_4Rgba),
+            (PVRTC, false, 1, true, true, Pvrtc1_4Rgb),
+            (NONE, false, 3, false, false, Rgba32),
+            // Linear R and RG use BC4/BC5, then EAC, whatever the codec.
+            (ALL, true, 1, false, true, Bc4R),
+            (ALL, false, 2, false, true, Bc5Rg),
+            (MOBILE, true, 1, false, true, Etc2EacR11),
+            (MOBILE, false, 2, false, true, Etc2EacRg11),
+            (ASTC, false, 2, false, true, Astc4x4Rgba),
+            // sRGB R and RG fall through to the RGBA order.
+            (ALL, true, 2, true, true, Etc),
+            (BC, false, 1, true, true, Bc7Rgba),
+        ];
+        for (caps, etc1s, components, srgb, power_of_two, expected) in cases {
+            let source = Source {
+                etc1s,
+                components,
+                srgb,
+                power_of_two,
+            };
+            assert_eq!(choose_for(&caps, source), expected, "{caps:?} {source:?}");
+        }
+    }
+
+    #[test]
+    fn encoded_textures_transcode_to_the_chosen_format() {
+        let etc1s = EncodeBasis::fast();
+        let uastc = EncodeBasis::new(BasisCodec::Uastc(Uastc {
+            level: UastcLevel::Fastest,
+            ..Uastc::default()
+        }));
+        let bc = DeviceCaps {
+            bc: true,
+            ..DeviceCaps::default()
+        };
+        let astc = DeviceCaps {
+            astc: true,
+            ..DeviceCaps::default()
+        };
+        let etc2 = DeviceCaps {
+            etc2: true,
+            ..DeviceCaps::default()
+        };
+        let none = DeviceCaps::default();
+
+        // The gradient is sRGB with alpha, so libktx resolves Etc and Bc1Or3 to
+        // their RGBA variants.
+        let cases = [
+            (
+                &etc1s,
+                etc2,
+                Etc,
+                VkFormat_VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK,
+                true,
+            ),
+            (&etc1s, bc, Bc1Or3, VkFormat_VK_FORMAT_BC3_SRGB_BLOCK, true),
+            (
+                &etc1s,
+                none,
+                Rgba32,
+                VkFormat_VK_FORMAT_R8G8B8A8_SRGB,
+                false,
+            ),
+            (
+                &uastc,
+                astc,
+                Astc4x4Rgba,
+                VkFormat_VK_FORMAT_ASTC_4x4_SRGB_BLOCK,
+                true,
+            ),
+            (&uastc, bc, Bc7Rgba, VkFormat_VK_FORMAT_BC7_SRGB_BLOCK, true),
+            (
+                &uastc,
+                none,
+                Rgba32,
+                VkFormat_VK_FORMAT_R8G8B8A8_SRGB,
+                false,
+            ),
+        ];
+        for (params, caps, format, vk_format, compressed) in cases {
+            let mut texture = rgba8_gradient(16, 16);
+            texture.compress_basis(params).unwrap();
+            assert_eq!(texture.num_components(), 4);
+            assert_eq!(choose_transcode_format(&caps, &texture), Some(format));
+
+            texture.transcode(format).unwrap();
+            assert_eq!(texture.vk_format(), vk_format, "{caps:?} {format:?}");
+            assert_eq!(texture.is_compressed(), compressed, "{caps:?} {format:?}");
+            assert!(!texture.needs_transcoding());
+            assert_eq!(choose_transcode_format(&caps, &texture), None);
+        }
+    }
+}
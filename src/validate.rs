@@ -0,0 +1,958 @@
+//! Checking KTX2 files against the rules of the KTX 2.0 specification.
+//!
+//! libktx refuses many invalid files with `KTX_FILE_DATA_ERROR` and accepts
+//! others, without saying why. [`validate`] reports every rule a file breaks
+//! instead, with where in the file and in the specification to look.
+
+use std::{ffi::CStr, fmt};
+
+use crate::{
+    dfd::{BasicDescriptor, DfdBuilder},
+    ffi::*,
+    format::{layout, FormatInfo},
+    metadata::{string_value, Orientation, Swizzle},
+    reader::{
+        read_header, read_level_index, read_u32, Header, Index, KeyValues, LevelIndex, HEADER_SIZE,
+        KTX2_IDENTIFIER, LEVEL_INDEX_ENTRY_SIZE,
+    },
+};
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The file doesn't follow the specification.
+    Error,
+    /// The file follows the specification, but not its recommendations.
+    Warning,
+}
+
+/// A rule of the KTX2 specification broken by a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The part of the specification the rule comes from: a header field such
+    /// as `faceCount`, a section such as `Level Index`, or a predefined key such
+    /// as `KTXswizzle`.
+    pub section: &'static str,
+    /// Where in the file the offending field or entry starts.
+    pub offset: u64,
+    pub message: String,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        })
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {} ({}): {}",
+            self.severity, self.offset, self.section, self.message
+        )
+    }
+}
+
+/// Checks `data` against the KTX2 specification, returning every problem found
+/// in file order. A file is valid if none of them is a [`Severity::Error`].
+pub fn validate(data: &[u8]) -> Vec<Diagnostic> {
+    let mut validator = Validator {
+        data,
+        diagnostics: Vec::new(),
+    };
+    validator.file();
+    validator
+        .diagnostics
+        .sort_by_key(|diagnostic| diagnostic.offset);
+    validator.diagnostics
+}
+
+const IDENTIFIER: &str = "identifier";
+const FILE_STRUCTURE: &str = "File Structure";
+const INDEX: &str = "Index";
+const LEVEL_INDEX: &str = "Level Index";
+const DFD: &str = "Data Format Descriptor";
+const KVD: &str = "Key/Value Data";
+const SGD: &str = "Supercompression Global Data";
+const MIP_LEVEL_ARRAY: &str = "Mip Level Array";
+
+// The predefined keys, NUL-terminated as in the bindings.
+const ORIENTATION_KEY: &[u8] = KTX_ORIENTATION_KEY;
+const SWIZZLE_KEY: &[u8] = KTX_SWIZZLE_KEY;
+const WRITER_KEY: &[u8] = KTX_WRITER_KEY;
+const WRITER_SCPARAMS_KEY: &[u8] = KTX_WRITER_SCPARAMS_KEY;
+const ANIMDATA_KEY: &[u8] = KTX_ANIMDATA_KEY;
+const CUBEMAP_INCOMPLETE_KEY: &[u8] = b"KTXcubemapIncomplete\0";
+const GL_FORMAT_KEY: &[u8] = b"KTXglFormat\0";
+const DXGI_FORMAT_KEY: &[u8] = b"KTXdxgiFormat__\0";
+const METAL_PIXEL_FORMAT_KEY: &[u8] = b"KTXmetalPixelFormat\0";
+const ASTC_DECODE_MODE_KEY: &[u8] = b"KTXastcDecodeMode\0";
+
+pub(crate) const BASIS_LZ_HEADER_SIZE: u64 = 20;
+pub(crate) const BASIS_LZ_IMAGE_DESC_SIZE: u64 = 20;
+
+/// Texel block extent and size in bytes.
+#[derive(Clone, Copy)]
+struct Block {
+    extent: [u32; 3],
+    bytes: u32,
+}
+
+impl Block {
+    fn image_size(&self, width: u32, height: u32, depth: u32) -> u64 {
+        [width, height, depth]
+            .iter()
+            .zip(self.extent)
+            .fold(u64::from(self.bytes), |size, (&texels, extent)| {
+                size.saturating_mul(texels.div_ceil(extent).into())
+            })
+    }
+}
+
+/// What a known `vkFormat` implies for the rest of the file.
+struct Expected {
+    name: &'static str,
+    type_size: u32,
+    block: Block,
+    is_srgb: bool,
+    descriptor: BasicDescriptor,
+}
+
+struct Validator<'a> {
+    data: &'a [u8],
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(
+        &mut self,
+        severity: Severity,
+        section: &'static str,
+        offset: u64,
+        message: impl Into<String>,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            section,
+            offset,
+            message: message.into(),
+        });
+    }
+
+    fn error(&mut self, section: &'static str, offset: u64, message: impl Into<String>) {
+        self.report(Severity::Error, section, offset, message);
+    }
+
+    fn warning(&mut self, section: &'static str, offset: u64, message: impl Into<String>) {
+        self.report(Severity::Warning, section, offset, message);
+    }
+
+    fn file(&mut self) {
+        let data = self.data;
+        if !data.starts_with(&KTX2_IDENTIFIER) {
+            self.error(IDENTIFIER, 0, "not a KTX2 file");
+            return;
+        }
+        let Ok((header, index)) = read_header(data) else {
+            self.error(
+                FILE_STRUCTURE,
+                data.len() as u64,
+                "the file ends within the header",
+            );
+            return;
+        };
+        let expected = self.header(&header);
+
+        let level_count = header.level_count.max(1) as usize;
+        let level_index_end =
+            HEADER_SIZE as u64 + level_count as u64 * LEVEL_INDEX_ENTRY_SIZE as u64;
+        if level_index_end > data.len() as u64 {
+            self.error(
+                LEVEL_INDEX,
+                HEADER_SIZE as u64,
+                "the file ends within the level index",
+            );
+            return;
+        }
+        let levels: Vec<LevelIndex> = (0..level_count)
+            .map(|level| read_level_index(data, level))
+            .collect();
+
+        let metadata_end = self.index(&header, &index, level_index_end);
+        let descriptor = self.dfd(&header, &index, expected.as_ref());
+        self.key_values(&header, &index);
+        self.global_data(&header, &index);
+
+        let block = expected.map(|expected| expected.block).or_else(|| {
+            let descriptor = descriptor?;
+            let [width, height, depth, _] = descriptor.texel_block_dimensions;
+            let bytes = u32::from(descriptor.bytes_planes[0]);
+            (bytes > 0).then_some(Block {
+                extent: [width, height, depth],
+                bytes,
+            })
+        });
+        self.levels(&header, &levels, block, metadata_end);
+    }
+
+    // Checks the fields before the index, returning what vkFormat implies.
+    fn header(&mut self, header: &Header) -> Option<Expected> {
+        let expected = self.vk_format(header);
+        let scheme = header.supercompression_scheme;
+        if scheme == ktxSupercmpScheme_KTX_SS_BASIS_LZ && header.vk_format != 0 {
+            self.error(
+                "vkFormat",
+                12,
+                "BasisLZ supercompression requires VK_FORMAT_UNDEFINED",
+            );
+        }
+        let type_size = match &expected {
+            Some(expected) => Some(expected.type_size),
+            None if header.vk_format == 0 => Some(1),
+            None => None,
+        };
+        if let Some(type_size) = type_size.filter(|&type_size| type_size != header.type_size) {
+            self.error(
+                "typeSize",
+                16,
+                format!(
+                    "typeSize is {}, the format requires {type_size}",
+                    header.type_size
+                ),
+            );
+        }
+
+        if header.pixel_width == 0 {
+            self.error("pixelWidth", 20, "pixelWidth must be non-zero");
+        }
+        if header.pixel_depth > 0 && header.pixel_height == 0 {
+            self.error(
+                "pixelHeight",
+                24,
+                "3D textures must have a non-zero pixelHeight",
+            );
+        }
+        if header.face_count != 1 && header.face_count != 6 {
+            self.error("faceCount", 36, "faceCount must be 1 or 6");
+        }
+        if header.face_count == 6 {
+            if header.pixel_width != header.pixel_height {
+                self.error("faceCount", 24, "cubemap faces must be square");
+            }
+            if header.pixel_depth != 0 {
+                self.error("faceCount", 28, "cubemaps can't be 3D");
+            }
+        }
+        let max_levels = 32
+            - header
+                .pixel_width
+                .max(header.pixel_height)
+                .max(header.pixel_depth)
+                .leading_zeros();
+        if header.level_count > max_levels {
+            self.error(
+                "levelCount",
+                40,
+                format!(
+                    "levelCount is {}, more than the {max_levels} levels down to 1x1",
+                    header.level_count
+                ),
+            );
+        }
+        if scheme > ktxSupercmpScheme_KTX_SS_END_RANGE
+            && !(ktxSupercmpScheme_KTX_SS_BEGIN_VENDOR_RANGE
+                ..=ktxSupercmpScheme_KTX_SS_END_VENDOR_RANGE)
+                .contains(&scheme)
+        {
+            self.error(
+                "supercompressionScheme",
+                44,
+                format!("supercompressionScheme {scheme} is reserved"),
+            );
+        }
+        expected
+    }
+
+    fn vk_format(&mut self, header: &Header) -> Option<Expected> {
+        if header.vk_format == 0 {
+            return None;
+        }
+        let Some(info) = FormatInfo::of(header.vk_format) else {
+            self.error(
+                "vkFormat",
+                12,
+                format!("VkFormat {} can't be stored in KTX2", header.vk_format),
+            );
+            return None;
+        };
+        if info.prohibited {
+            self.error(
+                "vkFormat",
+                12,
+                format!("{} is prohibited in KTX2", info.name),
+            );
+        }
+        Some(Expected {
+            name: info.name,
+            type_size: layout(info.vk_format)?.type_size(),
+            block: Block {
+                extent: info.block_extent,
+                bytes: info.bytes_per_block,
+            },
+            is_srgb: info.is_srgb,
+            descriptor: DfdBuilder::for_vk_format(info.vk_format)?
+                .descriptor()
+                .clone(),
+        })
+    }
+
+    // Checks where the index places the DFD, key/value data and global data,
+    // returning where the last of them ends.
+    fn index(&mut self, header: &Header, index: &Index, level_index_end: u64) -> u64 {
+        let len = self.data.len() as u64;
+        let dfd_start = u64::from(index.dfd_byte_offset);
+        let dfd_end = dfd_start + u64::from(index.dfd_byte_length);
+        if dfd_start != level_index_end {
+            self.error(
+                INDEX,
+                48,
+                format!(
+                    "the DFD must start right after the level index, at byte {level_index_end}"
+                ),
+            );
+        }
+        if dfd_end > len {
+            self.error(INDEX, 52, "the DFD extends past the end of the file");
+        }
+
+        let mut end = dfd_end;
+        let kvd_start = u64::from(index.kvd_byte_offset);
+        let kvd_end = kvd_start + u64::from(index.kvd_byte_length);
+        if index.kvd_byte_length == 0 {
+            if kvd_start != 0 {
+                self.error(INDEX, 56, "kvdByteOffset must be 0 without key/value data");
+            }
+        } else {
+            if kvd_start != dfd_end {
+                self.error(
+                    INDEX,
+                    56,
+                    format!("key/value data must start right after the DFD, at byte {dfd_end}"),
+                );
+            }
+            if kvd_end > len {
+                self.error(INDEX, 60, "key/value data extends past the end of the file");
+            }
+            end = kvd_end;
+        }
+
+        let sgd_start = index.sgd_byte_offset;
+        if index.sgd_byte_length == 0 {
+            if sgd_start != 0 {
+                self.error(INDEX, 64, "sgdByteOffset must be 0 without global data");
+            }
+            if header.supercompression_scheme == ktxSupercmpScheme_KTX_SS_BASIS_LZ {
+                self.error(SGD, 72, "BasisLZ supercompression requires global data");
+            }
+        } else {
+            if sgd_start != end.next_multiple_of(8) {
+                self.error(
+                    INDEX,
+                    64,
+                    format!(
+                        "global data must start at the next multiple of 8, byte {}",
+                        end.next_multiple_of(8)
+                    ),
+                );
+            }
+            if sgd_start
+                .checked_add(index.sgd_byte_length)
+                .map_or(true, |sgd_end| sgd_end > len)
+            {
+                self.error(INDEX, 72, "global data extends past the end of the file");
+            }
+            end = sgd_start.saturating_add(index.sgd_byte_length);
+        }
+        end
+    }
+
+    fn section(&self, offset: u64, length: u64) -> Option<&'a [u8]> {
+        let start = usize::try_from(offset).ok()?;
+        let end = start.checked_add(usize::try_from(length).ok()?)?;
+        self.data.get(start..end)
+    }
+
+    fn dfd(
+        &mut self,
+        header: &Header,
+        index: &Index,
+        expected: Option<&Expected>,
+    ) -> Option<BasicDescriptor> {
+        let at = u64::from(index.dfd_byte_offset);
+        let words: Vec<u32> = self
+            .section(at, index.dfd_byte_length.into())?
+            .chunks_exact(4)
+            .map(|word| read_u32(word, 0))
+            .collect();
+        if words.first() != Some(&index.dfd_byte_length) {
+            self.error(DFD, at, "dfdTotalSize must equal dfdByteLength");
+        }
+        let descriptor = match BasicDescriptor::parse(&words) {
+            Ok(descriptor) => descriptor,
+            Err(err) => {
+                self.error(DFD, at, err.to_string());
+                return None;
+            }
+        };
+
+        let at = at + 4;
+        let scheme = header.supercompression_scheme;
+        if scheme == ktxSupercmpScheme_KTX_SS_BASIS_LZ
+            && descriptor.color_model != _khr_df_model_e_KHR_DF_MODEL_ETC1S
+        {
+            self.error(
+                DFD,
+                at,
+                "BasisLZ supercompression requires the ETC1S color model",
+            );
+        }
+        if descriptor.color_model == _khr_df_model_e_KHR_DF_MODEL_UASTC && header.vk_format != 0 {
+            self.error(
+                DFD,
+                at,
+                "the UASTC color model requires VK_FORMAT_UNDEFINED",
+            );
+        }
+        if scheme != ktxSupercmpScheme_KTX_SS_NONE && descriptor.bytes_planes != [0; 8] {
+            self.error(DFD, at, "bytesPlane0-7 must be 0 for supercompressed data");
+        }
+        if let Some(expected) = expected {
+            let name = expected.name;
+            let want = &expected.descriptor;
+            if descriptor.color_model != want.color_model {
+                self.error(
+                    DFD,
+                    at,
+                    format!(
+                        "color model {} doesn't match {name}, expected {}",
+                        descriptor.color_model, want.color_model
+                    ),
+                );
+            }
+            let srgb = descriptor.transfer_function == _khr_df_transfer_e_KHR_DF_TRANSFER_SRGB;
+            if srgb != expected.is_srgb {
+                self.error(
+                    DFD,
+                    at,
+                    format!("the sRGB transfer function must be used exactly for sRGB formats, unlike {name}"),
+                );
+            }
+            if descriptor.texel_block_dimensions != want.texel_block_dimensions {
+                self.error(
+                    DFD,
+                    at,
+                    format!("texel block dimensions don't match {name}"),
+                );
+            }
+            if scheme == ktxSupercmpScheme_KTX_SS_NONE
+                && descriptor.bytes_planes != want.bytes_planes
+            {
+                self.error(DFD, at, format!("bytesPlane0-7 don't match {name}"));
+            }
+            if descriptor.samples != want.samples {
+                self.error(DFD, at, format!("samples don't match {name}"));
+            }
+        }
+        Some(descriptor)
+    }
+
+    fn key_values(&mut self, header: &Header, index: &Index) {
+        let start = u64::from(index.kvd_byte_offset);
+        let Some(data) = self.section(start, index.kvd_byte_length.into()) else {
+            return;
+        };
+        let mut entries = KeyValues::new(data);
+        let mut previous: Option<&CStr> = None;
+        let mut has_writer = false;
+        let mut has_cubemap_incomplete = false;
+        let mut anim_data_at = None;
+        loop {
+            let at = start + (data.len() - entries.remainder().len()) as u64;
+            let Some((key, value)) = entries.next() else {
+                if !entries.remainder().is_empty() {
+                    self.error(KVD, at, "malformed key/value entry");
+                }
+                break;
+            };
+            if previous.is_some_and(|previous| previous >= key) {
+                self.error(
+                    KVD,
+                    at,
+                    format!("{key:?} is out of order or repeated, keys must be sorted and unique"),
+                );
+            }
+            previous = Some(key);
+            match key.to_bytes_with_nul() {
+                WRITER_KEY => has_writer = true,
+                CUBEMAP_INCOMPLETE_KEY => has_cubemap_incomplete = true,
+                ANIMDATA_KEY => anim_data_at = Some(at),
+                _ => {}
+            }
+            self.key_value(header, key, value, at);
+        }
+
+        if !has_writer {
+            self.warning(
+                "KTXwriter",
+                if data.is_empty() { 56 } else { start },
+                "KTXwriter should name the tool that wrote the file",
+            );
+        }
+        if let (true, Some(at)) = (has_cubemap_incomplete, anim_data_at) {
+            self.error(
+                "KTXanimData",
+                at,
+                "KTXanimData can't be combined with KTXcubemapIncomplete",
+            );
+        }
+    }
+
+    fn key_value(&mut self, header: &Header, key: &CStr, value: &[u8], at: u64) {
+        let Ok(name) = key.to_str() else {
+            self.error(KVD, at, "keys must be valid UTF-8");
+            return;
+        };
+        match key.to_bytes_with_nul() {
+            ORIENTATION_KEY => match string_value(KTX_ORIENTATION_KEY, value) {
+                Ok(orientation) => {
+                    if let Err(err) = orientation.parse::<Orientation>() {
+                        self.error("KTXorientation", at, err.to_string());
+                    } else if orientation.starts_with("S=") {
+                        self.error("KTXorientation", at, "uses the KTX1 form, e.g. S=r,T=d");
+                    } else if orientation.len() as u32 != dimensions(header) {
+                        self.error(
+                            "KTXorientation",
+                            at,
+                            "must have one character per dimension of the texture",
+                        );
+                    }
+                }
+                Err(err) => self.error("KTXorientation", at, err.to_string()),
+            },
+            SWIZZLE_KEY => {
+                if let Err(err) = string_value(KTX_SWIZZLE_KEY, value)
+                    .and_then(|swizzle| swizzle.parse::<Swizzle>())
+                {
+                    self.error("KTXswizzle", at, err.to_string());
+                }
+            }
+            WRITER_KEY => {
+                if let Err(err) = string_value(KTX_WRITER_KEY, value) {
+                    self.error("KTXwriter", at, err.to_string());
+                }
+            }
+            WRITER_SCPARAMS_KEY => {
+                if let Err(err) = string_value(KTX_WRITER_SCPARAMS_KEY, value) {
+                    self.error("KTXwriterScParams", at, err.to_string());
+                }
+            }
+            ANIMDATA_KEY => {
+                if value.len() != 12 {
+                    self.error("KTXanimData", at, "must be 12 bytes");
+                }
+                if header.layer_count == 0 {
+                    self.error("KTXanimData", at, "requires an array texture");
+                }
+            }
+            CUBEMAP_INCOMPLETE_KEY => {
+                if value.len() != 1 || value[0] & !0x3f != 0 {
+                    self.error(
+                        "KTXcubemapIncomplete",
+                        at,
+                        "must be 1 byte with bits 0-5 set for the faces present",
+                    );
+                }
+                if header.face_count != 1 {
+                    self.error(
+                        "KTXcubemapIncomplete",
+                        at,
+                        "incomplete cubemaps must have a faceCount of 1",
+                    );
+                }
+            }
+            GL_FORMAT_KEY | DXGI_FORMAT_KEY | METAL_PIXEL_FORMAT_KEY => {
+                let size = if key.to_bytes_with_nul() == GL_FORMAT_KEY {
+                    12
+                } else {
+                    4
+                };
+                if value.len() != size {
+                    self.error(KVD, at, format!("{name} must be {size} bytes"));
+                }
+                if header.vk_format != 0 {
+                    self.error(
+                        KVD,
+                        at,
+                        format!("{name} is only allowed with VK_FORMAT_UNDEFINED"),
+                    );
+                }
+            }
+            ASTC_DECODE_MODE_KEY => match value {
+                b"rgb9e5\0" | b"unorm8\0" => {}
+                _ => self.error("KTXastcDecodeMode", at, "must be rgb9e5 or unorm8"),
+            },
+            _ if name.starts_with("KTX") || name.starts_with("ktx") => {
+                self.warning(
+                    KVD,
+                    at,
+                    format!("{name} is reserved but not defined by the specification"),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn global_data(&mut self, header: &Header, index: &Index) {
+        let at = index.sgd_byte_offset;
+        let Some(data) = self.section(at, index.sgd_byte_length) else {
+            return;
+        };
+        let scheme = header.supercompression_scheme;
+        if data.is_empty() {
+            return;
+        }
+        if scheme == ktxSupercmpScheme_KTX_SS_NONE || scheme == ktxSupercmpScheme_KTX_SS_ZSTD {
+            self.error(SGD, at, "only BasisLZ supercompression has global data");
+            return;
+        }
+        if scheme != ktxSupercmpScheme_KTX_SS_BASIS_LZ {
+            return;
+        }
+        if (data.len() as u64) < BASIS_LZ_HEADER_SIZE {
+            self.error(SGD, at, "BasisLZ global data starts with a 20 byte header");
+            return;
+        }
+        let slices: u64 = (0..header.level_count.max(1))
+            .map(|level| u64::from((header.pixel_depth >> level).max(1)))
+            .sum();
+        let images = slices
+            .saturating_mul(header.layer_count.max(1).into())
+            .saturating_mul(header.face_count.into());
+        let lengths: u64 = (4..20)
+            .step_by(4)
+            .map(|offset| u64::from(read_u32(data, offset)))
+            .sum();
+        let expected = images
+            .saturating_mul(BASIS_LZ_IMAGE_DESC_SIZE)
+            .saturating_add(BASIS_LZ_HEADER_SIZE + lengths);
+        if data.len() as u64 != expected {
+            self.error(
+                SGD,
+                at,
+                format!(
+                    "BasisLZ global data for {images} images is {expected} bytes, not {}",
+                    data.len()
+                ),
+            );
+        }
+    }
+
+    fn levels(
+        &mut self,
+        header: &Header,
+        levels: &[LevelIndex],
+        block: Option<Block>,
+        metadata_end: u64,
+    ) {
+        let len = self.data.len() as u64;
+        let scheme = header.supercompression_scheme;
+        for (level, entry) in levels.iter().enumerate() {
+            let at = (HEADER_SIZE + level * LEVEL_INDEX_ENTRY_SIZE) as u64;
+            if entry
+                .byte_offset
+                .checked_add(entry.byte_length)
+                .map_or(true, |end| end > len)
+            {
+                self.error(
+                    LEVEL_INDEX,
+                    at,
+                    format!("level {level} extends past the end of the file"),
+                );
+                continue;
+            }
+            if let Some(next) = levels.get(level + 1) {
+                if next.byte_offset.saturating_add(next.byte_length) > entry.byte_offset {
+                    self.error(
+                        MIP_LEVEL_ARRAY,
+                        at,
+                        format!(
+                            "level {level} must be stored after level {}, from the smallest level up",
+                            level + 1
+                        ),
+                    );
+                }
+            }
+            if scheme == ktxSupercmpScheme_KTX_SS_NONE {
+                let alignment = block.map_or(4, |block| lcm(block.bytes, 4));
+                if entry.byte_offset % u64::from(alignment) != 0 {
+                    self.error(
+                        LEVEL_INDEX,
+                        at,
+                        format!("level {level} must be aligned to {alignment} bytes"),
+                    );
+                }
+            }
+
+            let size = block.map(|block| {
+                let level = level as u32;
+                let extent = |base: u32| (base >> level).max(1);
+                block
+                    .image_size(
+                        extent(header.pixel_width),
+                        extent(header.pixel_height),
+                        extent(header.pixel_depth),
+                    )
+                    .saturating_mul(header.layer_count.max(1).into())
+                    .saturating_mul(header.face_count.into())
+            });
+            let uncompressed = entry.uncompressed_byte_length;
+            match scheme {
+                ktxSupercmpScheme_KTX_SS_NONE => {
+                    if let Some(size) = size.filter(|&size| size != entry.byte_length) {
+                        self.error(
+                            LEVEL_INDEX,
+                            at + 8,
+                            format!(
+                                "level {level} is {} bytes, not the {size} its images take",
+                                entry.byte_length
+                            ),
+                        );
+                    }
+                    if uncompressed != entry.byte_length {
+                        self.error(
+                            LEVEL_INDEX,
+                            at + 16,
+                            "uncompressedByteLength must equal byteLength without supercompression",
+                        );
+                    }
+                }
+                ktxSupercmpScheme_KTX_SS_BASIS_LZ if uncompressed != 0 => {
+                    self.error(
+                        LEVEL_INDEX,
+                        at + 16,
+                        "uncompressedByteLength must be 0 for BasisLZ",
+                    );
+                }
+                ktxSupercmpScheme_KTX_SS_ZSTD => {
+                    if let Some(size) = size.filter(|&size| size != uncompressed) {
+                        self.error(
+                            LEVEL_INDEX,
+                            at + 16,
+                            format!(
+                                "level {level} uncompresses to {uncompressed} bytes, not the {size} its images take"
+                            ),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let smallest = levels[levels.len() - 1];
+        if smallest.byte_offset < metadata_end {
+            self.error(
+                MIP_LEVEL_ARRAY,
+                (HEADER_SIZE + (levels.len() - 1) * LEVEL_INDEX_ENTRY_SIZE) as u64,
+                format!("image data must start after the metadata ends, at byte {metadata_end}"),
+            );
+        }
+    }
+}
+
+fn dimensions(header: &Header) -> u32 {
+    if header.pixel_depth > 0 {
+        3
+    } else if header.pixel_height > 0 {
+        2
+    } else {
+        1
+    }
+}
+
+fn lcm(a: u32, b: u32) -> u32 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    a / x * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        format::known_to_libktx,
+        reader::read_u64,
+        testing::{rgba8_gradient, undefined_r8, write_ktx2},
+        BaseTexture, EncodeBasis, TextureBuilder,
+    };
+
+    fn errors(data: &[u8]) -> Vec<(&'static str, u64)> {
+        validate(data)
+            .into_iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Error)
+            .map(|diagnostic| (diagnostic.section, diagnostic.offset))
+            .collect()
+    }
+
+    fn write(builder: TextureBuilder) -> Vec<u8> {
+        write_ktx2(&builder, "validate test").1
+    }
+
+    /// Writes a texture with extra key/value pairs, which libktx doesn't check.
+    fn write_with_metadata(builder: TextureBuilder, key_values: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut texture = builder.build_ktx2().unwrap();
+        for (key, value) in key_values {
+            texture.metadata_mut().insert(key, value).unwrap();
+        }
+        texture.metadata_mut().set_writer("validate test").unwrap();
+        let mut bytes = Vec::new();
+        texture.write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn set_u32(data: &mut [u8], offset: usize, value: u32) {
+        data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn set_u64(data: &mut [u8], offset: usize, value: u64) {
+        data[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn reports_broken_rules() {
+        let valid = write(undefined_r8());
+        assert_eq!(validate(&valid), []);
+
+        assert_eq!(errors(b"KTX 11"), [(IDENTIFIER, 0)]);
+        assert_eq!(
+            errors(&valid[..valid.len() - 1]),
+            [(LEVEL_INDEX, HEADER_SIZE as u64)]
+        );
+
+        let mut faces = valid.clone();
+        faces[36] = 3;
+        assert!(errors(&faces).contains(&("faceCount", 36)));
+
+        let mut swapped = valid.clone();
+        let (level0, level1) = swapped[HEADER_SIZE..].split_at_mut(LEVEL_INDEX_ENTRY_SIZE);
+        level0.swap_with_slice(&mut level1[..LEVEL_INDEX_ENTRY_SIZE]);
+        assert!(errors(&swapped).contains(&(MIP_LEVEL_ARRAY, HEADER_SIZE as u64)));
+
+        let mut dfd_size = valid.clone();
+        let dfd_offset = read_u32(&valid, 48) as usize;
+        dfd_size[dfd_offset] += 4;
+        assert!(errors(&dfd_size).contains(&(DFD, dfd_offset as u64)));
+    }
+
+    #[test]
+    fn reports_header_and_level_rules_at_their_field() {
+        let rgb8 = || {
+            TextureBuilder::new()
+                .vk_format(VkFormat_VK_FORMAT_R8G8B8_UNORM)
+                .extent_2d(4, 4)
+        };
+
+        // 3 byte texels align levels to lcm(3, 4) = 12 bytes, so 4 isn't enough.
+        let mut misaligned = write(rgb8());
+        assert_eq!(errors(&misaligned), []);
+        let level_offset = read_u64(&misaligned, HEADER_SIZE);
+        set_u64(&mut misaligned, HEADER_SIZE, level_offset + 4);
+        misaligned.extend([0; 4]);
+        assert_eq!(errors(&misaligned), [(LEVEL_INDEX, HEADER_SIZE as u64)]);
+
+        let mut srgb = write(rgb8());
+        set_u32(&mut srgb, 12, VkFormat_VK_FORMAT_R8G8B8_SRGB);
+        let dfd_offset = u64::from(read_u32(&srgb, 48));
+        assert!(errors(&srgb).contains(&(DFD, dfd_offset + 4)));
+
+        let mut type_size = write(
+            TextureBuilder::new()
+                .vk_format(VkFormat_VK_FORMAT_R16_UNORM)
+                .extent_2d(4, 4),
+        );
+        set_u32(&mut type_size, 16, 1);
+        assert_eq!(errors(&type_size), [("typeSize", 16)]);
+
+        let mut cubemap = write(
+            TextureBuilder::new()
+                .vk_format(VkFormat_VK_FORMAT_R8G8B8A8_UNORM)
+                .extent_2d(8, 8)
+                .cubemap(true),
+        );
+        assert_eq!(errors(&cubemap), []);
+        set_u32(&mut cubemap, 24, 4);
+        assert!(errors(&cubemap).contains(&("faceCount", 24)));
+    }
+
+    #[test]
+    fn reports_key_value_rules_at_their_entry() {
+        let r8 = || {
+            TextureBuilder::new()
+                .vk_format(VkFormat_VK_FORMAT_R8_UNORM)
+                .extent_2d(4, 4)
+        };
+        // Each key below sorts before KTXwriter, so its entry starts the key/value data.
+        let first_entry = |data: &[u8]| u64::from(read_u32(data, 56));
+
+        let gl_format = write_with_metadata(r8(), &[("KTXglFormat", &[0; 12])]);
+        assert_eq!(errors(&gl_format), [(KVD, first_entry(&gl_format))]);
+
+        let orientation = write_with_metadata(r8(), &[("KTXorientation", b"rdi\0")]);
+        assert_eq!(
+            errors(&orientation),
+            [("KTXorientation", first_entry(&orientation))]
+        );
+
+        let animated = write_with_metadata(
+            r8().layers(2).array(true),
+            &[("KTXanimData", &[0; 12]), ("KTXcubemapIncomplete", &[0x3f])],
+        );
+        assert_eq!(errors(&animated), [("KTXanimData", first_entry(&animated))]);
+    }
+
+    #[test]
+    fn reports_wrong_basis_lz_global_data_size() {
+        let mut texture = rgba8_gradient(16, 16);
+        texture.compress_basis(&EncodeBasis::fast()).unwrap();
+        texture.metadata_mut().set_writer("validate test").unwrap();
+        let mut bytes = Vec::new();
+        texture.write_to(&mut bytes).unwrap();
+        assert_eq!(errors(&bytes), []);
+
+        // Claim one more byte of endpoints than the global data holds.
+        let sgd_offset = read_u64(&bytes, 64);
+        let endpoints_length = sgd_offset as usize + 4;
+        let length = read_u32(&bytes, endpoints_length);
+        set_u32(&mut bytes, endpoints_length, length + 1);
+        assert_eq!(errors(&bytes), [(SGD, sgd_offset)]);
+    }
+
+    #[test]
+    fn libktx_output_is_valid() {
+        for info in FormatInfo::all().filter(|info| known_to_libktx(info.vk_format)) {
+            let bytes = write(
+                TextureBuilder::new()
+                    .vk_format(info.vk_format)
+                    .extent_2d(13, 7)
+                    .levels(4),
+            );
+            assert_eq!(errors(&bytes), [], "{}", info.name);
+        }
+    }
+}
@@ -1,5 +1,9 @@
 #pragma once
 
+#ifdef LIBKTX_SYS_VULKAN
 #include <vulkan/vulkan_core.h>
+#endif
 #include <ktx.h>
+#ifdef LIBKTX_SYS_VULKAN
 #include <ktxvulkan.h>
+#endif